@@ -0,0 +1,16 @@
+//! How a slider responds to a click on its rail
+
+/// How a slider responds when its rail is clicked outside of its handle
+///
+/// Clicking the handle itself always grabs it without changing the value.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum ClickMode {
+    /// The value does not change, and dragging moves the handle relative to
+    /// where it is.
+    #[default]
+    Relative,
+    /// The handle jumps to the cursor, and then follows it while dragged.
+    Absolute,
+    /// The value steps towards the cursor by the page step of the slider.
+    Page,
+}
@@ -3,6 +3,7 @@
 //! This module holds basic types that can be reused and re-exported in
 //! different runtime implementations.
 
+pub mod click_mode;
 pub mod knob_angle_range;
 pub mod math;
 pub mod modulation_range;
@@ -11,6 +12,7 @@ pub mod normal_param;
 pub mod offset;
 pub mod range;
 
+pub use click_mode::ClickMode;
 pub use knob_angle_range::*;
 pub use modulation_range::ModulationRange;
 pub use normal::Normal;
@@ -6,6 +6,44 @@ use crate::core::Normal;
 
 use std::fmt::Debug;
 
+/// A range of parameter values that can be shown to the user as text, and
+/// parsed from text typed in by the user.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{FreqRange, LogDBRange, Normal, ParamRange};
+///
+/// let freq_range = FreqRange::new(20.0, 20_000.0);
+///
+/// assert_eq!(freq_range.format(freq_range.map_to_normal(440.0)), "440 Hz");
+/// assert_eq!(
+///     freq_range.format(freq_range.map_to_normal(2_500.0)),
+///     "2.50 kHz"
+/// );
+///
+/// let db_range = LogDBRange::new(-60.0, 6.0, 0.9.into());
+///
+/// assert_eq!(db_range.format(Normal::min()), "-inf dB");
+/// assert_eq!(db_range.parse("-inf"), Some(Normal::min()));
+/// ```
+pub trait ParamRange {
+    /// Returns the value of the [`Normal`] in this range as text, including
+    /// its unit.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    fn format(&self, normal: Normal) -> String;
+
+    /// Parses text typed in by the user, such as `"440 Hz"` or `"-6 dB"`,
+    /// and returns the corresponding [`Normal`]. Values outside of the
+    /// range are clamped.
+    ///
+    /// Returns `None` if the text is not a valid value for this range.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    fn parse(&self, text: &str) -> Option<Normal>;
+}
+
 /// A range that maps a continuous linear range of `f32` values
 /// to a [`Normal`]
 ///
@@ -16,6 +54,9 @@ pub struct FloatRange {
     max: f32,
     span: f32,
     span_recip: f32,
+    precision: usize,
+    unit: &'static str,
+    percent: bool,
 }
 
 impl FloatRange {
@@ -40,9 +81,36 @@ impl FloatRange {
             max,
             span,
             span_recip,
+            precision: 2,
+            unit: "",
+            percent: false,
         }
     }
 
+    /// Sets the number of decimal places of the formatted value.
+    ///
+    /// The default precision is `2`.
+    pub fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Sets the unit shown after the formatted value, such as `"ms"`.
+    ///
+    /// By default no unit is shown.
+    pub fn with_unit(mut self, unit: &'static str) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    /// Formats the value as a percentage, so that a value of `0.5` is shown
+    /// as `"50%"` (with a precision of `0`). Parsed text is also read as a
+    /// percentage.
+    pub fn as_percent(mut self) -> Self {
+        self.percent = true;
+        self
+    }
+
     /// A `FloatRange` with the range
     ///
     /// * `min` = -1.0
@@ -108,6 +176,54 @@ impl Default for FloatRange {
     }
 }
 
+impl ParamRange for FloatRange {
+    /// Returns the value as text with the precision and unit of the range.
+    fn format(&self, normal: Normal) -> String {
+        let value = self.unmap_to_value(normal);
+
+        if self.percent {
+            format!("{}%", format_decimal(value * 100.0, self.precision))
+        } else {
+            with_unit(format_decimal(value, self.precision), self.unit)
+        }
+    }
+
+    /// The text may have the unit of the range or no unit at all, and a
+    /// range in `ms` also accepts values in `s` (and the other way around).
+    /// A range in `Hz`, `ms` or `s` also accepts a `k` suffix, which
+    /// multiplies the number by `1000`. A range formatted as a percentage
+    /// accepts `%`.
+    ///
+    /// Returns `None` for any other unit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::{FloatRange, ParamRange};
+    ///
+    /// let range = FloatRange::new(0.0, 500.0).with_unit("ms");
+    ///
+    /// assert_eq!(range.parse("150 ms"), Some(range.map_to_normal(150.0)));
+    /// assert_eq!(range.parse("0.25 s"), Some(range.map_to_normal(250.0)));
+    /// assert_eq!(range.parse("150 Hz"), None);
+    /// assert_eq!(range.parse("fast"), None);
+    /// ```
+    fn parse(&self, text: &str) -> Option<Normal> {
+        let (value, unit) = parse_value(text)?;
+
+        if self.percent {
+            match unit.as_str() {
+                "" | "%" => Some(self.map_to_normal(value / 100.0)),
+                _ => None,
+            }
+        } else {
+            let value = convert_unit(value, &unit, self.unit)?;
+
+            Some(self.map_to_normal(value))
+        }
+    }
+}
+
 /// A range that defines a discrete linear range of i32 values
 #[derive(Debug, Copy, Clone)]
 pub struct IntRange {
@@ -115,6 +231,8 @@ pub struct IntRange {
     max: i32,
     span: f32,
     span_recip: f32,
+    unit: &'static str,
+    note_names: bool,
 }
 
 impl IntRange {
@@ -139,9 +257,27 @@ impl IntRange {
             max,
             span,
             span_recip,
+            unit: "",
+            note_names: false,
         }
     }
 
+    /// Sets the unit shown after the formatted value, such as `"st"`.
+    ///
+    /// By default no unit is shown.
+    pub fn with_unit(mut self, unit: &'static str) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    /// Formats the values as the names of MIDI notes, where `60` is shown
+    /// as `"C4"` and `69` as `"A4"`. Parsed text may be either a note name
+    /// or a note number.
+    pub fn as_note_names(mut self) -> Self {
+        self.note_names = true;
+        self
+    }
+
     fn constrain(&self, value: i32) -> i32 {
         if value <= self.min {
             self.min
@@ -208,6 +344,50 @@ impl Default for IntRange {
     }
 }
 
+impl ParamRange for IntRange {
+    /// Returns the value as text with the unit of the range, or as a note
+    /// name.
+    fn format(&self, normal: Normal) -> String {
+        let value = self.unmap_to_value(normal);
+
+        if self.note_names {
+            note_name(value)
+        } else {
+            with_unit(value.to_string(), self.unit)
+        }
+    }
+
+    /// The number is rounded to the nearest integer. The text may have the
+    /// unit of the range or no unit at all. A range in `Hz`, `ms` or `s`
+    /// also accepts a `k` suffix, which multiplies the number by `1000`.
+    ///
+    /// Returns `None` for any other unit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::{IntRange, ParamRange};
+    ///
+    /// let range = IntRange::new(0, 127).as_note_names();
+    ///
+    /// assert_eq!(range.format(range.map_to_normal(61)), "C#4");
+    /// assert_eq!(range.parse("a4"), Some(range.map_to_normal(69)));
+    /// assert_eq!(range.parse("69"), Some(range.map_to_normal(69)));
+    /// ```
+    fn parse(&self, text: &str) -> Option<Normal> {
+        if self.note_names {
+            if let Some(note) = parse_note_name(text) {
+                return Some(self.map_to_normal(note));
+            }
+        }
+
+        let (value, unit) = parse_value(text)?;
+        let value = convert_unit(value, &unit, self.unit)?;
+
+        Some(self.map_to_normal(value.round() as i32))
+    }
+}
+
 /// A range that defines a continuous logarithmic range of `dB` values,
 /// with an inflection/stationary point at 0 dB
 ///
@@ -222,6 +402,7 @@ pub struct LogDBRange {
     max_recip: f32,
     zero_pos_recip: f32,
     one_min_zero_pos_recip: f32,
+    precision: usize,
 }
 
 impl LogDBRange {
@@ -272,9 +453,18 @@ impl LogDBRange {
             max_recip,
             zero_pos_recip,
             one_min_zero_pos_recip,
+            precision: 1,
         }
     }
 
+    /// Sets the number of decimal places of the formatted value.
+    ///
+    /// The default precision is `1`.
+    pub fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
     fn constrain(&self, value: f32) -> f32 {
         if value <= self.min {
             self.min
@@ -375,6 +565,47 @@ impl Default for LogDBRange {
     }
 }
 
+impl ParamRange for LogDBRange {
+    /// Returns the value as text in dB. The minimum of a range that goes
+    /// below 0 dB is shown as `"-inf dB"`.
+    fn format(&self, normal: Normal) -> String {
+        if self.min < 0.0 && normal.as_f32() <= 0.0 {
+            return String::from("-inf dB");
+        }
+
+        format!(
+            "{} dB",
+            format_decimal(self.unmap_to_value(normal), self.precision)
+        )
+    }
+
+    /// `"-inf"` maps to the minimum of the range. The text may only have
+    /// a unit of `dB`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::{LogDBRange, Normal, ParamRange};
+    ///
+    /// let range = LogDBRange::new(-12.0, 12.0, 0.5.into());
+    ///
+    /// assert_eq!(range.parse("0dB"), Some(Normal::new(0.5)));
+    /// assert_eq!(range.parse("-inf"), Some(Normal::min()));
+    /// assert_eq!(range.parse("-6 Hz"), None);
+    /// ```
+    fn parse(&self, text: &str) -> Option<Normal> {
+        let trimmed = text.trim().to_lowercase();
+        if trimmed.starts_with("-inf") {
+            return Some(self.map_to_normal(self.min));
+        }
+
+        let (value, unit) = parse_value(&trimmed)?;
+        let value = convert_unit(value, &unit, "dB")?;
+
+        Some(self.map_to_normal(value))
+    }
+}
+
 /// A [`NormalParam`] that defines a continuous logarithmic range of `f32` frequency
 /// values, with each octave in the 10 octave spectrum spaced evenly.
 ///
@@ -387,6 +618,7 @@ pub struct FreqRange {
     spectrum_normal_span: f32,
     spectrum_normal_span_recip: f32,
     min_spectrum_normal: Normal,
+    precision: usize,
 }
 
 impl FreqRange {
@@ -431,9 +663,20 @@ impl FreqRange {
             spectrum_normal_span,
             min_spectrum_normal,
             spectrum_normal_span_recip,
+            precision: 0,
         }
     }
 
+    /// Sets the number of decimal places of the formatted value in Hz.
+    ///
+    /// Values of 1 kHz and above are shown in kHz with two more decimal
+    /// places, so both have the same resolution. The default precision is
+    /// `0`, which shows `"440 Hz"` and `"2.50 kHz"`.
+    pub fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
     fn constrain(&self, value: f32) -> f32 {
         if value <= self.min {
             self.min
@@ -499,6 +742,45 @@ impl Default for FreqRange {
     }
 }
 
+impl ParamRange for FreqRange {
+    /// Returns the value as text in Hz, or in kHz for values of 1 kHz and
+    /// above.
+    fn format(&self, normal: Normal) -> String {
+        let value = self.unmap_to_value(normal);
+
+        let rounded = format_decimal(value, self.precision);
+        if rounded.parse::<f32>().unwrap_or(value) < 1_000.0 {
+            format!("{} Hz", rounded)
+        } else {
+            format!(
+                "{} kHz",
+                format_decimal(value / 1_000.0, self.precision + 2)
+            )
+        }
+    }
+
+    /// The text may only have a unit of `Hz` or `kHz`, and a `k` suffix
+    /// multiplies the number by `1000`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::{FreqRange, ParamRange};
+    ///
+    /// let range = FreqRange::new(20.0, 20_000.0);
+    ///
+    /// assert_eq!(range.parse("2.5 kHz"), Some(range.map_to_normal(2_500.0)));
+    /// assert_eq!(range.parse("440hz"), Some(range.map_to_normal(440.0)));
+    /// assert_eq!(range.parse("440 dB"), None);
+    /// ```
+    fn parse(&self, text: &str) -> Option<Normal> {
+        let (value, unit) = parse_value(text)?;
+        let value = convert_unit(value, &unit, "Hz")?;
+
+        Some(self.map_to_normal(value))
+    }
+}
+
 /// Returns the corresponding frequency for the whole 10 octave spectrum
 /// (between 20 Hz and 20480 Hz)
 #[inline]
@@ -514,3 +796,188 @@ fn octave_normal_to_spectrum(value: Normal) -> f32 {
 fn octave_spectrum_map_to_normal(freq: f32) -> Normal {
     (((freq / 40.0).log2() + 1.0) * 0.1).into()
 }
+
+/// Splits text typed in by the user, such as `"2.5 kHz"` or `"-6dB"`, into
+/// its number and its lowercase unit.
+fn parse_value(text: &str) -> Option<(f32, String)> {
+    let text = text.trim();
+
+    let number_len = text
+        .char_indices()
+        .find(|(i, c)| {
+            !(c.is_ascii_digit()
+                || *c == '.'
+                || (*i == 0 && (*c == '-' || *c == '+')))
+        })
+        .map(|(i, _)| i)
+        .unwrap_or_else(|| text.len());
+
+    let value: f32 = text[..number_len].parse().ok()?;
+    if !value.is_finite() {
+        return None;
+    }
+
+    Some((value, text[number_len..].trim().to_lowercase()))
+}
+
+/// Converts a value parsed with `parse_value` into the unit of a range.
+///
+/// A value without a unit is already in the unit of the range. `kHz` and
+/// `Hz` convert into each other, and so do `s` and `ms`. A bare `k`
+/// multiplies the value by `1000`, but only in a range of `Hz`, `ms` or
+/// `s`, since `"6k"` is not a sensible value in dB or without a unit.
+/// Returns `None` for any other unit.
+fn convert_unit(value: f32, unit: &str, range_unit: &str) -> Option<f32> {
+    let range_unit = range_unit.to_lowercase();
+
+    match (unit, range_unit.as_str()) {
+        ("", _) => Some(value),
+        ("k", "hz") | ("k", "ms") | ("k", "s") => Some(value * 1_000.0),
+        (unit, range_unit) if unit == range_unit => Some(value),
+        ("khz", "hz") | ("s", "ms") => Some(value * 1_000.0),
+        ("hz", "khz") | ("ms", "s") => Some(value / 1_000.0),
+        _ => None,
+    }
+}
+
+/// Formats a value with the given number of decimal places, without
+/// showing values that round to zero as `-0`.
+fn format_decimal(value: f32, precision: usize) -> String {
+    let text = format!("{:.*}", precision, value);
+
+    if text.starts_with('-') && text[1..].chars().all(|c| c == '0' || c == '.')
+    {
+        text[1..].to_string()
+    } else {
+        text
+    }
+}
+
+/// Appends a unit to a formatted value, separated by a space.
+fn with_unit(text: String, unit: &str) -> String {
+    if unit.is_empty() {
+        text
+    } else {
+        format!("{} {}", text, unit)
+    }
+}
+
+static NOTE_NAMES: [&str; 12] = [
+    "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+];
+
+/// Returns the name of a MIDI note, where `60` is `"C4"`.
+fn note_name(note: i32) -> String {
+    format!(
+        "{}{}",
+        NOTE_NAMES[note.rem_euclid(12) as usize],
+        note.div_euclid(12) - 1
+    )
+}
+
+/// Parses the name of a MIDI note such as `"C4"`, `"f#2"`, or `"Bb-1"`.
+fn parse_note_name(text: &str) -> Option<i32> {
+    let text = text.trim();
+    let mut chars = text.chars();
+
+    let mut note = match chars.next()?.to_ascii_uppercase() {
+        'C' => 0,
+        'D' => 2,
+        'E' => 4,
+        'F' => 5,
+        'G' => 7,
+        'A' => 9,
+        'B' => 11,
+        _ => return None,
+    };
+
+    let rest = chars.as_str();
+    let octave = if let Some(rest) = rest.strip_prefix('#') {
+        note += 1;
+        rest
+    } else if let Some(rest) = rest.strip_prefix('b') {
+        note -= 1;
+        rest
+    } else {
+        rest
+    };
+
+    let octave: i32 = octave.trim().parse().ok()?;
+
+    Some(note + ((octave + 1) * 12))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn float_range_converts_seconds_and_milliseconds() {
+        let ms_range = FloatRange::new(0.0, 2_000.0).with_unit("ms");
+
+        assert_eq!(
+            ms_range.parse("1.5 s"),
+            Some(ms_range.map_to_normal(1_500.0))
+        );
+        assert_eq!(
+            ms_range.parse("1500ms"),
+            Some(ms_range.map_to_normal(1_500.0))
+        );
+
+        let s_range = FloatRange::new(0.0, 2.0).with_unit("s");
+
+        assert_eq!(s_range.parse("500 ms"), Some(s_range.map_to_normal(0.5)));
+    }
+
+    #[test]
+    fn float_range_rejects_other_units() {
+        let range = FloatRange::new(0.0, 500.0);
+
+        assert_eq!(range.parse("440 dB"), None);
+        assert_eq!(range.parse("3 Hz"), None);
+        assert_eq!(range.parse("3 kms"), None);
+        assert_eq!(range.parse("0.25k"), None);
+
+        let ms_range = FloatRange::new(0.0, 2_000.0).with_unit("ms");
+
+        assert_eq!(
+            ms_range.parse("1.5k"),
+            Some(ms_range.map_to_normal(1_500.0))
+        );
+
+        let percent_range = FloatRange::new(0.0, 1.0).as_percent();
+
+        assert_eq!(percent_range.parse("50%"), Some(Normal::new(0.5)));
+        assert_eq!(percent_range.parse("50 ms"), None);
+    }
+
+    #[test]
+    fn int_range_checks_unit() {
+        let range = IntRange::new(-24, 24).with_unit("st");
+
+        assert_eq!(range.parse("7 st"), Some(range.map_to_normal(7)));
+        assert_eq!(range.parse("7"), Some(range.map_to_normal(7)));
+        assert_eq!(range.parse("7 ms"), None);
+    }
+
+    #[test]
+    fn log_db_range_only_accepts_db() {
+        let range = LogDBRange::new(-12.0, 12.0, 0.5.into());
+
+        assert_eq!(range.parse("6 dB"), Some(range.map_to_normal(6.0)));
+        assert_eq!(range.parse("12 kdb"), None);
+        assert_eq!(range.parse("6k"), None);
+        assert_eq!(range.parse("6 Hz"), None);
+    }
+
+    #[test]
+    fn freq_range_applies_kilo_only_to_hz() {
+        let range = FreqRange::new(20.0, 20_000.0);
+
+        assert_eq!(range.parse("2.5 kHz"), Some(range.map_to_normal(2_500.0)));
+        assert_eq!(range.parse("2.5k"), Some(range.map_to_normal(2_500.0)));
+        assert_eq!(range.parse("440 Hz"), Some(range.map_to_normal(440.0)));
+        assert_eq!(range.parse("2.5 kdb"), None);
+        assert_eq!(range.parse("440 s"), None);
+    }
+}
@@ -0,0 +1,275 @@
+//! Display an ADSR envelope with optional delay and hold stages. Each stage
+//! controls a [`NormalParam`], and the attack, decay, and release segments
+//! can be curved.
+//!
+//! [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
+
+use crate::core::Normal;
+use crate::graphics::envelope_editor::curve_control_point;
+use crate::native::adsr_envelope;
+use iced_graphics::canvas::{path, Frame, LineJoin, Path, Stroke};
+use iced_graphics::{
+    Backend, HorizontalAlignment, Primitive, Renderer, VerticalAlignment,
+};
+use iced_native::{mouse, Background, Point, Rectangle, Size, Vector};
+
+pub use crate::native::adsr_envelope::{Param, Shape, State, Target};
+pub use crate::style::adsr_envelope::{
+    HandleStyle, Style, StyleSheet, ValueTextStyle,
+};
+
+/// An ADSR envelope GUI widget with optional delay and hold stages.
+pub type ADSREnvelope<'a, Message, Backend> =
+    adsr_envelope::ADSREnvelope<'a, Message, Renderer<Backend>>;
+
+impl<B: Backend> adsr_envelope::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        shape: &Shape,
+        active_target: Option<Target>,
+        is_dragging: bool,
+        label: Option<String>,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if is_dragging {
+            style_sheet.dragging()
+        } else if is_mouse_over {
+            style_sheet.hovered()
+        } else {
+            style_sheet.active()
+        };
+
+        let bounds_x = bounds.x.floor();
+        let bounds_y = bounds.y.floor();
+
+        let bounds_width = bounds.width.floor();
+        let bounds_height = bounds.height.floor();
+
+        let back = Primitive::Quad {
+            bounds: Rectangle {
+                x: bounds_x,
+                y: bounds_y,
+                width: bounds_width,
+                height: bounds_height,
+            },
+            background: Background::Color(style.back_color),
+            border_radius: 0.0,
+            border_width: style.back_border_width,
+            border_color: style.back_border_color,
+        };
+
+        if bounds_width <= 0.0 || bounds_height <= 0.0 {
+            return (back, mouse::Interaction::default());
+        }
+
+        // Draw relative to the top-left corner of the widget.
+        let origin = Vector::new(bounds.x, bounds.y);
+        let local = |point: Point| -> Point { point - origin };
+
+        let mut frame = Frame::new(Size::new(bounds_width, bounds_height));
+
+        if let Some(fill_color) = style.fill_color {
+            let fill = Path::new(|p| {
+                p.move_to(local(shape.start));
+                add_shape(p, shape, &local);
+                p.close();
+            });
+
+            frame.fill(&fill, fill_color);
+        }
+
+        let line = Path::new(|p| {
+            p.move_to(local(shape.start));
+            add_shape(p, shape, &local);
+        });
+
+        frame.stroke(
+            &line,
+            Stroke {
+                width: style.line_width,
+                color: style.line_color,
+                line_join: LineJoin::Round,
+                ..Stroke::default()
+            },
+        );
+
+        if let Some(Target::Segment(param)) = active_target {
+            if let Some((from, to)) = shape.segment(param) {
+                let curve = match param {
+                    Param::AttackCurve => shape.attack_curve,
+                    Param::DecayCurve => shape.decay_curve,
+                    Param::ReleaseCurve => shape.release_curve,
+                    _ => Normal::center(),
+                };
+
+                let segment = Path::new(|p| {
+                    p.move_to(local(from));
+                    add_segment(p, local(from), local(to), curve);
+                });
+
+                frame.stroke(
+                    &segment,
+                    Stroke {
+                        width: style.line_width,
+                        color: style.active_line_color,
+                        line_join: LineJoin::Round,
+                        ..Stroke::default()
+                    },
+                );
+            }
+        }
+
+        let lines = Primitive::Translate {
+            translation: Vector::new(bounds_x, bounds_y),
+            content: Box::new(frame.into_geometry().into_primitive()),
+        };
+
+        let mut primitives = vec![back, lines];
+
+        for param in [
+            Param::Delay,
+            Param::Attack,
+            Param::Hold,
+            Param::Decay,
+            Param::Release,
+        ]
+        .iter()
+        {
+            if let Some(point) = shape.handle(*param) {
+                let handle_style =
+                    if active_target == Some(Target::Handle(*param)) {
+                        &style.active_handle
+                    } else {
+                        &style.handle
+                    };
+
+                primitives.push(draw_handle(point, handle_style));
+            }
+        }
+
+        if let Some(label) = label {
+            if let Some(text_style) = &style.value_text {
+                let anchor = match active_target {
+                    Some(Target::Handle(param)) => shape.handle(param),
+                    Some(Target::Segment(param)) => {
+                        shape.segment(param).map(|(from, to)| {
+                            Point::new((from.x + to.x) / 2.0, from.y.min(to.y))
+                        })
+                    }
+                    None => None,
+                };
+
+                if let Some(anchor) = anchor {
+                    primitives
+                        .push(draw_label(&bounds, anchor, label, text_style));
+                }
+            }
+        }
+
+        (
+            Primitive::Group { primitives },
+            mouse::Interaction::default(),
+        )
+    }
+}
+
+fn add_shape<F: Fn(Point) -> Point>(
+    p: &mut path::Builder,
+    shape: &Shape,
+    local: &F,
+) {
+    if let Some(delay) = shape.delay {
+        p.line_to(local(delay));
+    }
+
+    let attack_start = local(shape.delay.unwrap_or(shape.start));
+    add_segment(p, attack_start, local(shape.attack), shape.attack_curve);
+
+    if let Some(hold) = shape.hold {
+        p.line_to(local(hold));
+    }
+
+    let decay_start = local(shape.hold.unwrap_or(shape.attack));
+    add_segment(p, decay_start, local(shape.decay), shape.decay_curve);
+
+    p.line_to(local(shape.sustain));
+
+    add_segment(
+        p,
+        local(shape.sustain),
+        local(shape.release),
+        shape.release_curve,
+    );
+}
+
+fn add_segment(p: &mut path::Builder, from: Point, to: Point, curve: Normal) {
+    let curve_f32 = curve.as_f32();
+
+    if curve_f32 > 0.499 && curve_f32 < 0.501 {
+        p.line_to(to);
+    } else {
+        p.quadratic_curve_to(curve_control_point(from, to, curve), to);
+    }
+}
+
+fn draw_handle(center: Point, style: &HandleStyle) -> Primitive {
+    let radius = style.diameter / 2.0;
+
+    Primitive::Quad {
+        bounds: Rectangle {
+            x: (center.x - radius).round(),
+            y: (center.y - radius).round(),
+            width: style.diameter,
+            height: style.diameter,
+        },
+        background: Background::Color(style.color),
+        border_radius: radius,
+        border_width: style.border_width,
+        border_color: style.border_color,
+    }
+}
+
+fn draw_label(
+    bounds: &Rectangle,
+    anchor: Point,
+    label: String,
+    style: &ValueTextStyle,
+) -> Primitive {
+    let text_size = f32::from(style.text_size);
+
+    // Keep the text inside the widget. The width of the text is estimated
+    // from the number of characters.
+    let half_text_width = label.chars().count() as f32 * text_size * 0.3;
+    let x = anchor
+        .x
+        .max(bounds.x + half_text_width)
+        .min(bounds.x + bounds.width - half_text_width);
+
+    let (y, vertical_alignment) =
+        if anchor.y - style.gap - text_size >= bounds.y {
+            (anchor.y - style.gap, VerticalAlignment::Bottom)
+        } else {
+            (anchor.y + style.gap, VerticalAlignment::Top)
+        };
+
+    Primitive::Text {
+        content: label,
+        size: text_size,
+        bounds: Rectangle {
+            x: x.round(),
+            y: y.round(),
+            width: bounds.width,
+            height: text_size,
+        },
+        color: style.color,
+        font: style.font,
+        horizontal_alignment: HorizontalAlignment::Center,
+        vertical_alignment,
+    }
+}
@@ -0,0 +1,463 @@
+//! Display a bar meter that displays the level of one or two (stereo)
+//! audio channels, with an optional peak-hold line and a clip indicator.
+
+use crate::graphics::{text_marks, tick_marks};
+use crate::native::bar_meter;
+use iced_graphics::{Backend, Primitive, Renderer};
+use iced_native::{mouse, Background, Color, Point, Rectangle};
+
+pub use crate::native::bar_meter::{ChannelLevel, Orientation, State};
+pub use crate::style::bar_meter::{
+    ClipMarkerStyle, ColorZone, PeakLineStyle, Style, StyleSheet,
+    TextMarksStyle, TickMarksStyle,
+};
+
+/// A bar meter GUI widget that displays the level of one or two (stereo)
+/// channels.
+pub type BarMeter<'a, Message, Backend> =
+    bar_meter::BarMeter<'a, Message, Renderer<Backend>>;
+
+impl<B: Backend> bar_meter::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        orientation: Orientation,
+        rail_inset: f32,
+        left: ChannelLevel,
+        right: Option<ChannelLevel>,
+        clipped: bool,
+        tick_marks: Option<&tick_marks::Group>,
+        text_marks: Option<&text_marks::Group>,
+        style_sheet: &Self::Style,
+        tick_marks_cache: &tick_marks::PrimitiveCache,
+        text_marks_cache: &text_marks::PrimitiveCache,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if is_mouse_over {
+            style_sheet.hovered()
+        } else {
+            style_sheet.active()
+        };
+
+        let bounds = Rectangle {
+            x: bounds.x.round(),
+            y: bounds.y.round(),
+            width: bounds.width.round(),
+            height: bounds.height.round(),
+        };
+
+        let rail_inset = rail_inset.max(0.0).round();
+
+        let (meter_bounds, clip_marker) =
+            if let Some(clip_style) = &style.clip_marker {
+                let offset = clip_style.length + clip_style.gap;
+
+                let (meter_bounds, marker_bounds) = match orientation {
+                    Orientation::Vertical => (
+                        Rectangle {
+                            x: bounds.x,
+                            y: bounds.y + offset,
+                            width: bounds.width,
+                            height: bounds.height - offset,
+                        },
+                        Rectangle {
+                            x: bounds.x,
+                            y: bounds.y,
+                            width: bounds.width,
+                            height: clip_style.length,
+                        },
+                    ),
+                    Orientation::Horizontal => (
+                        Rectangle {
+                            x: bounds.x,
+                            y: bounds.y,
+                            width: bounds.width - offset,
+                            height: bounds.height,
+                        },
+                        Rectangle {
+                            x: bounds.x + bounds.width - clip_style.length,
+                            y: bounds.y,
+                            width: clip_style.length,
+                            height: bounds.height,
+                        },
+                    ),
+                };
+
+                let marker_color = if clipped {
+                    clip_style.on_color
+                } else {
+                    clip_style.off_color
+                };
+
+                (
+                    meter_bounds,
+                    Primitive::Quad {
+                        bounds: marker_bounds,
+                        background: Background::Color(marker_color),
+                        border_radius: 0.0,
+                        border_width: style.back_border_width,
+                        border_color: style.back_border_color,
+                    },
+                )
+            } else {
+                (bounds, Primitive::None)
+            };
+
+        let meter_bounds =
+            inset_meter_bounds(&bounds, &meter_bounds, orientation, rail_inset);
+
+        let tick_marks = draw_tick_marks(
+            &meter_bounds,
+            orientation,
+            tick_marks,
+            &style_sheet.tick_marks_style(),
+            tick_marks_cache,
+        );
+        let text_marks = draw_text_marks(
+            &meter_bounds,
+            orientation,
+            text_marks,
+            &style_sheet.text_marks_style(),
+            text_marks_cache,
+        );
+
+        let back = Primitive::Quad {
+            bounds: meter_bounds,
+            background: Background::Color(style.back_color),
+            border_radius: 0.0,
+            border_width: style.back_border_width,
+            border_color: style.back_border_color,
+        };
+
+        let border_width = style.back_border_width;
+        let twice_border_width = border_width * 2.0;
+
+        let inner_bounds = Rectangle {
+            x: meter_bounds.x + border_width,
+            y: meter_bounds.y + border_width,
+            width: meter_bounds.width - twice_border_width,
+            height: meter_bounds.height - twice_border_width,
+        };
+
+        let mut primitives: Vec<Primitive> = vec![tick_marks, text_marks, back];
+
+        if let Some(right) = right {
+            let (left_bounds, gap_bounds, right_bounds) =
+                split_channel_bounds(&inner_bounds, orientation, &style);
+
+            draw_channel(
+                &mut primitives,
+                &left_bounds,
+                orientation,
+                left,
+                &style,
+            );
+
+            primitives.push(Primitive::Quad {
+                bounds: gap_bounds,
+                background: Background::Color(style.inner_gap_color),
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            });
+
+            draw_channel(
+                &mut primitives,
+                &right_bounds,
+                orientation,
+                right,
+                &style,
+            );
+        } else {
+            draw_channel(
+                &mut primitives,
+                &inner_bounds,
+                orientation,
+                left,
+                &style,
+            );
+        }
+
+        primitives.push(clip_marker);
+
+        (
+            Primitive::Group { primitives },
+            mouse::Interaction::default(),
+        )
+    }
+}
+
+/// Insets the meter from each end of `bounds` by `rail_inset`. The clip
+/// marker sits inside the inset at the end of the meter when it fits.
+fn inset_meter_bounds(
+    bounds: &Rectangle,
+    meter_bounds: &Rectangle,
+    orientation: Orientation,
+    rail_inset: f32,
+) -> Rectangle {
+    match orientation {
+        Orientation::Vertical => {
+            let top = meter_bounds.y.max(bounds.y + rail_inset);
+            let bottom = bounds.y + bounds.height - rail_inset;
+
+            Rectangle {
+                y: top,
+                height: (bottom - top).max(0.0),
+                ..*meter_bounds
+            }
+        }
+        Orientation::Horizontal => {
+            let left = bounds.x + rail_inset;
+            let right = (meter_bounds.x + meter_bounds.width)
+                .min(bounds.x + bounds.width - rail_inset);
+
+            Rectangle {
+                x: left,
+                width: (right - left).max(0.0),
+                ..*meter_bounds
+            }
+        }
+    }
+}
+
+fn split_channel_bounds(
+    bounds: &Rectangle,
+    orientation: Orientation,
+    style: &Style,
+) -> (Rectangle, Rectangle, Rectangle) {
+    match orientation {
+        Orientation::Vertical => {
+            let channel_width =
+                ((bounds.width - style.inner_gap) / 2.0).floor();
+
+            (
+                Rectangle {
+                    width: channel_width,
+                    ..*bounds
+                },
+                Rectangle {
+                    x: bounds.x + channel_width,
+                    width: bounds.width - (channel_width * 2.0),
+                    ..*bounds
+                },
+                Rectangle {
+                    x: bounds.x + bounds.width - channel_width,
+                    width: channel_width,
+                    ..*bounds
+                },
+            )
+        }
+        Orientation::Horizontal => {
+            let channel_height =
+                ((bounds.height - style.inner_gap) / 2.0).floor();
+
+            (
+                Rectangle {
+                    height: channel_height,
+                    ..*bounds
+                },
+                Rectangle {
+                    y: bounds.y + channel_height,
+                    height: bounds.height - (channel_height * 2.0),
+                    ..*bounds
+                },
+                Rectangle {
+                    y: bounds.y + bounds.height - channel_height,
+                    height: channel_height,
+                    ..*bounds
+                },
+            )
+        }
+    }
+}
+
+/// Returns the section of `bounds` between the `start` and `end`
+/// positions along the axis of the meter.
+fn section_bounds(
+    bounds: &Rectangle,
+    orientation: Orientation,
+    start: f32,
+    end: f32,
+) -> Rectangle {
+    match orientation {
+        Orientation::Vertical => {
+            let y = (bounds.y + (bounds.height * (1.0 - end))).round();
+            let bottom = (bounds.y + (bounds.height * (1.0 - start))).round();
+
+            Rectangle {
+                x: bounds.x,
+                y,
+                width: bounds.width,
+                height: bottom - y,
+            }
+        }
+        Orientation::Horizontal => {
+            let x = (bounds.x + (bounds.width * start)).round();
+            let right = (bounds.x + (bounds.width * end)).round();
+
+            Rectangle {
+                x,
+                y: bounds.y,
+                width: right - x,
+                height: bounds.height,
+            }
+        }
+    }
+}
+
+fn draw_channel(
+    primitives: &mut Vec<Primitive>,
+    bounds: &Rectangle,
+    orientation: Orientation,
+    channel: ChannelLevel,
+    style: &Style,
+) {
+    let level = channel.level.as_f32();
+
+    for (i, zone) in style.color_zones.iter().enumerate() {
+        let start = zone.start.as_f32();
+
+        if start >= level {
+            break;
+        }
+
+        let end = if let Some(next_zone) = style.color_zones.get(i + 1) {
+            next_zone.start.as_f32().min(level)
+        } else {
+            level
+        };
+
+        if end > start {
+            primitives.push(Primitive::Quad {
+                bounds: section_bounds(bounds, orientation, start, end),
+                background: Background::Color(zone.color),
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            });
+        }
+    }
+
+    if let Some(peak) = channel.peak {
+        if let Some(peak_style) = &style.peak_line {
+            if peak.as_f32() > 0.0 {
+                let peak_bounds = match orientation {
+                    Orientation::Vertical => {
+                        let y = (bounds.y + peak.scale_inv(bounds.height)
+                            - (peak_style.width / 2.0))
+                            .round()
+                            .max(bounds.y)
+                            .min(bounds.y + bounds.height - peak_style.width);
+
+                        Rectangle {
+                            x: bounds.x,
+                            y,
+                            width: bounds.width,
+                            height: peak_style.width,
+                        }
+                    }
+                    Orientation::Horizontal => {
+                        let x = (bounds.x + peak.scale(bounds.width)
+                            - (peak_style.width / 2.0))
+                            .round()
+                            .max(bounds.x)
+                            .min(bounds.x + bounds.width - peak_style.width);
+
+                        Rectangle {
+                            x,
+                            y: bounds.y,
+                            width: peak_style.width,
+                            height: bounds.height,
+                        }
+                    }
+                };
+
+                primitives.push(Primitive::Quad {
+                    bounds: peak_bounds,
+                    background: Background::Color(peak_style.color),
+                    border_radius: 0.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+        }
+    }
+}
+
+fn draw_tick_marks(
+    bounds: &Rectangle,
+    orientation: Orientation,
+    tick_marks: Option<&tick_marks::Group>,
+    tick_marks_style: &Option<TickMarksStyle>,
+    tick_marks_cache: &tick_marks::PrimitiveCache,
+) -> Primitive {
+    if let Some(tick_marks) = tick_marks {
+        if let Some(style) = tick_marks_style {
+            match orientation {
+                Orientation::Vertical => tick_marks::draw_vertical_tick_marks(
+                    bounds,
+                    tick_marks,
+                    &style.style,
+                    &style.placement,
+                    false,
+                    tick_marks_cache,
+                ),
+                Orientation::Horizontal => {
+                    tick_marks::draw_horizontal_tick_marks(
+                        bounds,
+                        tick_marks,
+                        &style.style,
+                        &style.placement,
+                        false,
+                        tick_marks_cache,
+                    )
+                }
+            }
+        } else {
+            Primitive::None
+        }
+    } else {
+        Primitive::None
+    }
+}
+
+fn draw_text_marks(
+    bounds: &Rectangle,
+    orientation: Orientation,
+    text_marks: Option<&text_marks::Group>,
+    text_marks_style: &Option<TextMarksStyle>,
+    text_marks_cache: &text_marks::PrimitiveCache,
+) -> Primitive {
+    if let Some(text_marks) = text_marks {
+        if let Some(style) = text_marks_style {
+            match orientation {
+                Orientation::Vertical => text_marks::draw_vertical_text_marks(
+                    bounds,
+                    text_marks,
+                    &style.style,
+                    &style.placement,
+                    false,
+                    text_marks_cache,
+                ),
+                Orientation::Horizontal => {
+                    text_marks::draw_horizontal_text_marks(
+                        bounds,
+                        text_marks,
+                        &style.style,
+                        &style.placement,
+                        false,
+                        text_marks_cache,
+                    )
+                }
+            }
+        } else {
+            Primitive::None
+        }
+    } else {
+        Primitive::None
+    }
+}
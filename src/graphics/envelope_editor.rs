@@ -0,0 +1,232 @@
+//! Display an envelope editor of adjustable points connected by straight or
+//! curved lines. It is usually used to represent automation, envelopes, and
+//! LFO shapes.
+
+use crate::core::Normal;
+use crate::native::envelope_editor;
+use iced_graphics::canvas::{path, Frame, LineJoin, Path, Stroke};
+use iced_graphics::{Backend, Primitive, Renderer};
+use iced_native::{mouse, Background, Point, Rectangle, Size, Vector};
+
+pub use crate::native::envelope_editor::{
+    DragTarget, Edit, EnvelopePoint, State,
+};
+pub use crate::style::envelope_editor::{HandleStyle, Style, StyleSheet};
+
+/// An envelope editor GUI widget of adjustable points connected by straight
+/// or curved lines. It is usually used to represent automation, envelopes,
+/// and LFO shapes.
+pub type EnvelopeEditor<'a, Message, Backend> =
+    envelope_editor::EnvelopeEditor<'a, Message, Renderer<Backend>>;
+
+impl<B: Backend> envelope_editor::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        points: &[EnvelopePoint],
+        hovered_point: Option<usize>,
+        drag_target: Option<DragTarget>,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if drag_target.is_some() {
+            style_sheet.dragging()
+        } else if is_mouse_over {
+            style_sheet.hovered()
+        } else {
+            style_sheet.active()
+        };
+
+        let bounds_x = bounds.x.floor();
+        let bounds_y = bounds.y.floor();
+
+        let bounds_width = bounds.width.floor();
+        let bounds_height = bounds.height.floor();
+
+        let back = Primitive::Quad {
+            bounds: Rectangle {
+                x: bounds_x,
+                y: bounds_y,
+                width: bounds_width,
+                height: bounds_height,
+            },
+            background: Background::Color(style.back_color),
+            border_radius: 0.0,
+            border_width: style.back_border_width,
+            border_color: style.back_border_color,
+        };
+
+        if points.is_empty() || bounds_width <= 0.0 || bounds_height <= 0.0 {
+            return (back, mouse::Interaction::default());
+        }
+
+        let to_point = |point: &EnvelopePoint| -> Point {
+            Point::new(
+                point.x.scale(bounds_width),
+                point.y.scale_inv(bounds_height),
+            )
+        };
+
+        let mut frame = Frame::new(Size::new(bounds_width, bounds_height));
+
+        if let Some(fill_color) = style.fill_color {
+            let fill = Path::new(|p| {
+                let first = to_point(&points[0]);
+
+                p.move_to(Point::new(first.x, bounds_height));
+                p.line_to(first);
+                add_segments(p, points, &to_point);
+                p.line_to(Point::new(
+                    to_point(&points[points.len() - 1]).x,
+                    bounds_height,
+                ));
+                p.close();
+            });
+
+            frame.fill(&fill, fill_color);
+        }
+
+        let line = Path::new(|p| {
+            p.move_to(to_point(&points[0]));
+            add_segments(p, points, &to_point);
+        });
+
+        frame.stroke(
+            &line,
+            Stroke {
+                width: style.line_width,
+                color: style.line_color,
+                line_join: LineJoin::Round,
+                ..Stroke::default()
+            },
+        );
+
+        if let Some(DragTarget::Segment(index)) = drag_target {
+            if index + 1 < points.len() {
+                let segment = Path::new(|p| {
+                    p.move_to(to_point(&points[index]));
+                    add_segments(p, &points[index..=index + 1], &to_point);
+                });
+
+                frame.stroke(
+                    &segment,
+                    Stroke {
+                        width: style.line_width,
+                        color: style.active_line_color,
+                        line_join: LineJoin::Round,
+                        ..Stroke::default()
+                    },
+                );
+            }
+        }
+
+        let lines = Primitive::Translate {
+            translation: Vector::new(bounds_x, bounds_y),
+            content: Box::new(frame.into_geometry().into_primitive()),
+        };
+
+        let active_point = match drag_target {
+            Some(DragTarget::Point(index)) => Some(index),
+            _ => hovered_point,
+        };
+
+        let mut primitives = vec![back, lines];
+
+        for (i, point) in points.iter().enumerate() {
+            let handle_style = if Some(i) == active_point {
+                &style.active_handle
+            } else {
+                &style.handle
+            };
+
+            let center = to_point(point);
+
+            primitives.push(draw_handle(
+                Point::new(bounds_x + center.x, bounds_y + center.y),
+                handle_style,
+            ));
+        }
+
+        (
+            Primitive::Group { primitives },
+            mouse::Interaction::default(),
+        )
+    }
+}
+
+fn add_segments<F: Fn(&EnvelopePoint) -> Point>(
+    p: &mut path::Builder,
+    points: &[EnvelopePoint],
+    to_point: &F,
+) {
+    for pair in points.windows(2) {
+        let from = to_point(&pair[0]);
+        let to = to_point(&pair[1]);
+
+        let curve = pair[0].curve.as_f32();
+
+        if curve > 0.499 && curve < 0.501 {
+            p.line_to(to);
+        } else {
+            p.quadratic_curve_to(
+                curve_control_point(from, to, pair[0].curve),
+                to,
+            );
+        }
+    }
+}
+
+/// Returns the control point of a quadratic curve between two points.
+///
+/// A `curve` of `0.5` is a straight line. Values above `0.5` bend the line
+/// towards the upper corner of the rectangle spanned by the two points, and
+/// values below `0.5` bend it towards the lower corner.
+pub(crate) fn curve_control_point(
+    from: Point,
+    to: Point,
+    curve: Normal,
+) -> Point {
+    let middle = Point::new((from.x + to.x) / 2.0, (from.y + to.y) / 2.0);
+
+    let (upper, lower) = if from.y <= to.y {
+        (from, to)
+    } else {
+        (to, from)
+    };
+
+    let bend = (curve.as_f32() - 0.5) * 2.0;
+
+    let corner = if bend > 0.0 {
+        Point::new(lower.x, upper.y)
+    } else {
+        Point::new(upper.x, lower.y)
+    };
+
+    let amount = bend.abs();
+
+    Point::new(
+        middle.x + ((corner.x - middle.x) * amount),
+        middle.y + ((corner.y - middle.y) * amount),
+    )
+}
+
+fn draw_handle(center: Point, style: &HandleStyle) -> Primitive {
+    let radius = style.diameter / 2.0;
+
+    Primitive::Quad {
+        bounds: Rectangle {
+            x: (center.x - radius).round(),
+            y: (center.y - radius).round(),
+            width: style.diameter,
+            height: style.diameter,
+        },
+        background: Background::Color(style.color),
+        border_radius: radius,
+        border_width: style.border_width,
+        border_color: style.border_color,
+    }
+}
@@ -0,0 +1,43 @@
+//! Draw filmstrip (sprite sheet) textures
+
+use iced_graphics::Primitive;
+use iced_native::{Rectangle, Vector};
+
+use crate::core::Normal;
+
+pub use crate::style::filmstrip::*;
+
+/// Draws the frame of a filmstrip texture for the given normal value,
+/// stretched to `bounds`.
+pub fn draw_filmstrip(
+    bounds: &Rectangle,
+    normal: Normal,
+    style: &Style,
+) -> Primitive {
+    let frames = f32::from(style.frames.max(1));
+    let frame = f32::from(style.frame(normal.as_f32()));
+
+    let texture_bounds = match style.orientation {
+        Orientation::Horizontal => Rectangle {
+            x: bounds.x - (frame * bounds.width),
+            y: bounds.y,
+            width: bounds.width * frames,
+            height: bounds.height,
+        },
+        Orientation::Vertical => Rectangle {
+            x: bounds.x,
+            y: bounds.y - (frame * bounds.height),
+            width: bounds.width,
+            height: bounds.height * frames,
+        },
+    };
+
+    Primitive::Clip {
+        bounds: *bounds,
+        offset: Vector::new(0, 0),
+        content: Box::new(Primitive::Image {
+            handle: style.texture.clone(),
+            bounds: texture_bounds,
+        }),
+    }
+}
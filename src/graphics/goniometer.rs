@@ -0,0 +1,253 @@
+//! Display a goniometer that plots the stereo phase of an audio signal,
+//! along with a correlation meter.
+
+use crate::native::goniometer;
+use iced_graphics::canvas::{Frame, LineJoin, Path, Stroke};
+use iced_graphics::{Backend, Primitive, Renderer};
+use iced_native::{mouse, Background, Color, Point, Rectangle, Size, Vector};
+
+pub use crate::native::goniometer::State;
+pub use crate::style::goniometer::{CorrelationStyle, Style, StyleSheet};
+
+/// A goniometer GUI widget that plots the stereo phase of an audio signal,
+/// along with a correlation meter.
+pub type Goniometer<'a, Backend> =
+    goniometer::Goniometer<'a, Renderer<Backend>>;
+
+/// The number of steps the trace fades out in
+static FADE_STEPS: usize = 8;
+
+impl<B: Backend> goniometer::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        points: &[Point],
+        correlation: f32,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if is_mouse_over {
+            style_sheet.hovered()
+        } else {
+            style_sheet.active()
+        };
+
+        let bounds_x = bounds.x.floor();
+        let bounds_y = bounds.y.floor();
+
+        let bounds_width = bounds.width.floor();
+        let bounds_height = bounds.height.floor();
+
+        let plot_height = if let Some(correlation_style) = &style.correlation {
+            (bounds_height - correlation_style.height - correlation_style.gap)
+                .max(0.0)
+        } else {
+            bounds_height
+        };
+
+        let back = Primitive::Quad {
+            bounds: Rectangle {
+                x: bounds_x,
+                y: bounds_y,
+                width: bounds_width,
+                height: plot_height,
+            },
+            background: Background::Color(style.back_color),
+            border_radius: 0.0,
+            border_width: style.back_border_width,
+            border_color: style.back_border_color,
+        };
+
+        let mut primitives: Vec<Primitive> = vec![back];
+
+        if let Some(correlation_style) = &style.correlation {
+            draw_correlation_meter(
+                &mut primitives,
+                Rectangle {
+                    x: bounds_x,
+                    y: bounds_y + bounds_height - correlation_style.height,
+                    width: bounds_width,
+                    height: correlation_style.height,
+                },
+                correlation,
+                correlation_style,
+            );
+        }
+
+        let border_width = style.back_border_width;
+        let twice_border_width = border_width * 2.0;
+
+        let range_width = bounds_width - twice_border_width;
+        let range_height = plot_height - twice_border_width;
+
+        if range_width > 0.0 && range_height > 0.0 {
+            let radius = range_width.min(range_height) / 2.0;
+            let center = Point::new(range_width / 2.0, range_height / 2.0);
+
+            let to_frame = |point: &Point| -> Point {
+                Point::new(
+                    center.x + (point.x * radius),
+                    center.y - (point.y * radius),
+                )
+            };
+
+            let mut frame = Frame::new(Size::new(range_width, range_height));
+
+            if let Some(guide_color) = style.guide_color {
+                let diagonal = radius * std::f32::consts::FRAC_1_SQRT_2;
+
+                let guides = Path::new(|p| {
+                    // Mid
+                    p.move_to(Point::new(center.x, center.y - radius));
+                    p.line_to(Point::new(center.x, center.y + radius));
+                    // Side
+                    p.move_to(Point::new(center.x - radius, center.y));
+                    p.line_to(Point::new(center.x + radius, center.y));
+                    // Left
+                    p.move_to(Point::new(
+                        center.x - diagonal,
+                        center.y - diagonal,
+                    ));
+                    p.line_to(Point::new(
+                        center.x + diagonal,
+                        center.y + diagonal,
+                    ));
+                    // Right
+                    p.move_to(Point::new(
+                        center.x + diagonal,
+                        center.y - diagonal,
+                    ));
+                    p.line_to(Point::new(
+                        center.x - diagonal,
+                        center.y + diagonal,
+                    ));
+                });
+
+                frame.stroke(
+                    &guides,
+                    Stroke {
+                        width: style.guide_width,
+                        color: guide_color,
+                        ..Stroke::default()
+                    },
+                );
+            }
+
+            if points.len() > 1 {
+                let steps = FADE_STEPS.min(points.len() - 1);
+                let step_len = (points.len() - 1) as f32 / steps as f32;
+
+                for step in 0..steps {
+                    let start = (step as f32 * step_len) as usize;
+                    let end = (((step + 1) as f32 * step_len) as usize)
+                        .min(points.len() - 1);
+
+                    if start >= end {
+                        continue;
+                    }
+
+                    let alpha = style.persistence_alpha
+                        + ((1.0 - style.persistence_alpha)
+                            * ((step + 1) as f32 / steps as f32));
+
+                    let trace = Path::new(|p| {
+                        p.move_to(to_frame(&points[start]));
+
+                        for point in points[start + 1..=end].iter() {
+                            p.line_to(to_frame(point));
+                        }
+                    });
+
+                    frame.stroke(
+                        &trace,
+                        Stroke {
+                            width: style.line_width,
+                            color: Color {
+                                a: style.line_color.a * alpha,
+                                ..style.line_color
+                            },
+                            line_join: LineJoin::Round,
+                            ..Stroke::default()
+                        },
+                    );
+                }
+            }
+
+            primitives.push(Primitive::Translate {
+                translation: Vector::new(
+                    bounds_x + border_width,
+                    bounds_y + border_width,
+                ),
+                content: Box::new(frame.into_geometry().into_primitive()),
+            });
+        }
+
+        (
+            Primitive::Group { primitives },
+            mouse::Interaction::default(),
+        )
+    }
+}
+
+fn draw_correlation_meter(
+    primitives: &mut Vec<Primitive>,
+    bounds: Rectangle,
+    correlation: f32,
+    style: &CorrelationStyle,
+) {
+    if bounds.width <= 0.0 || bounds.height <= 0.0 {
+        return;
+    }
+
+    primitives.push(Primitive::Quad {
+        bounds,
+        background: Background::Color(style.back_color),
+        border_radius: 0.0,
+        border_width: style.back_border_width,
+        border_color: style.back_border_color,
+    });
+
+    let inner_x = bounds.x + style.back_border_width;
+    let inner_width = bounds.width - (style.back_border_width * 2.0);
+    let center_x = inner_x + (inner_width / 2.0);
+
+    let bar_width = (correlation.clamp(-1.0, 1.0) * inner_width / 2.0).round();
+
+    if bar_width != 0.0 {
+        let (x, color) = if bar_width > 0.0 {
+            (center_x, style.positive_color)
+        } else {
+            (center_x + bar_width, style.negative_color)
+        };
+
+        primitives.push(Primitive::Quad {
+            bounds: Rectangle {
+                x: x.round(),
+                y: bounds.y + style.back_border_width,
+                width: bar_width.abs(),
+                height: bounds.height - (style.back_border_width * 2.0),
+            },
+            background: Background::Color(color),
+            border_radius: 0.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        });
+    }
+
+    primitives.push(Primitive::Quad {
+        bounds: Rectangle {
+            x: (center_x - (style.center_line_width / 2.0)).round(),
+            y: bounds.y,
+            width: style.center_line_width,
+            height: bounds.height,
+        },
+        background: Background::Color(style.center_line_color),
+        border_radius: 0.0,
+        border_width: 0.0,
+        border_color: Color::TRANSPARENT,
+    });
+}
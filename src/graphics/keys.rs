@@ -0,0 +1,108 @@
+//! Display a piano keyboard that emits note-on and note-off messages.
+
+use crate::native::keys;
+use iced_graphics::{Backend, Primitive, Renderer};
+use iced_native::{mouse, Background, Color, Point, Rectangle};
+
+pub use crate::native::keys::{
+    is_black_key, Key, KeyState, Orientation, State,
+};
+pub use crate::style::keys::{KeyColors, Style, StyleSheet};
+
+/// A piano keyboard GUI widget that emits note-on and note-off messages.
+pub type Keys<'a, Message, Backend> =
+    keys::Keys<'a, Message, Renderer<Backend>>;
+
+impl<B: Backend> keys::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        orientation: Orientation,
+        keys: &[Key],
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if is_mouse_over {
+            style_sheet.hovered()
+        } else {
+            style_sheet.active()
+        };
+
+        let back = Primitive::Quad {
+            bounds: Rectangle {
+                x: bounds.x.round(),
+                y: bounds.y.round(),
+                width: bounds.width.round(),
+                height: bounds.height.round(),
+            },
+            background: Background::Color(style.back_color),
+            border_radius: 0.0,
+            border_width: style.back_border_width,
+            border_color: style.back_border_color,
+        };
+
+        let mut primitives: Vec<Primitive> = Vec::with_capacity(keys.len() + 1);
+        primitives.push(back);
+
+        for key in keys {
+            let colors = if key.is_black {
+                &style.black_key
+            } else {
+                &style.white_key
+            };
+
+            let color = match key.state {
+                KeyState::Idle => colors.idle,
+                KeyState::Hovered => colors.hovered,
+                KeyState::Highlighted => colors.highlighted,
+                KeyState::Pressed => colors.pressed,
+            };
+
+            let (key_bounds, border_width, border_color) = if key.is_black {
+                (
+                    key.bounds,
+                    style.black_key_border_width,
+                    style.black_key_border_color,
+                )
+            } else {
+                let gap = style.white_key_gap;
+
+                let key_bounds = match orientation {
+                    Orientation::Horizontal => Rectangle {
+                        x: key.bounds.x + gap,
+                        width: key.bounds.width - gap,
+                        ..key.bounds
+                    },
+                    Orientation::Vertical => Rectangle {
+                        height: key.bounds.height - gap,
+                        ..key.bounds
+                    },
+                };
+
+                (key_bounds, 0.0, Color::TRANSPARENT)
+            };
+
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x: key_bounds.x.round(),
+                    y: key_bounds.y.round(),
+                    width: key_bounds.width.round(),
+                    height: key_bounds.height.round(),
+                },
+                background: Background::Color(color),
+                border_radius: style.key_border_radius,
+                border_width,
+                border_color,
+            });
+        }
+
+        (
+            Primitive::Group { primitives },
+            mouse::Interaction::default(),
+        )
+    }
+}
@@ -2,18 +2,21 @@
 //!
 //! [`Param`]: ../core/param/struct.Param.html
 
-use crate::core::{ModulationRange, Normal};
-use crate::graphics::{text_marks, tick_marks};
+use crate::core::{KnobAngleRange, ModulationRange, Normal};
+use crate::graphics::{
+    filmstrip, text_marks, tick_marks, tooltip, value_entry,
+};
 use crate::native::knob;
 use iced_graphics::canvas::{path::Arc, Frame, Path, Stroke};
-use iced_graphics::{Backend, Primitive, Renderer};
-use iced_native::{mouse, Background, Point, Rectangle, Size, Vector};
+use iced_graphics::{backend, Backend, Primitive, Renderer};
+use iced_native::{image, mouse, Background, Point, Rectangle, Size, Vector};
 
-pub use crate::native::knob::State;
+pub use crate::native::knob::{DragMode, State};
 pub use crate::style::knob::{
-    ArcBipolarStyle, ArcStyle, CircleNotch, CircleStyle, LineCap, LineNotch,
-    ModRangeArcStyle, NotchShape, Style, StyleLength, StyleSheet,
-    TextMarksStyle, TickMarksStyle, ValueArcStyle,
+    ArcBipolarStyle, ArcStyle, CircleNotch, CircleStyle, KnobTexture,
+    KnobTextureError, LineCap, LineNotch, ModRangeArcStyle, ModValueStyle,
+    NotchShape, Style, StyleLength, StyleSheet, TextMarksStyle, TextureLayer,
+    TexturePadding, TextureStyle, TickMarksStyle, ValueArcStyle,
 };
 
 struct ValueMarkers<'a> {
@@ -21,11 +24,13 @@ struct ValueMarkers<'a> {
     text_marks: Option<&'a text_marks::Group>,
     mod_range_1: Option<&'a ModulationRange>,
     mod_range_2: Option<&'a ModulationRange>,
+    mod_value: Option<Normal>,
     tick_marks_style: Option<TickMarksStyle>,
     text_marks_style: Option<TextMarksStyle>,
     value_arc_style: Option<ValueArcStyle>,
     mod_range_style_1: Option<ModRangeArcStyle>,
     mod_range_style_2: Option<ModRangeArcStyle>,
+    mod_value_style: Option<ModValueStyle>,
 }
 
 struct KnobInfo {
@@ -43,17 +48,26 @@ struct KnobInfo {
 pub type Knob<'a, Message, Backend> =
     knob::Knob<'a, Message, Renderer<Backend>>;
 
-impl<B: Backend> knob::Renderer for Renderer<B> {
+impl<B: Backend + backend::Text> knob::Renderer for Renderer<B> {
     type Style = Box<dyn StyleSheet>;
 
+    fn angle_range(&self, style_sheet: &Self::Style) -> KnobAngleRange {
+        style_sheet.angle_range()
+    }
+
     fn draw(
         &mut self,
         bounds: Rectangle,
         cursor_position: Point,
         normal: Normal,
         is_dragging: bool,
+        is_focused: bool,
+        entry_text: Option<&str>,
+        tooltip_text: Option<&str>,
+        viewport: &Rectangle,
         mod_range_1: Option<&ModulationRange>,
         mod_range_2: Option<&ModulationRange>,
+        mod_value: Option<Normal>,
         tick_marks: Option<&tick_marks::Group>,
         text_marks: Option<&text_marks::Group>,
         style_sheet: &Self::Style,
@@ -66,6 +80,8 @@ impl<B: Backend> knob::Renderer for Renderer<B> {
 
         let style = if is_dragging {
             style_sheet.dragging()
+        } else if is_focused {
+            style_sheet.focused()
         } else if is_mouse_over {
             style_sheet.hovered()
         } else {
@@ -77,11 +93,13 @@ impl<B: Backend> knob::Renderer for Renderer<B> {
             text_marks,
             mod_range_1,
             mod_range_2,
+            mod_value,
             tick_marks_style: style_sheet.tick_marks_style(),
             text_marks_style: style_sheet.text_marks_style(),
             value_arc_style: style_sheet.value_arc_style(),
             mod_range_style_1: style_sheet.mod_range_arc_style(),
             mod_range_style_2: style_sheet.mod_range_arc_style_2(),
+            mod_value_style: style_sheet.mod_value_style(),
         };
 
         let bounds = {
@@ -133,29 +151,67 @@ impl<B: Backend> knob::Renderer for Renderer<B> {
             value_angle,
         };
 
+        let primitives = match style {
+            Style::Texture(style) => draw_texture_style(
+                &knob_info,
+                style,
+                &value_markers,
+                tick_marks_cache,
+                text_marks_cache,
+            ),
+            Style::Filmstrip(style) => draw_filmstrip_style(
+                &knob_info,
+                &style,
+                &value_markers,
+                tick_marks_cache,
+                text_marks_cache,
+            ),
+            Style::Circle(style) => draw_circle_style(
+                &knob_info,
+                style,
+                &value_markers,
+                tick_marks_cache,
+                text_marks_cache,
+            ),
+            Style::Arc(style) => draw_arc_style(
+                &knob_info,
+                style,
+                &value_markers,
+                tick_marks_cache,
+                text_marks_cache,
+            ),
+            Style::ArcBipolar(style) => draw_arc_bipolar_style(
+                &knob_info,
+                style,
+                &value_markers,
+                tick_marks_cache,
+                text_marks_cache,
+            ),
+        };
+
+        let entry = match entry_text {
+            Some(text) => value_entry::draw_value_entry(
+                &bounds,
+                text,
+                &style_sheet.value_entry_style(),
+            ),
+            None => Primitive::None,
+        };
+
+        let tooltip = match tooltip_text {
+            Some(text) => tooltip::draw_tooltip(
+                self.backend(),
+                &bounds,
+                viewport,
+                text,
+                &style_sheet.tooltip_style(),
+            ),
+            None => Primitive::None,
+        };
+
         (
-            match style {
-                Style::Circle(style) => draw_circle_style(
-                    &knob_info,
-                    style,
-                    &value_markers,
-                    tick_marks_cache,
-                    text_marks_cache,
-                ),
-                Style::Arc(style) => draw_arc_style(
-                    &knob_info,
-                    style,
-                    &value_markers,
-                    tick_marks_cache,
-                    text_marks_cache,
-                ),
-                Style::ArcBipolar(style) => draw_arc_bipolar_style(
-                    &knob_info,
-                    style,
-                    &value_markers,
-                    tick_marks_cache,
-                    text_marks_cache,
-                ),
+            Primitive::Group {
+                primitives: vec![primitives, entry, tooltip],
             },
             mouse::Interaction::default(),
         )
@@ -167,7 +223,14 @@ fn draw_value_markers<'a>(
     value_markers: &ValueMarkers<'a>,
     tick_marks_cache: &tick_marks::PrimitiveCache,
     text_marks_cache: &text_marks::PrimitiveCache,
-) -> (Primitive, Primitive, Primitive, Primitive, Primitive) {
+) -> (
+    Primitive,
+    Primitive,
+    Primitive,
+    Primitive,
+    Primitive,
+    Primitive,
+) {
     (
         draw_tick_marks(
             knob_info,
@@ -192,6 +255,11 @@ fn draw_value_markers<'a>(
             &value_markers.mod_range_style_2,
             value_markers.mod_range_2,
         ),
+        draw_mod_value(
+            knob_info,
+            &value_markers.mod_value_style,
+            value_markers.mod_value,
+        ),
     )
 }
 
@@ -455,6 +523,40 @@ fn draw_mod_range_arc(
     }
 }
 
+fn draw_mod_value(
+    knob_info: &KnobInfo,
+    style: &Option<ModValueStyle>,
+    mod_value: Option<Normal>,
+) -> Primitive {
+    if let Some(mod_value) = mod_value {
+        if let Some(style) = style {
+            let radius = style.diameter / 2.0;
+            let dot_radius = knob_info.radius + style.offset + radius;
+            let angle =
+                knob_info.start_angle + mod_value.scale(knob_info.angle_span);
+
+            let center = knob_info.bounds.center();
+
+            Primitive::Quad {
+                bounds: Rectangle {
+                    x: center.x + (dot_radius * angle.cos()) - radius,
+                    y: center.y + (dot_radius * angle.sin()) - radius,
+                    width: style.diameter,
+                    height: style.diameter,
+                },
+                background: Background::Color(style.color),
+                border_radius: radius,
+                border_width: style.border_width,
+                border_color: style.border_color,
+            }
+        } else {
+            Primitive::None
+        }
+    } else {
+        Primitive::None
+    }
+}
+
 fn draw_circle_notch(knob_info: &KnobInfo, style: &CircleNotch) -> Primitive {
     let value_angle = knob_info.value_angle + std::f32::consts::FRAC_PI_2;
 
@@ -531,6 +633,126 @@ fn draw_notch(knob_info: &KnobInfo, notch: &NotchShape) -> Primitive {
     }
 }
 
+fn draw_texture_layer(
+    bounds: &Rectangle,
+    texture: image::Handle,
+    padding: &TexturePadding,
+) -> Primitive {
+    Primitive::Image {
+        handle: texture,
+        bounds: Rectangle {
+            x: bounds.x - padding.left,
+            y: bounds.y - padding.top,
+            width: bounds.width + padding.left + padding.right,
+            height: bounds.height + padding.top + padding.bottom,
+        },
+    }
+}
+
+fn draw_texture_style<'a>(
+    knob_info: &KnobInfo,
+    style: TextureStyle,
+    value_markers: &ValueMarkers<'a>,
+    tick_marks_cache: &tick_marks::PrimitiveCache,
+    text_marks_cache: &text_marks::PrimitiveCache,
+) -> Primitive {
+    let (
+        tick_marks,
+        text_marks,
+        value_arc,
+        mod_range_arc_1,
+        mod_range_arc_2,
+        mod_value,
+    ) = draw_value_markers(
+        knob_info,
+        value_markers,
+        tick_marks_cache,
+        text_marks_cache,
+    );
+
+    let shadow = if let Some(shadow) = style.shadow {
+        draw_texture_layer(
+            &knob_info.bounds,
+            shadow.texture,
+            &shadow.texture_padding,
+        )
+    } else {
+        Primitive::None
+    };
+
+    // The texture points straight up when it is not rotated.
+    let rotation = knob_info.value_angle
+        - std::f32::consts::FRAC_PI_2
+        - std::f32::consts::PI;
+
+    let texture = draw_texture_layer(
+        &knob_info.bounds,
+        style.texture.rotated(rotation).clone(),
+        &style.texture_padding,
+    );
+
+    let highlight = if let Some(highlight) = style.highlight {
+        draw_texture_layer(
+            &knob_info.bounds,
+            highlight.texture,
+            &highlight.texture_padding,
+        )
+    } else {
+        Primitive::None
+    };
+
+    Primitive::Group {
+        primitives: vec![
+            tick_marks,
+            text_marks,
+            value_arc,
+            mod_range_arc_1,
+            mod_range_arc_2,
+            shadow,
+            texture,
+            highlight,
+            mod_value,
+        ],
+    }
+}
+
+fn draw_filmstrip_style<'a>(
+    knob_info: &KnobInfo,
+    style: &filmstrip::Style,
+    value_markers: &ValueMarkers<'a>,
+    tick_marks_cache: &tick_marks::PrimitiveCache,
+    text_marks_cache: &text_marks::PrimitiveCache,
+) -> Primitive {
+    let (
+        tick_marks,
+        text_marks,
+        value_arc,
+        mod_range_arc_1,
+        mod_range_arc_2,
+        mod_value,
+    ) = draw_value_markers(
+        knob_info,
+        value_markers,
+        tick_marks_cache,
+        text_marks_cache,
+    );
+
+    let knob =
+        filmstrip::draw_filmstrip(&knob_info.bounds, knob_info.value, style);
+
+    Primitive::Group {
+        primitives: vec![
+            tick_marks,
+            text_marks,
+            value_arc,
+            mod_range_arc_1,
+            mod_range_arc_2,
+            knob,
+            mod_value,
+        ],
+    }
+}
+
 fn draw_circle_style<'a>(
     knob_info: &KnobInfo,
     style: CircleStyle,
@@ -538,13 +760,19 @@ fn draw_circle_style<'a>(
     tick_marks_cache: &tick_marks::PrimitiveCache,
     text_marks_cache: &text_marks::PrimitiveCache,
 ) -> Primitive {
-    let (tick_marks, text_marks, value_arc, mod_range_arc_1, mod_range_arc_2) =
-        draw_value_markers(
-            knob_info,
-            value_markers,
-            tick_marks_cache,
-            text_marks_cache,
-        );
+    let (
+        tick_marks,
+        text_marks,
+        value_arc,
+        mod_range_arc_1,
+        mod_range_arc_2,
+        mod_value,
+    ) = draw_value_markers(
+        knob_info,
+        value_markers,
+        tick_marks_cache,
+        text_marks_cache,
+    );
 
     let knob_back = Primitive::Quad {
         bounds: knob_info.bounds,
@@ -565,6 +793,7 @@ fn draw_circle_style<'a>(
             mod_range_arc_2,
             knob_back,
             notch,
+            mod_value,
         ],
     }
 }
@@ -576,13 +805,19 @@ fn draw_arc_style<'a>(
     tick_marks_cache: &tick_marks::PrimitiveCache,
     text_marks_cache: &text_marks::PrimitiveCache,
 ) -> Primitive {
-    let (tick_marks, text_marks, value_arc, mod_range_arc_1, mod_range_arc_2) =
-        draw_value_markers(
-            knob_info,
-            value_markers,
-            tick_marks_cache,
-            text_marks_cache,
-        );
+    let (
+        tick_marks,
+        text_marks,
+        value_arc,
+        mod_range_arc_1,
+        mod_range_arc_2,
+        mod_value,
+    ) = draw_value_markers(
+        knob_info,
+        value_markers,
+        tick_marks_cache,
+        text_marks_cache,
+    );
 
     let arc: Primitive = {
         let width = style.width.from_knob_diameter(knob_info.bounds.width);
@@ -648,6 +883,7 @@ fn draw_arc_style<'a>(
             value_arc,
             mod_range_arc_1,
             mod_range_arc_2,
+            mod_value,
         ],
     }
 }
@@ -677,13 +913,19 @@ fn draw_arc_bipolar_style<'a>(
     tick_marks_cache: &tick_marks::PrimitiveCache,
     text_marks_cache: &text_marks::PrimitiveCache,
 ) -> Primitive {
-    let (tick_marks, text_marks, value_arc, mod_range_arc_1, mod_range_arc_2) =
-        draw_value_markers(
-            knob_info,
-            value_markers,
-            tick_marks_cache,
-            text_marks_cache,
-        );
+    let (
+        tick_marks,
+        text_marks,
+        value_arc,
+        mod_range_arc_1,
+        mod_range_arc_2,
+        mod_value,
+    ) = draw_value_markers(
+        knob_info,
+        value_markers,
+        tick_marks_cache,
+        text_marks_cache,
+    );
 
     let bipolar_state = BipolarState::from_knob_value(knob_info.value);
 
@@ -786,6 +1028,7 @@ fn draw_arc_bipolar_style<'a>(
             value_arc,
             mod_range_arc_1,
             mod_range_arc_2,
+            mod_value,
         ],
     }
 }
@@ -2,9 +2,10 @@
 //!
 //! [`Param`]: ../core/param/struct.Param.html
 
+use crate::graphics::tooltip;
 use crate::native::mod_range_input;
 
-use iced_graphics::{Backend, Primitive, Renderer};
+use iced_graphics::{backend, Backend, Primitive, Renderer};
 use iced_native::{mouse, Background, Point, Rectangle};
 
 pub use crate::native::mod_range_input::State;
@@ -18,7 +19,7 @@ pub use crate::style::mod_range_input::{
 pub type ModRangeInput<'a, Message, Backend> =
     mod_range_input::ModRangeInput<'a, Message, Renderer<Backend>>;
 
-impl<B: Backend> mod_range_input::Renderer for Renderer<B> {
+impl<B: Backend + backend::Text> mod_range_input::Renderer for Renderer<B> {
     type Style = Box<dyn StyleSheet>;
 
     fn draw(
@@ -26,12 +27,17 @@ impl<B: Backend> mod_range_input::Renderer for Renderer<B> {
         bounds: Rectangle,
         cursor_position: Point,
         is_dragging: bool,
+        is_focused: bool,
+        tooltip_text: Option<&str>,
+        viewport: &Rectangle,
         style_sheet: &Self::Style,
     ) -> Self::Output {
         let is_mouse_over = bounds.contains(cursor_position);
 
         let style = if is_dragging {
             style_sheet.dragging()
+        } else if is_focused {
+            style_sheet.focused()
         } else if is_mouse_over {
             style_sheet.hovered()
         } else {
@@ -80,6 +86,22 @@ impl<B: Backend> mod_range_input::Renderer for Renderer<B> {
             Style::Invisible => Primitive::None,
         };
 
-        (dot, mouse::Interaction::default())
+        let tooltip = match tooltip_text {
+            Some(text) => tooltip::draw_tooltip(
+                self.backend(),
+                &bounds,
+                viewport,
+                text,
+                &style_sheet.tooltip_style(),
+            ),
+            None => Primitive::None,
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![dot, tooltip],
+            },
+            mouse::Interaction::default(),
+        )
     }
 }
@@ -0,0 +1,254 @@
+//! Display an interactive mod wheel that controls a [`NormalParam`]. It can
+//! also act as a pitch wheel that springs back to its default value when it
+//! is released.
+//!
+//! [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
+
+use crate::core::Normal;
+use crate::graphics::tooltip;
+use crate::native::mod_wheel;
+use iced_graphics::{backend, Backend, Primitive, Renderer};
+use iced_native::{mouse, Background, Color, Point, Rectangle};
+
+pub use crate::native::mod_wheel::{State, WheelMode};
+pub use crate::style::mod_wheel::{Style, StyleSheet};
+
+/// A mod wheel GUI widget that controls a [`NormalParam`]. It can also act
+/// as a pitch wheel that springs back to its default value when it is
+/// released.
+///
+/// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+pub type ModWheel<'a, Message, Backend> =
+    mod_wheel::ModWheel<'a, Message, Renderer<Backend>>;
+
+// Features closer to the top and bottom edges than this are hidden, since
+// they would be too squashed to see.
+static MAX_VISIBLE_ANGLE: f32 = std::f32::consts::FRAC_PI_2 * 0.95;
+
+impl<B: Backend + backend::Text> mod_wheel::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        normal: Normal,
+        default: Normal,
+        mode: WheelMode,
+        is_dragging: bool,
+        is_focused: bool,
+        tooltip_text: Option<&str>,
+        viewport: &Rectangle,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if is_dragging {
+            style_sheet.dragging()
+        } else if is_focused {
+            style_sheet.focused()
+        } else if is_mouse_over {
+            style_sheet.hovered()
+        } else {
+            style_sheet.active()
+        };
+
+        let tooltip = match tooltip_text {
+            Some(text) => tooltip::draw_tooltip(
+                self.backend(),
+                &bounds,
+                viewport,
+                text,
+                &style_sheet.tooltip_style(),
+            ),
+            None => Primitive::None,
+        };
+
+        let bounds_x = bounds.x.floor();
+        let bounds_y = bounds.y.floor();
+
+        let bounds_width = bounds.width.floor();
+        let bounds_height = bounds.height.floor();
+
+        let back = Primitive::Quad {
+            bounds: Rectangle {
+                x: bounds_x,
+                y: bounds_y,
+                width: bounds_width,
+                height: bounds_height,
+            },
+            background: Background::Color(style.back_color),
+            border_radius: style.back_border_radius,
+            border_width: style.back_border_width,
+            border_color: style.back_border_color,
+        };
+
+        let wheel_bounds = Rectangle {
+            x: bounds_x + style.wheel_padding,
+            y: bounds_y + style.wheel_padding,
+            width: bounds_width - (style.wheel_padding * 2.0),
+            height: bounds_height - (style.wheel_padding * 2.0),
+        };
+
+        if wheel_bounds.width <= 0.0 || wheel_bounds.height <= 0.0 {
+            return (
+                Primitive::Group {
+                    primitives: vec![back, tooltip],
+                },
+                mouse::Interaction::default(),
+            );
+        }
+
+        let radius = wheel_bounds.height / 2.0;
+        let center_y = wheel_bounds.y + radius;
+
+        // The screen position of a feature on the surface of the wheel at
+        // the given angle, where `0.0` faces the viewer and positive angles
+        // rotate upwards.
+        let angle_to_y = |angle: f32| center_y - (radius * angle.sin());
+
+        let rotation_range = style.rotation_range.to_radians();
+        let value_angle = (normal.as_f32() - 0.5) * rotation_range;
+
+        let mut primitives: Vec<Primitive> =
+            Vec::with_capacity(usize::from(style.rib_count) / 2 + 7);
+
+        primitives.push(back);
+
+        primitives.push(Primitive::Quad {
+            bounds: wheel_bounds,
+            background: Background::Color(style.wheel_color),
+            border_radius: 0.0,
+            border_width: style.wheel_border_width,
+            border_color: style.wheel_border_color,
+        });
+
+        if style.rib_count > 0 {
+            let rib_step =
+                (std::f32::consts::PI * 2.0) / f32::from(style.rib_count);
+
+            // Offset the ribs by the rotation of the wheel so they appear to
+            // roll along with it.
+            let phase = value_angle % rib_step;
+
+            let max_index = (MAX_VISIBLE_ANGLE / rib_step).ceil() as i32 + 1;
+
+            for i in -max_index..=max_index {
+                let angle = phase + (i as f32 * rib_step);
+
+                if angle.abs() > MAX_VISIBLE_ANGLE {
+                    continue;
+                }
+
+                primitives.push(draw_band(
+                    &wheel_bounds,
+                    angle_to_y(angle),
+                    style.rib_width * angle.cos(),
+                    style.rib_color,
+                ));
+            }
+        }
+
+        if let Some(highlight_color) = style.highlight_color {
+            let top = angle_to_y(0.7_f32.asin());
+            let bottom = angle_to_y(0.15_f32.asin());
+
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x: wheel_bounds.x,
+                    y: top.round(),
+                    width: wheel_bounds.width,
+                    height: (bottom - top).round(),
+                },
+                background: Background::Color(highlight_color),
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            });
+        }
+
+        if let Some(shadow_color) = style.shadow_color {
+            let top = angle_to_y(-0.4_f32.asin());
+            let bottom = wheel_bounds.y + wheel_bounds.height;
+
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x: wheel_bounds.x,
+                    y: top.round(),
+                    width: wheel_bounds.width,
+                    height: (bottom - top).round(),
+                },
+                background: Background::Color(shadow_color),
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            });
+        }
+
+        if value_angle.abs() <= MAX_VISIBLE_ANGLE {
+            primitives.push(draw_band(
+                &wheel_bounds,
+                angle_to_y(value_angle),
+                style.marker_width * value_angle.cos(),
+                style.marker_color,
+            ));
+        }
+
+        if mode == WheelMode::Pitch && style.wheel_padding >= 1.0 {
+            // Notches on both sides of the slot show where the wheel
+            // springs back to.
+            let default_angle = (default.as_f32() - 0.5) * rotation_range;
+
+            if default_angle.abs() <= MAX_VISIBLE_ANGLE {
+                let y = (angle_to_y(default_angle)
+                    - (style.marker_width / 2.0))
+                    .round();
+
+                for x in [bounds_x, wheel_bounds.x + wheel_bounds.width].iter()
+                {
+                    primitives.push(Primitive::Quad {
+                        bounds: Rectangle {
+                            x: *x,
+                            y,
+                            width: style.wheel_padding,
+                            height: style.marker_width,
+                        },
+                        background: Background::Color(style.marker_color),
+                        border_radius: 0.0,
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    });
+                }
+            }
+        }
+
+        primitives.push(tooltip);
+
+        (
+            Primitive::Group { primitives },
+            mouse::Interaction::default(),
+        )
+    }
+}
+
+fn draw_band(
+    wheel_bounds: &Rectangle,
+    center_y: f32,
+    thickness: f32,
+    color: Color,
+) -> Primitive {
+    let thickness = thickness.max(1.0);
+
+    Primitive::Quad {
+        bounds: Rectangle {
+            x: wheel_bounds.x,
+            y: (center_y - (thickness / 2.0)).round(),
+            width: wheel_bounds.width,
+            height: thickness.round(),
+        },
+        background: Background::Color(color),
+        border_radius: 0.0,
+        border_width: 0.0,
+        border_color: Color::TRANSPARENT,
+    }
+}
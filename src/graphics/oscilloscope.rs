@@ -0,0 +1,168 @@
+//! Display an oscilloscope that displays the waveform of an audio signal in
+//! a given time window.
+
+use crate::native::oscilloscope;
+use iced_graphics::canvas::{Frame, LineJoin, Path, Stroke};
+use iced_graphics::{Backend, Primitive, Renderer};
+use iced_native::{mouse, Background, Point, Rectangle, Size, Vector};
+
+pub use crate::native::oscilloscope::{State, TriggerMode};
+pub use crate::style::oscilloscope::{Style, StyleSheet};
+
+/// An oscilloscope GUI widget that displays the waveform of an audio signal
+/// in a given time window.
+pub type Oscilloscope<'a, Backend> =
+    oscilloscope::Oscilloscope<'a, Renderer<Backend>>;
+
+impl<B: Backend> oscilloscope::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        samples: &[f32],
+        window_len: usize,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if is_mouse_over {
+            style_sheet.hovered()
+        } else {
+            style_sheet.active()
+        };
+
+        let bounds_x = bounds.x.floor();
+        let bounds_y = bounds.y.floor();
+
+        let bounds_width = bounds.width.floor();
+        let bounds_height = bounds.height.floor();
+
+        let back = Primitive::Quad {
+            bounds: Rectangle {
+                x: bounds_x,
+                y: bounds_y,
+                width: bounds_width,
+                height: bounds_height,
+            },
+            background: Background::Color(style.back_color),
+            border_radius: 0.0,
+            border_width: style.back_border_width,
+            border_color: style.back_border_color,
+        };
+
+        let border_width = style.back_border_width;
+        let twice_border_width = border_width * 2.0;
+
+        let range_width = bounds_width - twice_border_width;
+        let range_height = bounds_height - twice_border_width;
+        let half_range_height = range_height / 2.0;
+
+        if range_width <= 0.0 || range_height <= 0.0 {
+            return (back, mouse::Interaction::default());
+        }
+
+        let mut frame = Frame::new(Size::new(range_width, range_height));
+
+        if let Some(center_line_color) = style.center_line_color {
+            let center_y = half_range_height.floor();
+
+            frame.stroke(
+                &Path::line(
+                    Point::new(0.0, center_y),
+                    Point::new(range_width, center_y),
+                ),
+                Stroke {
+                    width: style.center_line_width,
+                    color: center_line_color,
+                    ..Stroke::default()
+                },
+            );
+        }
+
+        if samples.len() > 1 {
+            let sample_to_y = |sample: f32| -> f32 {
+                half_range_height
+                    - (sample.clamp(-1.0, 1.0) * half_range_height)
+            };
+
+            let x_per_sample = range_width / (window_len - 1) as f32;
+
+            let path = if x_per_sample >= 0.5 {
+                Path::new(|p| {
+                    p.move_to(Point::new(0.0, sample_to_y(samples[0])));
+
+                    for (i, sample) in samples.iter().enumerate().skip(1) {
+                        p.line_to(Point::new(
+                            i as f32 * x_per_sample,
+                            sample_to_y(*sample),
+                        ));
+                    }
+                })
+            } else {
+                // There are more samples than pixels, so draw the minimum and
+                // maximum of the samples in each pixel column instead.
+                let samples_per_column = 1.0 / x_per_sample;
+                let columns = ((samples.len() as f32 * x_per_sample).ceil()
+                    as usize)
+                    .max(1);
+
+                Path::new(|p| {
+                    for column in 0..columns {
+                        let start =
+                            (column as f32 * samples_per_column) as usize;
+                        let end = (((column + 1) as f32 * samples_per_column)
+                            as usize)
+                            .min(samples.len());
+
+                        if start >= end {
+                            break;
+                        }
+
+                        let (min, max) = samples[start..end].iter().fold(
+                            (f32::MAX, f32::MIN),
+                            |(min, max), sample| {
+                                (min.min(*sample), max.max(*sample))
+                            },
+                        );
+
+                        let x = column as f32;
+
+                        if column == 0 {
+                            p.move_to(Point::new(x, sample_to_y(max)));
+                        } else {
+                            p.line_to(Point::new(x, sample_to_y(max)));
+                        }
+                        p.line_to(Point::new(x, sample_to_y(min)));
+                    }
+                })
+            };
+
+            frame.stroke(
+                &path,
+                Stroke {
+                    width: style.line_width,
+                    color: style.line_color,
+                    line_join: LineJoin::Round,
+                    ..Stroke::default()
+                },
+            );
+        }
+
+        let waveform = Primitive::Translate {
+            translation: Vector::new(
+                bounds_x + border_width,
+                bounds_y + border_width,
+            ),
+            content: Box::new(frame.into_geometry().into_primitive()),
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![back, waveform],
+            },
+            mouse::Interaction::default(),
+        )
+    }
+}
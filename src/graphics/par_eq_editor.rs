@@ -0,0 +1,179 @@
+//! Display a parametric equalizer editor with draggable bands and the
+//! combined frequency response of all the bands.
+
+use crate::core::Normal;
+use crate::native::par_eq_editor;
+use iced_graphics::canvas::{Frame, LineJoin, Path, Stroke};
+use iced_graphics::{Backend, Primitive, Renderer};
+use iced_native::{mouse, Background, Color, Point, Rectangle, Size, Vector};
+
+pub use crate::native::par_eq_editor::{
+    Band, Edit, FilterType, GainRange, State,
+};
+pub use crate::style::par_eq_editor::{HandleStyle, Style, StyleSheet};
+
+/// A parametric equalizer GUI widget with draggable bands.
+pub type ParEqEditor<'a, Message, Backend> =
+    par_eq_editor::ParEqEditor<'a, Message, Renderer<Backend>>;
+
+impl<B: Backend> par_eq_editor::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        bands: &[Band],
+        response: &[Normal],
+        zero_gain: Normal,
+        hovered_band: Option<usize>,
+        dragged_band: Option<usize>,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if dragged_band.is_some() {
+            style_sheet.dragging()
+        } else if is_mouse_over {
+            style_sheet.hovered()
+        } else {
+            style_sheet.active()
+        };
+
+        let bounds_x = bounds.x.floor();
+        let bounds_y = bounds.y.floor();
+
+        let bounds_width = bounds.width.floor();
+        let bounds_height = bounds.height.floor();
+
+        let back = Primitive::Quad {
+            bounds: Rectangle {
+                x: bounds_x,
+                y: bounds_y,
+                width: bounds_width,
+                height: bounds_height,
+            },
+            background: Background::Color(style.back_color),
+            border_radius: 0.0,
+            border_width: style.back_border_width,
+            border_color: style.back_border_color,
+        };
+
+        if bounds_width <= 0.0 || bounds_height <= 0.0 {
+            return (back, mouse::Interaction::default());
+        }
+
+        let zero_y = zero_gain.scale_inv(bounds_height);
+
+        let zero_line = Primitive::Quad {
+            bounds: Rectangle {
+                x: bounds_x,
+                y: (bounds_y + zero_y - (style.zero_line_width / 2.0)).round(),
+                width: bounds_width,
+                height: style.zero_line_width,
+            },
+            background: Background::Color(style.zero_line_color),
+            border_radius: 0.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        };
+
+        let mut primitives: Vec<Primitive> =
+            Vec::with_capacity(bands.len() + 3);
+
+        primitives.push(back);
+        primitives.push(zero_line);
+
+        if response.len() > 1 {
+            let x_step = bounds_width / (response.len() - 1) as f32;
+
+            let curve_points =
+                response.iter().enumerate().map(|(i, normal)| {
+                    Point::new(
+                        i as f32 * x_step,
+                        normal.scale_inv(bounds_height),
+                    )
+                });
+
+            let mut frame = Frame::new(Size::new(bounds_width, bounds_height));
+
+            if let Some(fill_color) = style.fill_color {
+                let fill = Path::new(|p| {
+                    p.move_to(Point::new(0.0, zero_y));
+                    for point in curve_points.clone() {
+                        p.line_to(point);
+                    }
+                    p.line_to(Point::new(bounds_width, zero_y));
+                    p.close();
+                });
+
+                frame.fill(&fill, fill_color);
+            }
+
+            let line = Path::new(|p| {
+                for (i, point) in curve_points.enumerate() {
+                    if i == 0 {
+                        p.move_to(point);
+                    } else {
+                        p.line_to(point);
+                    }
+                }
+            });
+
+            frame.stroke(
+                &line,
+                Stroke {
+                    width: style.line_width,
+                    color: style.line_color,
+                    line_join: LineJoin::Round,
+                    ..Stroke::default()
+                },
+            );
+
+            primitives.push(Primitive::Translate {
+                translation: Vector::new(bounds_x, bounds_y),
+                content: Box::new(frame.into_geometry().into_primitive()),
+            });
+        }
+
+        for (i, band) in bands.iter().enumerate() {
+            let handle_style =
+                if hovered_band == Some(i) || dragged_band == Some(i) {
+                    &style.active_handle
+                } else if band.bypassed {
+                    &style.bypassed_handle
+                } else {
+                    &style.handle
+                };
+
+            let center = Point::new(
+                bounds_x + band.frequency.scale(bounds_width),
+                bounds_y + band.handle_y(zero_gain).scale_inv(bounds_height),
+            );
+
+            primitives.push(draw_handle(center, handle_style));
+        }
+
+        (
+            Primitive::Group { primitives },
+            mouse::Interaction::default(),
+        )
+    }
+}
+
+fn draw_handle(center: Point, style: &HandleStyle) -> Primitive {
+    let radius = style.diameter / 2.0;
+
+    Primitive::Quad {
+        bounds: Rectangle {
+            x: (center.x - radius).round(),
+            y: (center.y - radius).round(),
+            width: style.diameter,
+            height: style.diameter,
+        },
+        background: Background::Color(style.color),
+        border_radius: radius,
+        border_width: style.border_width,
+        border_color: style.border_color,
+    }
+}
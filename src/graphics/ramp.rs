@@ -4,9 +4,10 @@
 //! [`Param`]: ../core/param/trait.Param.html
 
 use crate::core::Normal;
+use crate::graphics::tooltip;
 use crate::native::ramp;
 use iced_graphics::canvas::{Frame, LineCap, Path, Stroke};
-use iced_graphics::{Backend, Primitive, Renderer};
+use iced_graphics::{backend, Backend, Primitive, Renderer};
 use iced_native::{mouse, Background, Point, Rectangle, Size, Vector};
 
 pub use crate::native::ramp::{RampDirection, State};
@@ -20,7 +21,7 @@ pub use crate::style::ramp::{Style, StyleSheet};
 pub type Ramp<'a, Message, Backend> =
     ramp::Ramp<'a, Message, Renderer<Backend>>;
 
-impl<B: Backend> ramp::Renderer for Renderer<B> {
+impl<B: Backend + backend::Text> ramp::Renderer for Renderer<B> {
     type Style = Box<dyn StyleSheet>;
 
     fn draw(
@@ -29,6 +30,9 @@ impl<B: Backend> ramp::Renderer for Renderer<B> {
         cursor_position: Point,
         normal: Normal,
         is_dragging: bool,
+        is_focused: bool,
+        tooltip_text: Option<&str>,
+        viewport: &Rectangle,
         style_sheet: &Self::Style,
         direction: RampDirection,
     ) -> Self::Output {
@@ -36,6 +40,8 @@ impl<B: Backend> ramp::Renderer for Renderer<B> {
 
         let style = if is_dragging {
             style_sheet.dragging()
+        } else if is_focused {
+            style_sheet.focused()
         } else if is_mouse_over {
             style_sheet.hovered()
         } else {
@@ -283,9 +289,20 @@ impl<B: Backend> ramp::Renderer for Renderer<B> {
             }
         };
 
+        let tooltip = match tooltip_text {
+            Some(text) => tooltip::draw_tooltip(
+                self.backend(),
+                &bounds,
+                viewport,
+                text,
+                &style_sheet.tooltip_style(),
+            ),
+            None => Primitive::None,
+        };
+
         (
             Primitive::Group {
-                primitives: vec![back, line],
+                primitives: vec![back, line, tooltip],
             },
             mouse::Interaction::default(),
         )
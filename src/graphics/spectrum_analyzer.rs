@@ -0,0 +1,297 @@
+//! Display a spectrum analyzer that displays the amplitude of a range of
+//! frequencies on a logarithmic frequency axis.
+
+use crate::graphics::{text_marks, tick_marks};
+use crate::native::spectrum_analyzer;
+use iced_graphics::canvas::{Frame, LineJoin, Path, Stroke};
+use iced_graphics::{Backend, Primitive, Renderer};
+use iced_native::{mouse, Background, Color, Point, Rectangle, Size, Vector};
+
+pub use crate::native::spectrum_analyzer::{
+    frequency_text_marks, frequency_tick_marks, DisplayMode, State,
+};
+pub use crate::style::spectrum_analyzer::{
+    PeakLineStyle, Style, StyleSheet, TextMarksStyle, TickMarksStyle,
+};
+
+/// A spectrum analyzer GUI widget that displays the amplitude of a range of
+/// frequencies on a logarithmic frequency axis.
+pub type SpectrumAnalyzer<'a, Backend> =
+    spectrum_analyzer::SpectrumAnalyzer<'a, Renderer<Backend>>;
+
+impl<B: Backend> spectrum_analyzer::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        points: &[f32],
+        peaks: Option<&[f32]>,
+        mode: DisplayMode,
+        tick_marks: Option<&tick_marks::Group>,
+        text_marks: Option<&text_marks::Group>,
+        style_sheet: &Self::Style,
+        tick_marks_cache: &tick_marks::PrimitiveCache,
+        text_marks_cache: &text_marks::PrimitiveCache,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if is_mouse_over {
+            style_sheet.hovered()
+        } else {
+            style_sheet.active()
+        };
+
+        let bounds = Rectangle {
+            x: bounds.x.floor(),
+            y: bounds.y.floor(),
+            width: bounds.width.floor(),
+            height: bounds.height.floor(),
+        };
+
+        let back = Primitive::Quad {
+            bounds,
+            background: Background::Color(style.back_color),
+            border_radius: 0.0,
+            border_width: style.back_border_width,
+            border_color: style.back_border_color,
+        };
+
+        let border_width = style.back_border_width;
+        let twice_border_width = border_width * 2.0;
+
+        let inner_bounds = Rectangle {
+            x: bounds.x + border_width,
+            y: bounds.y + border_width,
+            width: bounds.width - twice_border_width,
+            height: bounds.height - twice_border_width,
+        };
+
+        let tick_marks = draw_tick_marks(
+            &inner_bounds,
+            tick_marks,
+            &style_sheet.tick_marks_style(),
+            tick_marks_cache,
+        );
+        let text_marks = draw_text_marks(
+            &bounds,
+            text_marks,
+            &style_sheet.text_marks_style(),
+            text_marks_cache,
+        );
+
+        if inner_bounds.width <= 0.0
+            || inner_bounds.height <= 0.0
+            || points.len() < 2
+        {
+            return (
+                Primitive::Group {
+                    primitives: vec![back, tick_marks, text_marks],
+                },
+                mouse::Interaction::default(),
+            );
+        }
+
+        let width = inner_bounds.width;
+        let height = inner_bounds.height;
+
+        let x_per_point = width / (points.len() - 1) as f32;
+        let point_to_y =
+            |value: f32| -> f32 { height - (value.clamp(0.0, 1.0) * height) };
+
+        let mut frame = Frame::new(Size::new(width, height));
+
+        let spectrum = match mode {
+            DisplayMode::Line | DisplayMode::Filled => {
+                let line = Path::new(|p| {
+                    p.move_to(Point::new(0.0, point_to_y(points[0])));
+
+                    for (i, value) in points.iter().enumerate().skip(1) {
+                        p.line_to(Point::new(
+                            i as f32 * x_per_point,
+                            point_to_y(*value),
+                        ));
+                    }
+                });
+
+                if mode == DisplayMode::Filled {
+                    let fill = Path::new(|p| {
+                        p.move_to(Point::new(0.0, height));
+
+                        for (i, value) in points.iter().enumerate() {
+                            p.line_to(Point::new(
+                                i as f32 * x_per_point,
+                                point_to_y(*value),
+                            ));
+                        }
+
+                        p.line_to(Point::new(width, height));
+                        p.close();
+                    });
+
+                    frame.fill(&fill, style.fill_color);
+                }
+
+                frame.stroke(
+                    &line,
+                    Stroke {
+                        width: style.line_width,
+                        color: style.line_color,
+                        line_join: LineJoin::Round,
+                        ..Stroke::default()
+                    },
+                );
+
+                Primitive::None
+            }
+            DisplayMode::Bars { count } => {
+                draw_bars(&inner_bounds, points, count, &style)
+            }
+        };
+
+        if let Some(peaks) = peaks {
+            if let Some(peak_style) = &style.peak_line {
+                let x_per_peak = width / (peaks.len().max(2) - 1) as f32;
+
+                let peak_line = Path::new(|p| {
+                    for (i, value) in peaks.iter().enumerate() {
+                        let point = Point::new(
+                            i as f32 * x_per_peak,
+                            point_to_y(*value),
+                        );
+
+                        if i == 0 {
+                            p.move_to(point);
+                        } else {
+                            p.line_to(point);
+                        }
+                    }
+                });
+
+                frame.stroke(
+                    &peak_line,
+                    Stroke {
+                        width: peak_style.width,
+                        color: peak_style.color,
+                        line_join: LineJoin::Round,
+                        ..Stroke::default()
+                    },
+                );
+            }
+        }
+
+        let curves = Primitive::Translate {
+            translation: Vector::new(inner_bounds.x, inner_bounds.y),
+            content: Box::new(frame.into_geometry().into_primitive()),
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![
+                    back, tick_marks, spectrum, curves, text_marks,
+                ],
+            },
+            mouse::Interaction::default(),
+        )
+    }
+}
+
+fn draw_bars(
+    bounds: &Rectangle,
+    points: &[f32],
+    count: usize,
+    style: &Style,
+) -> Primitive {
+    let count = count.max(1);
+
+    let bar_span = bounds.width / count as f32;
+    let points_per_bar = points.len() as f32 / count as f32;
+
+    let mut primitives: Vec<Primitive> = Vec::with_capacity(count);
+
+    for bar in 0..count {
+        let start = (bar as f32 * points_per_bar) as usize;
+        let end = (((bar + 1) as f32 * points_per_bar).ceil() as usize)
+            .max(start + 1)
+            .min(points.len());
+
+        let value = points[start..end]
+            .iter()
+            .fold(0.0f32, |max, value| max.max(*value))
+            .min(1.0);
+
+        if value <= 0.0 {
+            continue;
+        }
+
+        let x = (bounds.x + (bar as f32 * bar_span)).round();
+        let right = (bounds.x + ((bar + 1) as f32 * bar_span) - style.bar_gap)
+            .round()
+            .max(x + 1.0);
+        let y = (bounds.y + (bounds.height * (1.0 - value))).round();
+
+        primitives.push(Primitive::Quad {
+            bounds: Rectangle {
+                x,
+                y,
+                width: right - x,
+                height: bounds.y + bounds.height - y,
+            },
+            background: Background::Color(style.bar_color),
+            border_radius: 0.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        });
+    }
+
+    Primitive::Group { primitives }
+}
+
+fn draw_tick_marks(
+    bounds: &Rectangle,
+    tick_marks: Option<&tick_marks::Group>,
+    tick_marks_style: &Option<TickMarksStyle>,
+    tick_marks_cache: &tick_marks::PrimitiveCache,
+) -> Primitive {
+    if let Some(tick_marks) = tick_marks {
+        if let Some(style) = tick_marks_style {
+            tick_marks::draw_horizontal_tick_marks(
+                bounds,
+                tick_marks,
+                &style.style,
+                &style.placement,
+                false,
+                tick_marks_cache,
+            )
+        } else {
+            Primitive::None
+        }
+    } else {
+        Primitive::None
+    }
+}
+
+fn draw_text_marks(
+    bounds: &Rectangle,
+    text_marks: Option<&text_marks::Group>,
+    text_marks_style: &Option<TextMarksStyle>,
+    text_marks_cache: &text_marks::PrimitiveCache,
+) -> Primitive {
+    if let Some(text_marks) = text_marks {
+        if let Some(style) = text_marks_style {
+            text_marks::draw_horizontal_text_marks(
+                bounds,
+                text_marks,
+                &style.style,
+                &style.placement,
+                false,
+                text_marks_cache,
+            )
+        } else {
+            Primitive::None
+        }
+    } else {
+        Primitive::None
+    }
+}
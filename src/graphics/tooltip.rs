@@ -0,0 +1,85 @@
+//! Draw the tooltip that displays the value of a parameter widget
+
+use iced_graphics::{
+    backend, HorizontalAlignment, Primitive, VerticalAlignment,
+};
+use iced_native::{Background, Rectangle, Size, Vector};
+
+pub use crate::style::tooltip::*;
+
+/// Draws a tooltip with the given text next to `bounds`, keeping it
+/// inside of `viewport`.
+///
+/// The tooltip is sized to fit the text as measured by the `backend`, with
+/// the padding of the style on either side.
+pub fn draw_tooltip<B: backend::Text>(
+    backend: &B,
+    bounds: &Rectangle,
+    viewport: &Rectangle,
+    text: &str,
+    style: &Style,
+) -> Primitive {
+    let (text_width, text_height) = backend.measure(
+        text,
+        f32::from(style.text_size),
+        style.font,
+        Size::INFINITY,
+    );
+
+    let width = (text_width + (style.padding * 2.0))
+        .max(f32::from(style.min_width))
+        .ceil();
+    let height = text_height.max(f32::from(style.height)).ceil();
+
+    let x = (bounds.center_x() - (width / 2.0))
+        .min(viewport.x + viewport.width - width)
+        .max(viewport.x);
+
+    let above = bounds.y - style.offset - height;
+    let y = if above >= viewport.y {
+        above
+    } else {
+        (bounds.y + bounds.height + style.offset)
+            .min(viewport.y + viewport.height - height)
+            .max(viewport.y)
+    };
+
+    let tooltip_bounds = Rectangle {
+        x: x.round(),
+        y: y.round(),
+        width,
+        height,
+    };
+
+    let back = Primitive::Quad {
+        bounds: tooltip_bounds,
+        background: Background::Color(style.back_color),
+        border_radius: style.border_radius,
+        border_width: style.border_width,
+        border_color: style.border_color,
+    };
+
+    let text = Primitive::Text {
+        content: text.to_string(),
+        size: f32::from(style.text_size),
+        bounds: Rectangle {
+            x: tooltip_bounds.center_x(),
+            y: tooltip_bounds.center_y(),
+            ..tooltip_bounds
+        },
+        color: style.text_color,
+        font: style.font,
+        horizontal_alignment: HorizontalAlignment::Center,
+        vertical_alignment: VerticalAlignment::Center,
+    };
+
+    // Clipping puts the tooltip in its own layer, which is drawn on top of
+    // the widgets around it.
+    Primitive::Clip {
+        bounds: *viewport,
+        offset: Vector::new(0, 0),
+        content: Box::new(Primitive::Group {
+            primitives: vec![back, text],
+        }),
+    }
+}
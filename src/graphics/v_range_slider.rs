@@ -0,0 +1,248 @@
+//! Display an interactive vertical slider with multiple handles that each
+//! control a [`NormalParam`]
+//!
+//! [`NormalParam`]: ../core/normal_param/struct.Param.html
+
+use crate::core::Normal;
+use crate::graphics::tooltip;
+use crate::graphics::v_slider::draw_classic_rail;
+use crate::native::v_range_slider;
+use iced_graphics::{backend, Backend, Primitive, Renderer};
+use iced_native::{mouse, Background, Color, Point, Rectangle};
+
+pub use crate::native::v_range_slider::{DragTarget, State};
+pub use crate::style::v_range_slider::{HandleStyle, Style, StyleSheet};
+pub use crate::style::v_slider::{
+    ClassicRail, ModRangePlacement, ModRangeStyle,
+};
+
+/// A vertical slider GUI widget with two or more handles, each
+/// controlling a [`NormalParam`]
+///
+/// a [`VRangeSlider`] will try to fill the vertical space of its container.
+///
+/// [`NormalParam`]: ../../core/normal_param/struct.Param.html
+/// [`VRangeSlider`]: struct.VRangeSlider.html
+pub type VRangeSlider<'a, Message, Backend> =
+    v_range_slider::VRangeSlider<'a, Message, Renderer<Backend>>;
+
+impl<B: Backend + backend::Text> v_range_slider::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        normals: &[Normal],
+        handle_height: u16,
+        drag_target: Option<DragTarget>,
+        focused_handle: Option<usize>,
+        tooltips: &[(usize, String)],
+        viewport: &Rectangle,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let idle_style = if drag_target.is_none() && is_mouse_over {
+            style_sheet.hovered()
+        } else {
+            style_sheet.active()
+        };
+
+        let style = if drag_target.is_some() {
+            style_sheet.dragging()
+        } else if focused_handle.is_some() {
+            style_sheet.focused()
+        } else {
+            idle_style.clone()
+        };
+
+        let handle_height = f32::from(handle_height);
+
+        let value_bounds = Rectangle {
+            x: bounds.x,
+            y: (bounds.y + (handle_height / 2.0)).round(),
+            width: bounds.width,
+            height: bounds.height - handle_height,
+        };
+
+        let (left_rail, right_rail) = draw_classic_rail(&bounds, &style.rail);
+
+        let span = match (normals.first(), normals.last(), &style.span) {
+            (Some(first), Some(last), Some(span_style))
+                if normals.len() > 1 =>
+            {
+                draw_span(&value_bounds, *first, *last, span_style)
+            }
+            _ => Primitive::None,
+        };
+
+        let mut primitives = vec![left_rail, right_rail, span];
+
+        let mut tooltip_primitives = Vec::new();
+
+        for (i, normal) in normals.iter().enumerate() {
+            let is_highlighted = match drag_target {
+                Some(DragTarget::Handle(index)) => index == i,
+                Some(DragTarget::Span(a, b)) => a == i || b == i,
+                None => focused_handle == Some(i),
+            };
+
+            let handle_style = if is_highlighted {
+                &style.handle
+            } else {
+                &idle_style.handle
+            };
+
+            let handle_bounds =
+                handle_bounds(&bounds, &value_bounds, *normal, handle_height);
+
+            primitives.push(draw_handle(&handle_bounds, handle_style));
+
+            if let Some((_, text)) =
+                tooltips.iter().find(|(index, _)| *index == i)
+            {
+                tooltip_primitives.push(tooltip::draw_tooltip(
+                    self.backend(),
+                    &handle_bounds,
+                    viewport,
+                    text,
+                    &style_sheet.tooltip_style(),
+                ));
+            }
+        }
+
+        primitives.append(&mut tooltip_primitives);
+
+        (
+            Primitive::Group { primitives },
+            if drag_target.is_some() {
+                mouse::Interaction::Grabbing
+            } else if is_mouse_over {
+                mouse::Interaction::Grab
+            } else {
+                mouse::Interaction::default()
+            },
+        )
+    }
+}
+
+fn handle_bounds(
+    bounds: &Rectangle,
+    value_bounds: &Rectangle,
+    normal: Normal,
+    handle_height: f32,
+) -> Rectangle {
+    Rectangle {
+        x: bounds.x,
+        y: bounds.y + normal.scale_inv(value_bounds.height).round(),
+        width: bounds.width,
+        height: handle_height,
+    }
+}
+
+fn draw_handle(bounds: &Rectangle, style: &HandleStyle) -> Primitive {
+    let notch_width = style.notch_width;
+
+    let handle = Primitive::Quad {
+        bounds: *bounds,
+        background: Background::Color(style.color),
+        border_radius: style.border_radius,
+        border_width: style.border_width,
+        border_color: style.border_color,
+    };
+
+    let notch = if notch_width != 0.0 {
+        Primitive::Quad {
+            bounds: Rectangle {
+                x: bounds.x,
+                y: (bounds.center_y() - (notch_width / 2.0)).round(),
+                width: bounds.width,
+                height: notch_width,
+            },
+            background: Background::Color(style.notch_color),
+            border_radius: 0.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        }
+    } else {
+        Primitive::None
+    };
+
+    Primitive::Group {
+        primitives: vec![handle, notch],
+    }
+}
+
+fn draw_span(
+    bounds: &Rectangle,
+    first: Normal,
+    last: Normal,
+    style: &ModRangeStyle,
+) -> Primitive {
+    let (x, width) = match style.placement {
+        ModRangePlacement::Center { width, offset } => {
+            (bounds.x + offset + ((bounds.width - width) / 2.0), width)
+        }
+        ModRangePlacement::CenterFilled { edge_padding } => {
+            (bounds.x + edge_padding, bounds.width - (edge_padding * 2.0))
+        }
+        ModRangePlacement::Left { width, offset } => {
+            (bounds.x + offset - width, width)
+        }
+        ModRangePlacement::Right { width, offset } => {
+            (bounds.x + bounds.width + offset, width)
+        }
+    };
+
+    let back = if let Some(back_color) = style.back_color {
+        Primitive::Quad {
+            bounds: Rectangle {
+                x,
+                y: bounds.y,
+                width,
+                height: bounds.height,
+            },
+            background: Background::Color(back_color),
+            border_radius: style.back_border_radius,
+            border_width: style.back_border_width,
+            border_color: style.back_border_color,
+        }
+    } else {
+        Primitive::None
+    };
+
+    let filled = if first.as_f32() != last.as_f32() {
+        let (start, end, color) = if first.as_f32() < last.as_f32() {
+            (last.as_f32_inv(), first.as_f32_inv(), style.filled_color)
+        } else {
+            (
+                first.as_f32_inv(),
+                last.as_f32_inv(),
+                style.filled_inverse_color,
+            )
+        };
+
+        let start_offset = bounds.height * start;
+        let filled_height = (bounds.height * end) - start_offset;
+
+        Primitive::Quad {
+            bounds: Rectangle {
+                x,
+                y: bounds.y + start_offset,
+                width,
+                height: filled_height,
+            },
+            background: Background::Color(color),
+            border_radius: style.back_border_radius,
+            border_width: style.back_border_width,
+            border_color: Color::TRANSPARENT,
+        }
+    } else {
+        Primitive::None
+    };
+
+    Primitive::Group {
+        primitives: vec![back, filled],
+    }
+}
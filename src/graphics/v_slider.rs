@@ -3,16 +3,18 @@
 //! [`Param`]: ../core/param/trait.Param.html
 
 use crate::core::{ModulationRange, Normal};
-use crate::graphics::{text_marks, tick_marks};
+use crate::graphics::{
+    filmstrip, text_marks, tick_marks, tooltip, value_entry,
+};
 use crate::native::v_slider;
-use iced_graphics::{Backend, Primitive, Renderer};
+use iced_graphics::{backend, Backend, Primitive, Renderer};
 use iced_native::{mouse, Background, Color, Point, Rectangle};
 
 pub use crate::native::v_slider::State;
 pub use crate::style::v_slider::{
     ClassicHandle, ClassicRail, ClassicStyle, ModRangePlacement, ModRangeStyle,
-    RectBipolarStyle, RectStyle, Style, StyleSheet, TextMarksStyle,
-    TextureStyle, TickMarksStyle,
+    ModValueStyle, RectBipolarStyle, RectStyle, Style, StyleSheet,
+    TextMarksStyle, TextureStyle, TickMarksStyle,
 };
 
 struct ValueMarkers<'a> {
@@ -20,10 +22,12 @@ struct ValueMarkers<'a> {
     text_marks: Option<&'a text_marks::Group>,
     mod_range_1: Option<&'a ModulationRange>,
     mod_range_2: Option<&'a ModulationRange>,
+    mod_value: Option<Normal>,
     tick_marks_style: Option<TickMarksStyle>,
     text_marks_style: Option<TextMarksStyle>,
     mod_range_style_1: Option<ModRangeStyle>,
     mod_range_style_2: Option<ModRangeStyle>,
+    mod_value_style: Option<ModValueStyle>,
 }
 
 /// A vertical slider GUI widget that controls a [`Param`]
@@ -35,49 +39,74 @@ struct ValueMarkers<'a> {
 pub type VSlider<'a, Message, Backend> =
     v_slider::VSlider<'a, Message, Renderer<Backend>>;
 
-impl<B: Backend> v_slider::Renderer for Renderer<B> {
+impl<B: Backend + backend::Text> v_slider::Renderer for Renderer<B> {
     type Style = Box<dyn StyleSheet>;
 
+    fn handle_height(
+        &self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        is_dragging: bool,
+        is_focused: bool,
+        style_sheet: &Self::Style,
+    ) -> f32 {
+        let is_mouse_over = round_bounds(&bounds).contains(cursor_position);
+
+        match current_style(
+            &**style_sheet,
+            is_mouse_over,
+            is_dragging,
+            is_focused,
+        ) {
+            Style::Texture(style) => f32::from(style.handle_height),
+            Style::Filmstrip(_) => 0.0,
+            Style::Classic(style) => f32::from(style.handle.height),
+            Style::Rect(style) => f32::from(style.handle_height),
+            Style::RectBipolar(style) => f32::from(style.handle_height),
+        }
+    }
+
     fn draw(
         &mut self,
         bounds: Rectangle,
         cursor_position: Point,
         normal: Normal,
         is_dragging: bool,
+        is_focused: bool,
+        entry_text: Option<&str>,
+        tooltip_text: Option<&str>,
+        viewport: &Rectangle,
         mod_range_1: Option<&ModulationRange>,
         mod_range_2: Option<&ModulationRange>,
+        mod_value: Option<Normal>,
         tick_marks: Option<&tick_marks::Group>,
         text_marks: Option<&text_marks::Group>,
         style_sheet: &Self::Style,
         tick_marks_cache: &tick_marks::PrimitiveCache,
         text_marks_cache: &text_marks::PrimitiveCache,
     ) -> Self::Output {
-        let is_mouse_over = bounds.contains(cursor_position);
+        let bounds = round_bounds(&bounds);
 
-        let style = if is_dragging {
-            style_sheet.dragging()
-        } else if is_mouse_over {
-            style_sheet.hovered()
-        } else {
-            style_sheet.active()
-        };
+        let is_mouse_over = bounds.contains(cursor_position);
 
-        let bounds = Rectangle {
-            x: bounds.x.round(),
-            y: bounds.y.round(),
-            width: bounds.width.round(),
-            height: bounds.height.round(),
-        };
+        let style = current_style(
+            &**style_sheet,
+            is_mouse_over,
+            is_dragging,
+            is_focused,
+        );
 
         let value_markers = ValueMarkers {
             tick_marks,
             text_marks,
             mod_range_1,
             mod_range_2,
+            mod_value,
             tick_marks_style: style_sheet.tick_marks_style(),
             text_marks_style: style_sheet.text_marks_style(),
             mod_range_style_1: style_sheet.mod_range_style(),
             mod_range_style_2: style_sheet.mod_range_style_2(),
+            mod_value_style: style_sheet.mod_value_style(),
         };
 
         let primitives = match style {
@@ -89,6 +118,14 @@ impl<B: Backend> v_slider::Renderer for Renderer<B> {
                 tick_marks_cache,
                 text_marks_cache,
             ),
+            Style::Filmstrip(style) => draw_filmstrip_style(
+                normal,
+                &bounds,
+                &style,
+                &value_markers,
+                tick_marks_cache,
+                text_marks_cache,
+            ),
             Style::Classic(style) => draw_classic_style(
                 normal,
                 &bounds,
@@ -115,7 +152,62 @@ impl<B: Backend> v_slider::Renderer for Renderer<B> {
             ),
         };
 
-        (primitives, mouse::Interaction::default())
+        let entry = match entry_text {
+            Some(text) => value_entry::draw_value_entry(
+                &bounds,
+                text,
+                &style_sheet.value_entry_style(),
+            ),
+            None => Primitive::None,
+        };
+
+        let tooltip = match tooltip_text {
+            Some(text) => tooltip::draw_tooltip(
+                self.backend(),
+                &bounds,
+                viewport,
+                text,
+                &style_sheet.tooltip_style(),
+            ),
+            None => Primitive::None,
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![primitives, entry, tooltip],
+            },
+            mouse::Interaction::default(),
+        )
+    }
+}
+
+/// Rounds the bounds of the slider to whole pixels. Both the handle size
+/// and the drawing use the rounded bounds, so they agree on the style.
+fn round_bounds(bounds: &Rectangle) -> Rectangle {
+    Rectangle {
+        x: bounds.x.round(),
+        y: bounds.y.round(),
+        width: bounds.width.round(),
+        height: bounds.height.round(),
+    }
+}
+
+/// Returns the style to draw with, so that the handle size used to map
+/// clicks comes from the same style that is drawn.
+fn current_style(
+    style_sheet: &dyn StyleSheet,
+    is_mouse_over: bool,
+    is_dragging: bool,
+    is_focused: bool,
+) -> Style {
+    if is_dragging {
+        style_sheet.dragging()
+    } else if is_focused {
+        style_sheet.focused()
+    } else if is_mouse_over {
+        style_sheet.hovered()
+    } else {
+        style_sheet.active()
     }
 }
 
@@ -125,7 +217,7 @@ fn draw_value_markers<'a>(
     value_markers: &ValueMarkers<'a>,
     tick_marks_cache: &tick_marks::PrimitiveCache,
     text_marks_cache: &text_marks::PrimitiveCache,
-) -> (Primitive, Primitive, Primitive, Primitive) {
+) -> (Primitive, Primitive, Primitive, Primitive, Primitive) {
     (
         draw_tick_marks(
             mark_bounds,
@@ -149,6 +241,11 @@ fn draw_value_markers<'a>(
             value_markers.mod_range_2,
             &value_markers.mod_range_style_2,
         ),
+        draw_mod_value(
+            mark_bounds,
+            value_markers.mod_value,
+            &value_markers.mod_value_style,
+        ),
     )
 }
 
@@ -295,6 +392,36 @@ fn draw_mod_range(
     }
 }
 
+fn draw_mod_value(
+    bounds: &Rectangle,
+    mod_value: Option<Normal>,
+    style: &Option<ModValueStyle>,
+) -> Primitive {
+    if let Some(mod_value) = mod_value {
+        if let Some(style) = style {
+            let radius = style.diameter / 2.0;
+
+            Primitive::Quad {
+                bounds: Rectangle {
+                    x: (bounds.center_x() + style.offset - radius).round(),
+                    y: (bounds.y + mod_value.scale_inv(bounds.height) - radius)
+                        .round(),
+                    width: style.diameter,
+                    height: style.diameter,
+                },
+                background: Background::Color(style.color),
+                border_radius: radius,
+                border_width: style.border_width,
+                border_color: style.border_color,
+            }
+        } else {
+            Primitive::None
+        }
+    } else {
+        Primitive::None
+    }
+}
+
 fn draw_texture_style<'a>(
     normal: Normal,
     bounds: &Rectangle,
@@ -310,13 +437,14 @@ fn draw_texture_style<'a>(
         height: bounds.height - f32::from(style.handle_height),
     };
 
-    let (tick_marks, text_marks, mod_range_1, mod_range_2) = draw_value_markers(
-        &value_bounds,
-        &value_bounds,
-        value_markers,
-        tick_marks_cache,
-        text_marks_cache,
-    );
+    let (tick_marks, text_marks, mod_range_1, mod_range_2, mod_value) =
+        draw_value_markers(
+            &value_bounds,
+            &value_bounds,
+            value_markers,
+            tick_marks_cache,
+            text_marks_cache,
+        );
 
     let (left_rail, right_rail) = draw_classic_rail(&bounds, &style.rail);
 
@@ -342,6 +470,38 @@ fn draw_texture_style<'a>(
             handle,
             mod_range_1,
             mod_range_2,
+            mod_value,
+        ],
+    }
+}
+
+fn draw_filmstrip_style<'a>(
+    normal: Normal,
+    bounds: &Rectangle,
+    style: &filmstrip::Style,
+    value_markers: &ValueMarkers<'a>,
+    tick_marks_cache: &tick_marks::PrimitiveCache,
+    text_marks_cache: &text_marks::PrimitiveCache,
+) -> Primitive {
+    let (tick_marks, text_marks, mod_range_1, mod_range_2, mod_value) =
+        draw_value_markers(
+            bounds,
+            bounds,
+            value_markers,
+            tick_marks_cache,
+            text_marks_cache,
+        );
+
+    let slider = filmstrip::draw_filmstrip(bounds, normal, style);
+
+    Primitive::Group {
+        primitives: vec![
+            tick_marks,
+            text_marks,
+            slider,
+            mod_range_1,
+            mod_range_2,
+            mod_value,
         ],
     }
 }
@@ -363,13 +523,14 @@ fn draw_classic_style<'a>(
         height: bounds.height - handle_height,
     };
 
-    let (tick_marks, text_marks, mod_range_1, mod_range_2) = draw_value_markers(
-        &value_bounds,
-        &value_bounds,
-        value_markers,
-        tick_marks_cache,
-        text_marks_cache,
-    );
+    let (tick_marks, text_marks, mod_range_1, mod_range_2, mod_value) =
+        draw_value_markers(
+            &value_bounds,
+            &value_bounds,
+            value_markers,
+            tick_marks_cache,
+            text_marks_cache,
+        );
 
     let (left_rail, right_rail) = draw_classic_rail(&bounds, &style.rail);
 
@@ -419,6 +580,7 @@ fn draw_classic_style<'a>(
             handle_notch,
             mod_range_1,
             mod_range_2,
+            mod_value,
         ],
     }
 }
@@ -443,13 +605,14 @@ fn draw_rect_style<'a>(
         height: bounds.height - handle_height,
     };
 
-    let (tick_marks, text_marks, mod_range_1, mod_range_2) = draw_value_markers(
-        &value_bounds,
-        &bounds,
-        value_markers,
-        tick_marks_cache,
-        text_marks_cache,
-    );
+    let (tick_marks, text_marks, mod_range_1, mod_range_2, mod_value) =
+        draw_value_markers(
+            &value_bounds,
+            &bounds,
+            value_markers,
+            tick_marks_cache,
+            text_marks_cache,
+        );
 
     let empty_rect = Primitive::Quad {
         bounds: Rectangle {
@@ -505,6 +668,7 @@ fn draw_rect_style<'a>(
             handle,
             mod_range_1,
             mod_range_2,
+            mod_value,
         ],
     }
 }
@@ -529,13 +693,14 @@ fn draw_rect_bipolar_style<'a>(
         height: bounds.height - handle_height,
     };
 
-    let (tick_marks, text_marks, mod_range_1, mod_range_2) = draw_value_markers(
-        &value_bounds,
-        &bounds,
-        value_markers,
-        tick_marks_cache,
-        text_marks_cache,
-    );
+    let (tick_marks, text_marks, mod_range_1, mod_range_2, mod_value) =
+        draw_value_markers(
+            &value_bounds,
+            &bounds,
+            value_markers,
+            tick_marks_cache,
+            text_marks_cache,
+        );
 
     let empty_rect = Primitive::Quad {
         bounds: Rectangle {
@@ -621,11 +786,12 @@ fn draw_rect_bipolar_style<'a>(
             handle,
             mod_range_1,
             mod_range_2,
+            mod_value,
         ],
     }
 }
 
-fn draw_classic_rail(
+pub(crate) fn draw_classic_rail(
     bounds: &Rectangle,
     style: &ClassicRail,
 ) -> (Primitive, Primitive) {
@@ -0,0 +1,50 @@
+//! Draw the text field used to type in an exact value
+
+use iced_graphics::{HorizontalAlignment, Primitive, VerticalAlignment};
+use iced_native::{Background, Rectangle};
+
+pub use crate::style::value_entry::*;
+
+/// Draws a value entry text field with the given text, centered over
+/// `bounds`.
+pub fn draw_value_entry(
+    bounds: &Rectangle,
+    text: &str,
+    style: &Style,
+) -> Primitive {
+    let width = f32::from(style.width);
+    let height = f32::from(style.height);
+
+    let field_bounds = Rectangle {
+        x: (bounds.center_x() - (width / 2.0)).round(),
+        y: (bounds.center_y() - (height / 2.0)).round(),
+        width,
+        height,
+    };
+
+    let back = Primitive::Quad {
+        bounds: field_bounds,
+        background: Background::Color(style.back_color),
+        border_radius: style.border_radius,
+        border_width: style.border_width,
+        border_color: style.border_color,
+    };
+
+    let text = Primitive::Text {
+        content: text.to_string(),
+        size: f32::from(style.text_size),
+        bounds: Rectangle {
+            x: field_bounds.center_x(),
+            y: field_bounds.center_y(),
+            ..field_bounds
+        },
+        color: style.text_color,
+        font: style.font,
+        horizontal_alignment: HorizontalAlignment::Center,
+        vertical_alignment: VerticalAlignment::Center,
+    };
+
+    Primitive::Group {
+        primitives: vec![back, text],
+    }
+}
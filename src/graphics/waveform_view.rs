@@ -0,0 +1,188 @@
+//! Display the peak amplitude of a sample buffer over time. The view can be
+//! zoomed and scrolled, and a region and start/loop/end markers can be
+//! selected.
+
+use crate::native::waveform_view;
+use iced_graphics::canvas::{Frame, LineJoin, Path, Stroke};
+use iced_graphics::{Backend, Primitive, Renderer};
+use iced_native::{mouse, Background, Color, Point, Rectangle, Size, Vector};
+
+pub use crate::native::waveform_view::{DragTarget, Edit, MarkerKind, State};
+pub use crate::style::waveform_view::{MarkerStyle, Style, StyleSheet};
+
+/// A waveform view GUI widget that displays the peak amplitude of a sample
+/// buffer over time.
+pub type WaveformView<'a, Message, Backend> =
+    waveform_view::WaveformView<'a, Message, Renderer<Backend>>;
+
+impl<B: Backend> waveform_view::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        columns: &[(f32, f32)],
+        selection: Option<(f32, f32)>,
+        markers: &[(MarkerKind, f32)],
+        active_marker: Option<MarkerKind>,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if is_mouse_over {
+            style_sheet.hovered()
+        } else {
+            style_sheet.active()
+        };
+
+        let bounds_x = bounds.x.floor();
+        let bounds_y = bounds.y.floor();
+
+        let bounds_width = bounds.width.floor();
+        let bounds_height = bounds.height.floor();
+
+        let back = Primitive::Quad {
+            bounds: Rectangle {
+                x: bounds_x,
+                y: bounds_y,
+                width: bounds_width,
+                height: bounds_height,
+            },
+            background: Background::Color(style.back_color),
+            border_radius: 0.0,
+            border_width: style.back_border_width,
+            border_color: style.back_border_color,
+        };
+
+        if bounds_width <= 0.0 || bounds_height <= 0.0 {
+            return (back, mouse::Interaction::default());
+        }
+
+        let mut primitives: Vec<Primitive> =
+            Vec::with_capacity(markers.len() + 4);
+
+        primitives.push(back);
+
+        if let Some((start, end)) = selection {
+            let start = start.clamp(0.0, 1.0);
+            let end = end.clamp(0.0, 1.0);
+
+            if end > start {
+                let x = (bounds_x + (start * bounds_width)).round();
+
+                primitives.push(Primitive::Quad {
+                    bounds: Rectangle {
+                        x,
+                        y: bounds_y,
+                        width: ((bounds_x + (end * bounds_width)).round() - x)
+                            .max(1.0),
+                        height: bounds_height,
+                    },
+                    background: Background::Color(style.selection_color),
+                    border_radius: 0.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+        }
+
+        let half_height = bounds_height / 2.0;
+
+        if style.center_line_width > 0.0 {
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x: bounds_x,
+                    y: (bounds_y + half_height
+                        - (style.center_line_width / 2.0))
+                        .round(),
+                    width: bounds_width,
+                    height: style.center_line_width,
+                },
+                background: Background::Color(style.center_line_color),
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            });
+        }
+
+        if !columns.is_empty() {
+            let sample_to_y = |sample: f32| -> f32 {
+                half_height - (sample.clamp(-1.0, 1.0) * half_height)
+            };
+
+            // Trace the maximums from left to right, then the minimums
+            // from right to left.
+            let wave = Path::new(|p| {
+                for (i, (_, max)) in columns.iter().enumerate() {
+                    let point = Point::new(i as f32 + 0.5, sample_to_y(*max));
+
+                    if i == 0 {
+                        p.move_to(point);
+                    } else {
+                        p.line_to(point);
+                    }
+                }
+
+                for (i, (min, _)) in columns.iter().enumerate().rev() {
+                    p.line_to(Point::new(i as f32 + 0.5, sample_to_y(*min)));
+                }
+
+                p.close();
+            });
+
+            let mut frame = Frame::new(Size::new(bounds_width, bounds_height));
+
+            frame.fill(&wave, style.wave_color);
+
+            // Stroke the outline so that quiet or zoomed in parts of the
+            // waveform are still visible.
+            frame.stroke(
+                &wave,
+                Stroke {
+                    width: 1.0,
+                    color: style.wave_color,
+                    line_join: LineJoin::Round,
+                    ..Stroke::default()
+                },
+            );
+
+            primitives.push(Primitive::Translate {
+                translation: Vector::new(bounds_x, bounds_y),
+                content: Box::new(frame.into_geometry().into_primitive()),
+            });
+        }
+
+        for (kind, x) in markers.iter() {
+            let color = match kind {
+                MarkerKind::Start => style.markers.start_color,
+                MarkerKind::Loop => style.markers.loop_color,
+                MarkerKind::End => style.markers.end_color,
+            };
+
+            let width = if active_marker == Some(*kind) {
+                style.markers.active_width
+            } else {
+                style.markers.width
+            };
+
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x: (bounds_x + (x * bounds_width) - (width / 2.0)).round(),
+                    y: bounds_y,
+                    width,
+                    height: bounds_height,
+                },
+                background: Background::Color(color),
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            });
+        }
+
+        (
+            Primitive::Group { primitives },
+            mouse::Interaction::default(),
+        )
+    }
+}
@@ -0,0 +1,207 @@
+//! Display a single-cycle waveform, or a stack of wavetable frames where the
+//! current frame is controlled by a [`NormalParam`].
+//!
+//! [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
+
+use crate::native::wavetable_view;
+use iced_graphics::canvas::{Frame, LineJoin, Path, Stroke};
+use iced_graphics::{Backend, Primitive, Renderer};
+use iced_native::{mouse, Background, Point, Rectangle, Size, Vector};
+
+pub use crate::native::wavetable_view::State;
+pub use crate::style::wavetable_view::{StackStyle, Style, StyleSheet};
+
+/// A wavetable view GUI widget that displays a single-cycle waveform, or a
+/// stack of wavetable frames drawn as a pseudo-3D pile.
+pub type WavetableView<'a, Message, Backend> =
+    wavetable_view::WavetableView<'a, Message, Renderer<Backend>>;
+
+impl<B: Backend> wavetable_view::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        frames: &[Vec<f32>],
+        current_frame: usize,
+        is_dragging: bool,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if is_dragging {
+            style_sheet.dragging()
+        } else if is_mouse_over {
+            style_sheet.hovered()
+        } else {
+            style_sheet.active()
+        };
+
+        let bounds_x = bounds.x.floor();
+        let bounds_y = bounds.y.floor();
+
+        let bounds_width = bounds.width.floor();
+        let bounds_height = bounds.height.floor();
+
+        let back = Primitive::Quad {
+            bounds: Rectangle {
+                x: bounds_x,
+                y: bounds_y,
+                width: bounds_width,
+                height: bounds_height,
+            },
+            background: Background::Color(style.back_color),
+            border_radius: 0.0,
+            border_width: style.back_border_width,
+            border_color: style.back_border_color,
+        };
+
+        let border_width = style.back_border_width;
+        let twice_border_width = border_width * 2.0;
+
+        let range_width = bounds_width - twice_border_width;
+        let range_height = bounds_height - twice_border_width;
+
+        if range_width <= 0.0 || range_height <= 0.0 || frames.is_empty() {
+            return (back, mouse::Interaction::default());
+        }
+
+        let is_stack = frames.len() > 1;
+
+        let (offset_x, offset_y) = if is_stack {
+            (
+                style.stack.offset_x.min(range_width - 1.0).max(0.0),
+                style.stack.offset_y.min(range_height - 1.0).max(0.0),
+            )
+        } else {
+            (0.0, 0.0)
+        };
+
+        // The size of a single frame. The first frame sits in the bottom
+        // left corner, and the last frame in the top right corner.
+        let frame_size =
+            Size::new(range_width - offset_x, range_height - offset_y);
+
+        let frame_rect = |index: usize| -> Rectangle {
+            let t = if is_stack {
+                index as f32 / (frames.len() - 1) as f32
+            } else {
+                0.0
+            };
+
+            Rectangle {
+                x: t * offset_x,
+                y: offset_y - (t * offset_y),
+                width: frame_size.width,
+                height: frame_size.height,
+            }
+        };
+
+        let mut frame = Frame::new(Size::new(range_width, range_height));
+
+        if is_stack {
+            let last = frames.len() - 1;
+            let count =
+                frames.len().min(usize::from(style.stack.max_frames).max(2));
+
+            // Draw from back to front.
+            for k in (0..count).rev() {
+                let index =
+                    ((k * last) as f32 / (count - 1) as f32).round() as usize;
+
+                if index != current_frame {
+                    frame.stroke(
+                        &waveform_path(&frames[index], frame_rect(index)),
+                        Stroke {
+                            width: style.stack.line_width,
+                            color: style.stack.line_color,
+                            line_join: LineJoin::Round,
+                            ..Stroke::default()
+                        },
+                    );
+                }
+            }
+        }
+
+        let current_frame = current_frame.min(frames.len() - 1);
+        let current_rect = frame_rect(current_frame);
+
+        if let Some(center_line_color) = style.center_line_color {
+            let center_y =
+                (current_rect.y + (current_rect.height / 2.0)).floor();
+
+            frame.stroke(
+                &Path::line(
+                    Point::new(current_rect.x, center_y),
+                    Point::new(current_rect.x + current_rect.width, center_y),
+                ),
+                Stroke {
+                    width: style.center_line_width,
+                    color: center_line_color,
+                    ..Stroke::default()
+                },
+            );
+        }
+
+        frame.stroke(
+            &waveform_path(&frames[current_frame], current_rect),
+            Stroke {
+                width: style.line_width,
+                color: style.line_color,
+                line_join: LineJoin::Round,
+                ..Stroke::default()
+            },
+        );
+
+        let waveforms = Primitive::Translate {
+            translation: Vector::new(
+                bounds_x + border_width,
+                bounds_y + border_width,
+            ),
+            content: Box::new(frame.into_geometry().into_primitive()),
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![back, waveforms],
+            },
+            mouse::Interaction::default(),
+        )
+    }
+}
+
+/// Builds the path of a single-cycle waveform scaled to the given
+/// rectangle. Samples are skipped if there are more samples than pixels.
+fn waveform_path(samples: &[f32], rect: Rectangle) -> Path {
+    let half_height = rect.height / 2.0;
+    let center_y = rect.y + half_height;
+
+    let sample_to_y = |sample: f32| -> f32 {
+        center_y - (sample.clamp(-1.0, 1.0) * half_height)
+    };
+
+    Path::new(|p| {
+        if samples.len() < 2 {
+            return;
+        }
+
+        let last = samples.len() - 1;
+        let num_points = last.min(rect.width.ceil().max(1.0) as usize);
+
+        for i in 0..=num_points {
+            let normal = i as f32 / num_points as f32;
+            let sample =
+                samples[((normal * last as f32).round() as usize).min(last)];
+
+            let point =
+                Point::new(rect.x + (normal * rect.width), sample_to_y(sample));
+
+            if i == 0 {
+                p.move_to(point);
+            } else {
+                p.line_to(point);
+            }
+        }
+    })
+}
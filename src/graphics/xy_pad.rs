@@ -4,8 +4,9 @@
 //! [`Param`]: ../core/param/trait.Param.html
 
 use crate::core::Normal;
+use crate::graphics::tooltip;
 use crate::native::xy_pad;
-use iced_graphics::{Backend, Primitive, Renderer};
+use iced_graphics::{backend, Backend, Primitive, Renderer};
 use iced_native::{mouse, Background, Color, Point, Rectangle};
 
 pub use crate::native::xy_pad::State;
@@ -24,7 +25,7 @@ pub use crate::style::xy_pad::{
 pub type XYPad<'a, Message, Backend> =
     xy_pad::XYPad<'a, Message, Renderer<Backend>>;
 
-impl<B: Backend> xy_pad::Renderer for Renderer<B> {
+impl<B: Backend + backend::Text> xy_pad::Renderer for Renderer<B> {
     type Style = Box<dyn StyleSheet>;
 
     fn draw(
@@ -34,12 +35,17 @@ impl<B: Backend> xy_pad::Renderer for Renderer<B> {
         normal_x: Normal,
         normal_y: Normal,
         is_dragging: bool,
+        is_focused: bool,
+        tooltip_text: Option<&str>,
+        viewport: &Rectangle,
         style_sheet: &Self::Style,
     ) -> Self::Output {
         let is_mouse_over = bounds.contains(cursor_position);
 
         let style = if is_dragging {
             style_sheet.dragging()
+        } else if is_focused {
+            style_sheet.focused()
         } else if is_mouse_over {
             style_sheet.hovered()
         } else {
@@ -184,6 +190,17 @@ impl<B: Backend> xy_pad::Renderer for Renderer<B> {
             }
         };
 
+        let tooltip = match tooltip_text {
+            Some(text) => tooltip::draw_tooltip(
+                self.backend(),
+                &bounds,
+                viewport,
+                text,
+                &style_sheet.tooltip_style(),
+            ),
+            None => Primitive::None,
+        };
+
         (
             Primitive::Group {
                 primitives: vec![
@@ -193,6 +210,7 @@ impl<B: Backend> xy_pad::Renderer for Renderer<B> {
                     h_rail,
                     v_rail,
                     handle,
+                    tooltip,
                 ],
             },
             mouse::Interaction::default(),
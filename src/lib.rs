@@ -208,18 +208,30 @@ pub mod style;
 #[doc(no_inline)]
 pub use crate::core::*;
 
+#[doc(no_inline)]
+pub use crate::native::focus;
+
 #[cfg(not(target_arch = "wasm32"))]
 mod platform {
     #[doc(no_inline)]
     pub use crate::graphics::{
-        h_slider, knob, mod_range_input, ramp, text_marks, tick_marks,
-        v_slider, xy_pad,
+        adsr_envelope, bar_meter, envelope_editor, filmstrip, goniometer,
+        h_range_slider, h_slider, keys, knob, mod_range_input, mod_wheel,
+        oscilloscope, par_eq_editor, ramp, spectrum_analyzer, text_marks,
+        tick_marks, tooltip, v_range_slider, v_slider, value_entry,
+        waveform_view, wavetable_view, xy_pad,
     };
 
     #[doc(no_inline)]
     pub use {
-        h_slider::HSlider, knob::Knob, mod_range_input::ModRangeInput,
-        ramp::Ramp, v_slider::VSlider, xy_pad::XYPad,
+        adsr_envelope::ADSREnvelope, bar_meter::BarMeter,
+        envelope_editor::EnvelopeEditor, goniometer::Goniometer,
+        h_range_slider::HRangeSlider, h_slider::HSlider, keys::Keys,
+        knob::Knob, mod_range_input::ModRangeInput, mod_wheel::ModWheel,
+        oscilloscope::Oscilloscope, par_eq_editor::ParEqEditor, ramp::Ramp,
+        spectrum_analyzer::SpectrumAnalyzer, v_range_slider::VRangeSlider,
+        v_slider::VSlider, waveform_view::WaveformView,
+        wavetable_view::WavetableView, xy_pad::XYPad,
     };
 }
 
@@ -0,0 +1,849 @@
+//! Display an ADSR envelope with optional delay and hold stages. Each stage
+//! controls a [`NormalParam`], and the attack, decay, and release segments
+//! can be curved.
+//!
+//! [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
+
+use std::fmt::Debug;
+
+use iced_native::{
+    event, keyboard, layout, mouse, Clipboard, Element, Event, Hasher, Layout,
+    Length, Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+use crate::core::{Normal, NormalParam};
+
+static DEFAULT_WIDTH: u16 = 300;
+static DEFAULT_HEIGHT: u16 = 120;
+static DEFAULT_CURVE_SCALAR: f32 = 0.005;
+static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+static DEFAULT_HIT_RADIUS: f32 = 6.0;
+
+/// A parameter of an [`ADSREnvelope`].
+///
+/// [`ADSREnvelope`]: struct.ADSREnvelope.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Param {
+    /// The time of the delay stage
+    Delay,
+    /// The time of the attack stage
+    Attack,
+    /// The time of the hold stage
+    Hold,
+    /// The time of the decay stage
+    Decay,
+    /// The level of the sustain stage
+    Sustain,
+    /// The time of the release stage
+    Release,
+    /// The curve of the attack segment
+    AttackCurve,
+    /// The curve of the decay segment
+    DecayCurve,
+    /// The curve of the release segment
+    ReleaseCurve,
+}
+
+/// The part of an [`ADSREnvelope`] that is hovered or being dragged.
+///
+/// [`ADSREnvelope`]: struct.ADSREnvelope.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Target {
+    /// The handle at the end of a stage. This is either `Param::Delay`,
+    /// `Param::Attack`, `Param::Hold`, `Param::Decay` (which also controls
+    /// the sustain level), or `Param::Release`.
+    Handle(Param),
+    /// A segment of the envelope. This is either `Param::AttackCurve`,
+    /// `Param::DecayCurve`, `Param::ReleaseCurve`, or `Param::Sustain` (the
+    /// sustain segment, which is dragged up or down to set the sustain
+    /// level).
+    Segment(Param),
+}
+
+/// The shape of an [`ADSREnvelope`] in screen coordinates.
+///
+/// [`ADSREnvelope`]: struct.ADSREnvelope.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Shape {
+    /// The start of the envelope
+    pub start: Point,
+    /// The end of the delay stage
+    pub delay: Option<Point>,
+    /// The end of the attack stage (the peak)
+    pub attack: Point,
+    /// The end of the hold stage
+    pub hold: Option<Point>,
+    /// The end of the decay stage (the start of the sustain stage)
+    pub decay: Point,
+    /// The end of the sustain stage (the start of the release stage)
+    pub sustain: Point,
+    /// The end of the release stage
+    pub release: Point,
+    /// The curve of the attack segment
+    pub attack_curve: Normal,
+    /// The curve of the decay segment
+    pub decay_curve: Normal,
+    /// The curve of the release segment
+    pub release_curve: Normal,
+}
+
+impl Shape {
+    /// Returns the point at the end of the stage that the handle of the
+    /// given [`Param`] controls, if any.
+    ///
+    /// [`Param`]: enum.Param.html
+    pub fn handle(&self, param: Param) -> Option<Point> {
+        match param {
+            Param::Delay => self.delay,
+            Param::Attack => Some(self.attack),
+            Param::Hold => self.hold,
+            Param::Decay => Some(self.decay),
+            Param::Release => Some(self.release),
+            _ => None,
+        }
+    }
+
+    /// Returns the start and end point of the segment of the given
+    /// [`Param`], if any.
+    ///
+    /// [`Param`]: enum.Param.html
+    pub fn segment(&self, param: Param) -> Option<(Point, Point)> {
+        match param {
+            Param::AttackCurve => {
+                Some((self.delay.unwrap_or(self.start), self.attack))
+            }
+            Param::DecayCurve => {
+                Some((self.hold.unwrap_or(self.attack), self.decay))
+            }
+            Param::Sustain => Some((self.decay, self.sustain)),
+            Param::ReleaseCurve => Some((self.sustain, self.release)),
+            _ => None,
+        }
+    }
+}
+
+/// An ADSR envelope GUI widget with optional delay and hold stages.
+///
+/// * Drag the handle at the end of a stage left or right to change its
+///   time. The decay handle can also be dragged up or down to change the
+///   sustain level.
+/// * Drag the attack, decay, or release segment up or down to change its
+///   curve.
+/// * Drag the sustain segment up or down to change the sustain level.
+/// * Double-click a handle or segment to reset it to its default value.
+///
+/// [`ADSREnvelope`]: struct.ADSREnvelope.html
+#[allow(missing_debug_implementations)]
+pub struct ADSREnvelope<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    on_change: Box<dyn Fn(Param, Normal) -> Message>,
+    formatter: Option<Box<dyn Fn(Param, Normal) -> String>>,
+    curve_scalar: f32,
+    modifier_scalar: f32,
+    modifier_keys: keyboard::Modifiers,
+    hit_radius: f32,
+    width: Length,
+    height: Length,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer>
+    ADSREnvelope<'a, Message, Renderer>
+{
+    /// Creates a new [`ADSREnvelope`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`ADSREnvelope`]
+    ///   * a function that will be called when a [`Param`] of the
+    ///     [`ADSREnvelope`] is changed
+    ///
+    /// [`State`]: struct.State.html
+    /// [`Param`]: enum.Param.html
+    /// [`ADSREnvelope`]: struct.ADSREnvelope.html
+    pub fn new<F>(state: &'a mut State, on_change: F) -> Self
+    where
+        F: 'static + Fn(Param, Normal) -> Message,
+    {
+        ADSREnvelope {
+            state,
+            on_change: Box::new(on_change),
+            formatter: None,
+            curve_scalar: DEFAULT_CURVE_SCALAR,
+            modifier_scalar: DEFAULT_MODIFIER_SCALAR,
+            modifier_keys: keyboard::Modifiers {
+                control: true,
+                ..Default::default()
+            },
+            hit_radius: DEFAULT_HIT_RADIUS,
+            width: Length::Units(DEFAULT_WIDTH),
+            height: Length::Units(DEFAULT_HEIGHT),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`ADSREnvelope`].
+    /// The default width is `Length::from(Length::Units(300))`.
+    ///
+    /// [`ADSREnvelope`]: struct.ADSREnvelope.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`ADSREnvelope`].
+    /// The default height is `Length::from(Length::Units(120))`.
+    ///
+    /// [`ADSREnvelope`]: struct.ADSREnvelope.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`ADSREnvelope`].
+    ///
+    /// [`ADSREnvelope`]: struct.ADSREnvelope.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets a function that formats the value of a [`Param`] (for example
+    /// `"250 ms"` for a stage time). The formatted value of the hovered or
+    /// dragged part of the envelope is displayed next to it.
+    ///
+    /// If this is not set, no value is displayed.
+    ///
+    /// [`Param`]: enum.Param.html
+    pub fn formatter<F>(mut self, formatter: F) -> Self
+    where
+        F: 'static + Fn(Param, Normal) -> String,
+    {
+        self.formatter = Some(Box::new(formatter));
+        self
+    }
+
+    /// Sets how much the curve of a segment will change per `y` pixel
+    /// movement of the mouse.
+    ///
+    /// The default value is `0.005`
+    pub fn curve_scalar(mut self, scalar: f32) -> Self {
+        self.curve_scalar = scalar;
+        self
+    }
+
+    /// Sets the modifier keys of the [`ADSREnvelope`].
+    ///
+    /// The default modifier key is `Ctrl`.
+    ///
+    /// [`ADSREnvelope`]: struct.ADSREnvelope.html
+    pub fn modifier_keys(mut self, modifier_keys: keyboard::Modifiers) -> Self {
+        self.modifier_keys = modifier_keys;
+        self
+    }
+
+    /// Sets the scalar to use when the user drags a handle or a segment
+    /// while holding down the modifier key.
+    ///
+    /// For example, a `modifier_scalar` of `0.5` will cause handles to move
+    /// half as fast when the modifier key is down.
+    ///
+    /// The default `modifier_scalar` is `0.02`, and the default modifier key
+    /// is `Ctrl`.
+    pub fn modifier_scalar(mut self, scalar: f32) -> Self {
+        self.modifier_scalar = scalar;
+        self
+    }
+
+    /// Sets the distance in pixels from the center of a handle within which
+    /// the handle can be grabbed.
+    ///
+    /// The default value is `6.0`
+    pub fn hit_radius(mut self, hit_radius: f32) -> Self {
+        self.hit_radius = hit_radius;
+        self
+    }
+
+    fn target_at(&self, shape: &Shape, cursor: Point) -> Option<Target> {
+        let hit_radius_squared = self.hit_radius * self.hit_radius;
+
+        let handle = [
+            Param::Release,
+            Param::Decay,
+            Param::Hold,
+            Param::Attack,
+            Param::Delay,
+        ]
+        .iter()
+        .filter_map(|param| {
+            shape.handle(*param).map(|point| {
+                let dx = point.x - cursor.x;
+                let dy = point.y - cursor.y;
+
+                (*param, (dx * dx) + (dy * dy))
+            })
+        })
+        .filter(|(_, distance)| *distance <= hit_radius_squared)
+        .min_by(|(_, a), (_, b)| {
+            a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        if let Some((param, _)) = handle {
+            return Some(Target::Handle(param));
+        }
+
+        [
+            Param::AttackCurve,
+            Param::DecayCurve,
+            Param::Sustain,
+            Param::ReleaseCurve,
+        ]
+        .iter()
+        .find(|param| {
+            if let Some((from, to)) = shape.segment(**param) {
+                cursor.x >= from.x && cursor.x < to.x
+            } else {
+                false
+            }
+        })
+        .map(|param| Target::Segment(*param))
+    }
+
+    fn change(
+        &mut self,
+        messages: &mut Vec<Message>,
+        param: Param,
+        normal: Normal,
+    ) {
+        if let Some(normal_param) = self.state.param_mut(param) {
+            normal_param.value = normal;
+
+            messages.push((self.on_change)(param, normal));
+        }
+    }
+}
+
+/// The local state of an [`ADSREnvelope`].
+///
+/// [`ADSREnvelope`]: struct.ADSREnvelope.html
+#[derive(Debug, Copy, Clone)]
+pub struct State {
+    delay: Option<NormalParam>,
+    attack: NormalParam,
+    hold: Option<NormalParam>,
+    decay: NormalParam,
+    sustain: NormalParam,
+    release: NormalParam,
+    attack_curve: NormalParam,
+    decay_curve: NormalParam,
+    release_curve: NormalParam,
+    drag_target: Option<Target>,
+    prev_drag_x: f32,
+    prev_drag_y: f32,
+    continuous_x: f32,
+    continuous_y: f32,
+    pressed_modifiers: keyboard::Modifiers,
+    last_click: Option<mouse::Click>,
+}
+
+impl State {
+    /// Creates a new [`ADSREnvelope`] state without delay and hold stages.
+    ///
+    /// It expects:
+    /// * a [`NormalParam`] for the attack time
+    /// * a [`NormalParam`] for the decay time
+    /// * a [`NormalParam`] for the sustain level
+    /// * a [`NormalParam`] for the release time
+    ///
+    /// All segments start out straight. Use [`set_curve`] to change that.
+    ///
+    /// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+    /// [`set_curve`]: struct.State.html#method.set_curve
+    /// [`ADSREnvelope`]: struct.ADSREnvelope.html
+    pub fn new(
+        attack: NormalParam,
+        decay: NormalParam,
+        sustain: NormalParam,
+        release: NormalParam,
+    ) -> Self {
+        let straight = NormalParam {
+            value: Normal::center(),
+            default: Normal::center(),
+        };
+
+        Self {
+            delay: None,
+            attack,
+            hold: None,
+            decay,
+            sustain,
+            release,
+            attack_curve: straight,
+            decay_curve: straight,
+            release_curve: straight,
+            drag_target: None,
+            prev_drag_x: 0.0,
+            prev_drag_y: 0.0,
+            continuous_x: 0.0,
+            continuous_y: 0.0,
+            pressed_modifiers: Default::default(),
+            last_click: None,
+        }
+    }
+
+    /// Adds a delay stage before the attack stage, with a [`NormalParam`]
+    /// for the delay time.
+    ///
+    /// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+    pub fn with_delay(mut self, delay: NormalParam) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    /// Adds a hold stage between the attack and decay stages, with a
+    /// [`NormalParam`] for the hold time.
+    ///
+    /// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+    pub fn with_hold(mut self, hold: NormalParam) -> Self {
+        self.hold = Some(hold);
+        self
+    }
+
+    /// Sets the [`NormalParam`] of a curve (`Param::AttackCurve`,
+    /// `Param::DecayCurve`, or `Param::ReleaseCurve`). A [`Normal`] value of
+    /// `0.5` represents a straight line, `0.0` is curved downward all the
+    /// way, and `1.0` is curved upward all the way.
+    ///
+    /// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn set_curve(&mut self, curve: Param, normal_param: NormalParam) {
+        match curve {
+            Param::AttackCurve => self.attack_curve = normal_param,
+            Param::DecayCurve => self.decay_curve = normal_param,
+            Param::ReleaseCurve => self.release_curve = normal_param,
+            _ => {}
+        }
+    }
+
+    /// Set the normalized value of a [`Param`]. This does nothing if the
+    /// [`Param`] is an optional stage that was not added.
+    ///
+    /// [`Param`]: enum.Param.html
+    pub fn set_normal(&mut self, param: Param, normal: Normal) {
+        if let Some(normal_param) = self.param_mut(param) {
+            normal_param.value = normal;
+        }
+    }
+
+    /// Get the normalized value of a [`Param`]. This returns `None` if the
+    /// [`Param`] is an optional stage that was not added.
+    ///
+    /// [`Param`]: enum.Param.html
+    pub fn normal(&self, param: Param) -> Option<Normal> {
+        self.param(param).map(|normal_param| normal_param.value)
+    }
+
+    /// Set the normalized default value of a [`Param`].
+    ///
+    /// [`Param`]: enum.Param.html
+    pub fn set_default(&mut self, param: Param, normal: Normal) {
+        if let Some(normal_param) = self.param_mut(param) {
+            normal_param.default = normal;
+        }
+    }
+
+    /// Get the normalized default value of a [`Param`].
+    ///
+    /// [`Param`]: enum.Param.html
+    pub fn default(&self, param: Param) -> Option<Normal> {
+        self.param(param).map(|normal_param| normal_param.default)
+    }
+
+    /// Is the [`ADSREnvelope`] currently in the dragging state?
+    ///
+    /// [`ADSREnvelope`]: struct.ADSREnvelope.html
+    pub fn is_dragging(&self) -> bool {
+        self.drag_target.is_some()
+    }
+
+    fn param(&self, param: Param) -> Option<&NormalParam> {
+        match param {
+            Param::Delay => self.delay.as_ref(),
+            Param::Attack => Some(&self.attack),
+            Param::Hold => self.hold.as_ref(),
+            Param::Decay => Some(&self.decay),
+            Param::Sustain => Some(&self.sustain),
+            Param::Release => Some(&self.release),
+            Param::AttackCurve => Some(&self.attack_curve),
+            Param::DecayCurve => Some(&self.decay_curve),
+            Param::ReleaseCurve => Some(&self.release_curve),
+        }
+    }
+
+    fn param_mut(&mut self, param: Param) -> Option<&mut NormalParam> {
+        match param {
+            Param::Delay => self.delay.as_mut(),
+            Param::Attack => Some(&mut self.attack),
+            Param::Hold => self.hold.as_mut(),
+            Param::Decay => Some(&mut self.decay),
+            Param::Sustain => Some(&mut self.sustain),
+            Param::Release => Some(&mut self.release),
+            Param::AttackCurve => Some(&mut self.attack_curve),
+            Param::DecayCurve => Some(&mut self.decay_curve),
+            Param::ReleaseCurve => Some(&mut self.release_curve),
+        }
+    }
+
+    /// Each time stage and the sustain stage get an equal slot of the width
+    /// of the widget. A time stage fills its slot at its maximum value.
+    fn slot_width(&self, bounds: &Rectangle) -> f32 {
+        let mut slots = 4.0;
+        if self.delay.is_some() {
+            slots += 1.0;
+        }
+        if self.hold.is_some() {
+            slots += 1.0;
+        }
+
+        bounds.width / slots
+    }
+
+    fn shape(&self, bounds: &Rectangle) -> Shape {
+        let slot_width = self.slot_width(bounds);
+
+        let top = bounds.y;
+        let bottom = bounds.y + bounds.height;
+
+        let start = Point::new(bounds.x, bottom);
+
+        let delay = self.delay.map(|delay| {
+            Point::new(start.x + delay.value.scale(slot_width), bottom)
+        });
+
+        let attack = Point::new(
+            delay.unwrap_or(start).x + self.attack.value.scale(slot_width),
+            top,
+        );
+
+        let hold = self.hold.map(|hold| {
+            Point::new(attack.x + hold.value.scale(slot_width), top)
+        });
+
+        let sustain_y = bounds.y + self.sustain.value.scale_inv(bounds.height);
+
+        let decay = Point::new(
+            hold.unwrap_or(attack).x + self.decay.value.scale(slot_width),
+            sustain_y,
+        );
+
+        let sustain = Point::new(decay.x + slot_width, sustain_y);
+
+        let release = Point::new(
+            sustain.x + self.release.value.scale(slot_width),
+            bottom,
+        );
+
+        Shape {
+            start,
+            delay,
+            attack,
+            hold,
+            decay,
+            sustain,
+            release,
+            attack_curve: self.attack_curve.value,
+            decay_curve: self.decay_curve.value,
+            release_curve: self.release_curve.value,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for ADSREnvelope<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse_event) => match mouse_event {
+                mouse::Event::CursorMoved { .. } => {
+                    if bounds.width <= 0.0 || bounds.height <= 0.0 {
+                        return event::Status::Ignored;
+                    }
+
+                    if let Some(target) = self.state.drag_target {
+                        let mut movement_x = (cursor_position.x
+                            - self.state.prev_drag_x)
+                            / self.state.slot_width(&bounds);
+                        let mut movement_y =
+                            cursor_position.y - self.state.prev_drag_y;
+
+                        if self
+                            .state
+                            .pressed_modifiers
+                            .matches(self.modifier_keys)
+                        {
+                            movement_x *= self.modifier_scalar;
+                            movement_y *= self.modifier_scalar;
+                        }
+
+                        self.state.prev_drag_x = cursor_position.x;
+                        self.state.prev_drag_y = cursor_position.y;
+
+                        match target {
+                            Target::Handle(param) => {
+                                let x = (self.state.continuous_x + movement_x)
+                                    .clamp(0.0, 1.0);
+                                self.state.continuous_x = x;
+                                self.change(messages, param, x.into());
+
+                                if param == Param::Decay {
+                                    let y = (self.state.continuous_y
+                                        - (movement_y / bounds.height))
+                                        .clamp(0.0, 1.0);
+                                    self.state.continuous_y = y;
+                                    self.change(
+                                        messages,
+                                        Param::Sustain,
+                                        y.into(),
+                                    );
+                                }
+                            }
+                            Target::Segment(Param::Sustain) => {
+                                let y = (self.state.continuous_y
+                                    - (movement_y / bounds.height))
+                                    .clamp(0.0, 1.0);
+                                self.state.continuous_y = y;
+                                self.change(messages, Param::Sustain, y.into());
+                            }
+                            Target::Segment(param) => {
+                                let curve = (self.state.continuous_y
+                                    - (movement_y * self.curve_scalar))
+                                    .clamp(0.0, 1.0);
+                                self.state.continuous_y = curve;
+                                self.change(messages, param, curve.into());
+                            }
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
+                mouse::Event::ButtonPressed(mouse::Button::Left)
+                    if bounds.contains(cursor_position) =>
+                {
+                    let click = mouse::Click::new(
+                        cursor_position,
+                        self.state.last_click,
+                    );
+
+                    let target = self
+                        .target_at(&self.state.shape(&bounds), cursor_position);
+
+                    match click.kind() {
+                        mouse::click::Kind::Single => {
+                            self.state.drag_target = target;
+                            self.state.prev_drag_x = cursor_position.x;
+                            self.state.prev_drag_y = cursor_position.y;
+
+                            match target {
+                                Some(Target::Handle(param)) => {
+                                    self.state.continuous_x = self
+                                        .state
+                                        .normal(param)
+                                        .map(|n| n.as_f32())
+                                        .unwrap_or(0.0);
+                                    self.state.continuous_y =
+                                        self.state.sustain.value.as_f32();
+                                }
+                                Some(Target::Segment(param)) => {
+                                    self.state.continuous_y = self
+                                        .state
+                                        .normal(param)
+                                        .map(|n| n.as_f32())
+                                        .unwrap_or(0.0);
+                                }
+                                None => {}
+                            }
+                        }
+                        _ => {
+                            self.state.drag_target = None;
+
+                            let param = match target {
+                                Some(Target::Handle(param)) => Some(param),
+                                Some(Target::Segment(param)) => Some(param),
+                                None => None,
+                            };
+
+                            if let Some(param) = param {
+                                if let Some(default) = self.state.default(param)
+                                {
+                                    self.change(messages, param, default);
+                                }
+                            }
+                        }
+                    }
+
+                    self.state.last_click = Some(click);
+
+                    return event::Status::Captured;
+                }
+                mouse::Event::ButtonReleased(mouse::Button::Left)
+                    if self.state.drag_target.is_some() =>
+                {
+                    self.state.drag_target = None;
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            },
+            Event::Keyboard(keyboard_event) => match keyboard_event {
+                keyboard::Event::KeyPressed { modifiers, .. } => {
+                    self.state.pressed_modifiers = modifiers;
+
+                    return event::Status::Captured;
+                }
+                keyboard::Event::KeyReleased { modifiers, .. } => {
+                    self.state.pressed_modifiers = modifiers;
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let shape = self.state.shape(&bounds);
+
+        let active_target = if self.state.drag_target.is_some() {
+            self.state.drag_target
+        } else if bounds.contains(cursor_position) {
+            self.target_at(&shape, cursor_position)
+        } else {
+            None
+        };
+
+        let label = if let Some(formatter) = &self.formatter {
+            active_target.and_then(|target| {
+                let param = match target {
+                    Target::Handle(param) => param,
+                    Target::Segment(param) => param,
+                };
+
+                self.state
+                    .normal(param)
+                    .map(|normal| (formatter)(param, normal))
+            })
+        } else {
+            None
+        };
+
+        renderer.draw(
+            bounds,
+            cursor_position,
+            &shape,
+            active_target,
+            self.state.drag_target.is_some(),
+            label,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of an [`ADSREnvelope`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use an [`ADSREnvelope`] in your user interface.
+///
+/// [`ADSREnvelope`]: struct.ADSREnvelope.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws an [`ADSREnvelope`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`ADSREnvelope`]
+    ///   * the current cursor position
+    ///   * the shape of the envelope in screen coordinates
+    ///   * the part of the envelope that is hovered or being dragged, if any
+    ///   * whether the envelope is currently being dragged
+    ///   * the formatted value of the hovered or dragged part, if any
+    ///   * the style of the [`ADSREnvelope`]
+    ///
+    /// [`ADSREnvelope`]: struct.ADSREnvelope.html
+    #[allow(clippy::too_many_arguments)]
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        shape: &Shape,
+        active_target: Option<Target>,
+        is_dragging: bool,
+        label: Option<String>,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<ADSREnvelope<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        adsr_envelope: ADSREnvelope<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(adsr_envelope)
+    }
+}
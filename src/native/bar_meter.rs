@@ -0,0 +1,499 @@
+//! Display a bar meter that displays the level of one or two (stereo)
+//! audio channels, with an optional peak-hold line and a clip indicator.
+
+use std::fmt::Debug;
+use std::time::{Duration, Instant};
+
+use iced_native::{
+    event, layout, mouse, Clipboard, Element, Event, Hasher, Layout, Length,
+    Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+use crate::core::Normal;
+use crate::native::{text_marks, tick_marks};
+
+static DEFAULT_WIDTH: u16 = 14;
+static DEFAULT_PEAK_HOLD_MS: u64 = 1500;
+
+/// The orientation of a [`BarMeter`] widget.
+///
+/// [`BarMeter`]: struct.BarMeter.html
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum Orientation {
+    /// The bar fills from the bottom to the top.
+    #[default]
+    Vertical,
+    /// The bar fills from the left to the right.
+    Horizontal,
+}
+
+/// The level of a single channel in a [`BarMeter`], as it is passed to
+/// the renderer.
+///
+/// [`BarMeter`]: struct.BarMeter.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ChannelLevel {
+    /// The current level of the channel
+    pub level: Normal,
+    /// The held peak level of the channel. This is `None` if peak-hold
+    /// is disabled.
+    pub peak: Option<Normal>,
+}
+
+/// A bar meter GUI widget that displays the level of one or two (stereo)
+/// channels.
+///
+/// A vertical [`BarMeter`] will try to fill the vertical space of its
+/// container, and a horizontal [`BarMeter`] will try to fill the horizontal
+/// space of its container.
+///
+/// [`BarMeter`]: struct.BarMeter.html
+#[allow(missing_debug_implementations)]
+pub struct BarMeter<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    on_clip_reset: Option<Box<dyn Fn() -> Message>>,
+    orientation: Orientation,
+    rail_inset: f32,
+    width: Option<Length>,
+    height: Option<Length>,
+    style: Renderer::Style,
+    tick_marks: Option<&'a tick_marks::Group>,
+    text_marks: Option<&'a text_marks::Group>,
+}
+
+impl<'a, Message, Renderer: self::Renderer> BarMeter<'a, Message, Renderer> {
+    /// Creates a new vertical [`BarMeter`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`BarMeter`]
+    ///
+    /// [`State`]: struct.State.html
+    /// [`BarMeter`]: struct.BarMeter.html
+    pub fn new(state: &'a mut State) -> Self {
+        BarMeter {
+            state,
+            on_clip_reset: None,
+            orientation: Orientation::Vertical,
+            rail_inset: 0.0,
+            width: None,
+            height: None,
+            style: Renderer::Style::default(),
+            tick_marks: None,
+            text_marks: None,
+        }
+    }
+
+    /// Sets the [`Orientation`] of the [`BarMeter`]. This also swaps the
+    /// default width and height, but not a width or height that was set
+    /// explicitly.
+    ///
+    /// The default orientation is `Orientation::Vertical`.
+    ///
+    /// [`Orientation`]: enum.Orientation.html
+    /// [`BarMeter`]: struct.BarMeter.html
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets how far the scale of the [`BarMeter`] is inset from each end of
+    /// the meter, in pixels. The level, the peak line, and the tick and
+    /// text marks all use the inset scale.
+    ///
+    /// A [`VSlider`] or [`HSlider`] insets its rail by half the length of
+    /// its handle, so set this to half of the slider's handle length to
+    /// line the meter up with a slider of the same length that uses the
+    /// same range. The clip marker sits inside the inset when it fits.
+    ///
+    /// The default inset is `0.0`.
+    ///
+    /// [`BarMeter`]: struct.BarMeter.html
+    /// [`VSlider`]: ../v_slider/struct.VSlider.html
+    /// [`HSlider`]: ../h_slider/struct.HSlider.html
+    pub fn rail_inset(mut self, rail_inset: f32) -> Self {
+        self.rail_inset = rail_inset;
+        self
+    }
+
+    /// Sets the width of the [`BarMeter`].
+    ///
+    /// The default width of a vertical meter is
+    /// `Length::from(Length::Units(14))`, and the default width of a
+    /// horizontal meter is `Length::Fill`.
+    ///
+    /// [`BarMeter`]: struct.BarMeter.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Sets the height of the [`BarMeter`].
+    ///
+    /// The default height of a vertical meter is `Length::Fill`, and the
+    /// default height of a horizontal meter is
+    /// `Length::from(Length::Units(14))`.
+    ///
+    /// [`BarMeter`]: struct.BarMeter.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Sets the style of the [`BarMeter`].
+    ///
+    /// [`BarMeter`]: struct.BarMeter.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets a function that will be called when the user clicks the
+    /// [`BarMeter`] to reset the clip indicator.
+    ///
+    /// [`BarMeter`]: struct.BarMeter.html
+    pub fn on_clip_reset<F>(mut self, on_clip_reset: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_clip_reset = Some(Box::new(on_clip_reset));
+        self
+    }
+
+    /// Sets the tick marks to display. Note your [`StyleSheet`] must
+    /// also implement `tick_marks_style(&self) -> Option<tick_marks::Style>` for
+    /// them to display (which the default style does).
+    ///
+    /// [`StyleSheet`]: ../../style/bar_meter/trait.StyleSheet.html
+    pub fn tick_marks(mut self, tick_marks: &'a tick_marks::Group) -> Self {
+        self.tick_marks = Some(tick_marks);
+        self
+    }
+
+    /// Sets the text marks to display. Note your [`StyleSheet`] must
+    /// also implement `text_marks_style(&self) -> Option<text_marks::Style>` for
+    /// them to display (which the default style does).
+    ///
+    /// [`StyleSheet`]: ../../style/bar_meter/trait.StyleSheet.html
+    pub fn text_marks(mut self, text_marks: &'a text_marks::Group) -> Self {
+        self.text_marks = Some(text_marks);
+        self
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct ChannelState {
+    level: Normal,
+    peak: Normal,
+    peak_instant: Instant,
+}
+
+impl ChannelState {
+    fn new() -> Self {
+        Self {
+            level: Normal::min(),
+            peak: Normal::min(),
+            peak_instant: Instant::now(),
+        }
+    }
+
+    fn set_level(&mut self, level: Normal, peak_hold: Option<Duration>) {
+        self.level = level;
+
+        if let Some(peak_hold) = peak_hold {
+            let now = Instant::now();
+
+            if level >= self.peak
+                || now.duration_since(self.peak_instant) >= peak_hold
+            {
+                self.peak = level;
+                self.peak_instant = now;
+            }
+        } else {
+            self.peak = level;
+        }
+    }
+
+    fn channel_level(&self, peak_hold: Option<Duration>) -> ChannelLevel {
+        ChannelLevel {
+            level: self.level,
+            peak: peak_hold.map(|_| self.peak),
+        }
+    }
+}
+
+/// The local state of a [`BarMeter`].
+///
+/// The levels are usually mapped from a [`LogDBRange`] so that the scale of
+/// the meter lines up with the scale of a [`VSlider`] that uses the same
+/// range. See [`BarMeter::rail_inset`] to line up the ends of the scales.
+///
+/// [`BarMeter`]: struct.BarMeter.html
+/// [`LogDBRange`]: ../../core/range/struct.LogDBRange.html
+/// [`VSlider`]: ../v_slider/struct.VSlider.html
+/// [`BarMeter::rail_inset`]: struct.BarMeter.html#method.rail_inset
+#[derive(Debug, Clone)]
+pub struct State {
+    left: ChannelState,
+    right: Option<ChannelState>,
+    peak_hold: Option<Duration>,
+    clip_threshold: Normal,
+    clipped: bool,
+    tick_marks_cache: crate::graphics::tick_marks::PrimitiveCache,
+    text_marks_cache: crate::graphics::text_marks::PrimitiveCache,
+}
+
+impl State {
+    /// Creates a new mono [`BarMeter`] state.
+    ///
+    /// [`BarMeter`]: struct.BarMeter.html
+    pub fn new() -> Self {
+        Self {
+            left: ChannelState::new(),
+            right: None,
+            peak_hold: Some(Duration::from_millis(DEFAULT_PEAK_HOLD_MS)),
+            clip_threshold: Normal::max(),
+            clipped: false,
+            tick_marks_cache: Default::default(),
+            text_marks_cache: Default::default(),
+        }
+    }
+
+    /// Creates a new stereo [`BarMeter`] state.
+    ///
+    /// [`BarMeter`]: struct.BarMeter.html
+    pub fn new_stereo() -> Self {
+        Self {
+            right: Some(ChannelState::new()),
+            ..Self::new()
+        }
+    }
+
+    /// Sets how long the peak level is held before it falls back down to
+    /// the current level. Set this to `None` to disable peak-hold.
+    ///
+    /// The default is `1500` milliseconds.
+    pub fn set_peak_hold(&mut self, peak_hold: Option<Duration>) {
+        self.peak_hold = peak_hold;
+    }
+
+    /// Sets the level at (and above) which the clip indicator will be
+    /// latched.
+    ///
+    /// For example, use `db_range.map_to_normal(0.0)` to latch the clip
+    /// indicator at 0 dB.
+    ///
+    /// The default is `Normal::max()`.
+    pub fn set_clip_threshold(&mut self, clip_threshold: Normal) {
+        self.clip_threshold = clip_threshold;
+    }
+
+    /// Sets the level of the meter. If the meter is stereo, then both
+    /// channels will be set to this level.
+    pub fn set_level(&mut self, level: Normal) {
+        self.set_levels(level, level);
+    }
+
+    /// Sets the levels of the left and right channels of the meter. If the
+    /// meter is mono, then `right` is ignored.
+    pub fn set_levels(&mut self, left: Normal, right: Normal) {
+        self.left.set_level(left, self.peak_hold);
+
+        if left >= self.clip_threshold {
+            self.clipped = true;
+        }
+
+        if let Some(right_channel) = &mut self.right {
+            right_channel.set_level(right, self.peak_hold);
+
+            if right >= self.clip_threshold {
+                self.clipped = true;
+            }
+        }
+    }
+
+    /// Returns the current level of the left (or mono) channel.
+    pub fn level(&self) -> Normal {
+        self.left.level
+    }
+
+    /// Returns the current level of the right channel, or `None` if the
+    /// meter is mono.
+    pub fn right_level(&self) -> Option<Normal> {
+        self.right.map(|right| right.level)
+    }
+
+    /// Returns whether the clip indicator is currently latched.
+    pub fn is_clipped(&self) -> bool {
+        self.clipped
+    }
+
+    /// Resets the clip indicator.
+    pub fn reset_clip(&mut self) {
+        self.clipped = false;
+    }
+
+    /// Returns whether this is a stereo meter.
+    pub fn is_stereo(&self) -> bool {
+        self.right.is_some()
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for BarMeter<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width.unwrap_or(match self.orientation {
+            Orientation::Vertical => Length::Units(DEFAULT_WIDTH),
+            Orientation::Horizontal => Length::Fill,
+        })
+    }
+
+    fn height(&self) -> Length {
+        self.height.unwrap_or(match self.orientation {
+            Orientation::Vertical => Length::Fill,
+            Orientation::Horizontal => Length::Units(DEFAULT_WIDTH),
+        })
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .width(Widget::<Message, Renderer>::width(self))
+            .height(Widget::<Message, Renderer>::height(self));
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) =
+            event
+        {
+            if layout.bounds().contains(cursor_position) {
+                if self.state.clipped {
+                    self.state.clipped = false;
+
+                    if let Some(on_clip_reset) = &self.on_clip_reset {
+                        messages.push((on_clip_reset)());
+                    }
+                }
+
+                return event::Status::Captured;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        renderer.draw(
+            layout.bounds(),
+            cursor_position,
+            self.orientation,
+            self.rail_inset,
+            self.state.left.channel_level(self.state.peak_hold),
+            self.state
+                .right
+                .map(|right| right.channel_level(self.state.peak_hold)),
+            self.state.clipped,
+            self.tick_marks,
+            self.text_marks,
+            &self.style,
+            &self.state.tick_marks_cache,
+            &self.state.text_marks_cache,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        Widget::<Message, Renderer>::width(self).hash(state);
+        Widget::<Message, Renderer>::height(self).hash(state);
+    }
+}
+
+/// The renderer of a [`BarMeter`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`BarMeter`] in your user interface.
+///
+/// [`BarMeter`]: struct.BarMeter.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`BarMeter`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`BarMeter`]
+    ///   * the current cursor position
+    ///   * the orientation of the [`BarMeter`]
+    ///   * how far the scale is inset from each end of the [`BarMeter`]
+    ///   * the level of the left (or mono) channel
+    ///   * the level of the right channel if the meter is stereo
+    ///   * whether the clip indicator is latched
+    ///   * any tick marks to display
+    ///   * any text marks to display
+    ///   * the style of the [`BarMeter`]
+    ///
+    /// [`BarMeter`]: struct.BarMeter.html
+    #[allow(clippy::too_many_arguments)]
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        orientation: Orientation,
+        rail_inset: f32,
+        left: ChannelLevel,
+        right: Option<ChannelLevel>,
+        clipped: bool,
+        tick_marks: Option<&tick_marks::Group>,
+        text_marks: Option<&text_marks::Group>,
+        style: &Self::Style,
+        tick_marks_cache: &crate::tick_marks::PrimitiveCache,
+        text_marks_cache: &crate::text_marks::PrimitiveCache,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<BarMeter<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        bar_meter: BarMeter<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(bar_meter)
+    }
+}
@@ -0,0 +1,659 @@
+//! Display an envelope editor of adjustable points connected by straight or
+//! curved lines. It is usually used to represent automation, envelopes, and
+//! LFO shapes.
+
+use std::fmt::Debug;
+
+use iced_native::{
+    event, keyboard, layout, mouse, Clipboard, Element, Event, Hasher, Layout,
+    Length, Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+use crate::core::Normal;
+
+static DEFAULT_WIDTH: u16 = 300;
+static DEFAULT_HEIGHT: u16 = 120;
+static DEFAULT_CURVE_SCALAR: f32 = 0.005;
+static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+static DEFAULT_HIT_RADIUS: f32 = 6.0;
+
+/// A point in an [`EnvelopeEditor`].
+///
+/// [`EnvelopeEditor`]: struct.EnvelopeEditor.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct EnvelopePoint {
+    /// The position of the point on the `x` axis (time)
+    pub x: Normal,
+    /// The position of the point on the `y` axis (value)
+    pub y: Normal,
+    /// The curve of the segment from this point to the next point. A value
+    /// of `0.5` represents a straight line, `0.0` is curved downward all
+    /// the way, and `1.0` is curved upward all the way (the same as a
+    /// [`Ramp`]).
+    ///
+    /// This has no effect on the last point.
+    ///
+    /// [`Ramp`]: ../ramp/struct.Ramp.html
+    pub curve: Normal,
+}
+
+impl EnvelopePoint {
+    /// Creates a new [`EnvelopePoint`] with a straight segment to the next
+    /// point.
+    ///
+    /// [`EnvelopePoint`]: struct.EnvelopePoint.html
+    pub fn new(x: Normal, y: Normal) -> Self {
+        Self {
+            x,
+            y,
+            curve: Normal::center(),
+        }
+    }
+}
+
+/// An edit made by the user in an [`EnvelopeEditor`].
+///
+/// [`EnvelopeEditor`]: struct.EnvelopeEditor.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Edit {
+    /// A point was added by double-clicking.
+    PointAdded {
+        /// The index the point was inserted at
+        index: usize,
+        /// The new point
+        point: EnvelopePoint,
+    },
+    /// A point was removed by right-clicking.
+    PointRemoved {
+        /// The index of the removed point
+        index: usize,
+    },
+    /// A point was dragged.
+    PointMoved {
+        /// The index of the point
+        index: usize,
+        /// The new position of the point on the `x` axis
+        x: Normal,
+        /// The new position of the point on the `y` axis
+        y: Normal,
+    },
+    /// A segment was dragged up or down to change its curve.
+    CurveChanged {
+        /// The index of the point the segment starts at
+        index: usize,
+        /// The new curve of the segment
+        curve: Normal,
+    },
+}
+
+/// The part of an [`EnvelopeEditor`] that is being dragged.
+///
+/// [`EnvelopeEditor`]: struct.EnvelopeEditor.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DragTarget {
+    /// The point with the given index
+    Point(usize),
+    /// The segment starting at the point with the given index
+    Segment(usize),
+}
+
+/// An envelope editor GUI widget of adjustable points connected by straight
+/// or curved lines. It is usually used to represent automation, envelopes,
+/// and LFO shapes.
+///
+/// * Double-click to add a point.
+/// * Right-click on a point to remove it.
+/// * Drag a point to move it. A point cannot be moved past its neighbours.
+/// * Drag a segment up or down to change its curve.
+///
+/// [`EnvelopeEditor`]: struct.EnvelopeEditor.html
+#[allow(missing_debug_implementations)]
+pub struct EnvelopeEditor<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    on_edit: Box<dyn Fn(Edit) -> Message>,
+    curve_scalar: f32,
+    modifier_scalar: f32,
+    modifier_keys: keyboard::Modifiers,
+    hit_radius: f32,
+    width: Length,
+    height: Length,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer>
+    EnvelopeEditor<'a, Message, Renderer>
+{
+    /// Creates a new [`EnvelopeEditor`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`EnvelopeEditor`]
+    ///   * a function that will be called with each [`Edit`] made by the
+    ///     user
+    ///
+    /// [`State`]: struct.State.html
+    /// [`Edit`]: enum.Edit.html
+    /// [`EnvelopeEditor`]: struct.EnvelopeEditor.html
+    pub fn new<F>(state: &'a mut State, on_edit: F) -> Self
+    where
+        F: 'static + Fn(Edit) -> Message,
+    {
+        EnvelopeEditor {
+            state,
+            on_edit: Box::new(on_edit),
+            curve_scalar: DEFAULT_CURVE_SCALAR,
+            modifier_scalar: DEFAULT_MODIFIER_SCALAR,
+            modifier_keys: keyboard::Modifiers {
+                control: true,
+                ..Default::default()
+            },
+            hit_radius: DEFAULT_HIT_RADIUS,
+            width: Length::Units(DEFAULT_WIDTH),
+            height: Length::Units(DEFAULT_HEIGHT),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`EnvelopeEditor`].
+    /// The default width is `Length::from(Length::Units(300))`.
+    ///
+    /// [`EnvelopeEditor`]: struct.EnvelopeEditor.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`EnvelopeEditor`].
+    /// The default height is `Length::from(Length::Units(120))`.
+    ///
+    /// [`EnvelopeEditor`]: struct.EnvelopeEditor.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`EnvelopeEditor`].
+    ///
+    /// [`EnvelopeEditor`]: struct.EnvelopeEditor.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets how much the curve of a segment will change per `y` pixel
+    /// movement of the mouse.
+    ///
+    /// The default value is `0.005`
+    pub fn curve_scalar(mut self, scalar: f32) -> Self {
+        self.curve_scalar = scalar;
+        self
+    }
+
+    /// Sets the modifier keys of the [`EnvelopeEditor`].
+    ///
+    /// The default modifier key is `Ctrl`.
+    ///
+    /// [`EnvelopeEditor`]: struct.EnvelopeEditor.html
+    pub fn modifier_keys(mut self, modifier_keys: keyboard::Modifiers) -> Self {
+        self.modifier_keys = modifier_keys;
+        self
+    }
+
+    /// Sets the scalar to use when the user drags a point or a segment
+    /// while holding down the modifier key.
+    ///
+    /// For example, a `modifier_scalar` of `0.5` will cause points to move
+    /// half as fast when the modifier key is down.
+    ///
+    /// The default `modifier_scalar` is `0.02`, and the default modifier key
+    /// is `Ctrl`.
+    pub fn modifier_scalar(mut self, scalar: f32) -> Self {
+        self.modifier_scalar = scalar;
+        self
+    }
+
+    /// Sets the distance in pixels from the center of a point within which
+    /// the point can be grabbed or removed.
+    ///
+    /// The default value is `6.0`
+    pub fn hit_radius(mut self, hit_radius: f32) -> Self {
+        self.hit_radius = hit_radius;
+        self
+    }
+
+    fn point_at(&self, bounds: &Rectangle, cursor: Point) -> Option<usize> {
+        let hit_radius_squared = self.hit_radius * self.hit_radius;
+
+        self.state
+            .points
+            .iter()
+            .enumerate()
+            .map(|(i, point)| {
+                let dx = bounds.x + point.x.scale(bounds.width) - cursor.x;
+                let dy = bounds.y + point.y.scale_inv(bounds.height) - cursor.y;
+
+                (i, (dx * dx) + (dy * dy))
+            })
+            .filter(|(_, distance)| *distance <= hit_radius_squared)
+            .min_by(|(_, a), (_, b)| {
+                a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|(i, _)| i)
+    }
+
+    fn segment_at(&self, bounds: &Rectangle, cursor: Point) -> Option<usize> {
+        if bounds.width <= 0.0 {
+            return None;
+        }
+
+        let x = (cursor.x - bounds.x) / bounds.width;
+
+        self.state
+            .points
+            .windows(2)
+            .position(|pair| x >= pair[0].x.as_f32() && x < pair[1].x.as_f32())
+    }
+}
+
+/// The local state of an [`EnvelopeEditor`].
+///
+/// [`EnvelopeEditor`]: struct.EnvelopeEditor.html
+#[derive(Debug, Clone)]
+pub struct State {
+    points: Vec<EnvelopePoint>,
+    min_points: usize,
+    drag_target: Option<DragTarget>,
+    prev_drag_x: f32,
+    prev_drag_y: f32,
+    continuous_x: f32,
+    continuous_y: f32,
+    pressed_modifiers: keyboard::Modifiers,
+    last_click: Option<mouse::Click>,
+}
+
+impl State {
+    /// Creates a new [`EnvelopeEditor`] state.
+    ///
+    /// It expects:
+    /// * the points of the envelope, ordered by their `x` position
+    ///
+    /// [`EnvelopeEditor`]: struct.EnvelopeEditor.html
+    pub fn new(points: Vec<EnvelopePoint>) -> Self {
+        let mut state = Self {
+            points: Vec::new(),
+            min_points: 2,
+            drag_target: None,
+            prev_drag_x: 0.0,
+            prev_drag_y: 0.0,
+            continuous_x: 0.0,
+            continuous_y: 0.0,
+            pressed_modifiers: Default::default(),
+            last_click: None,
+        };
+
+        state.set_points(points);
+
+        state
+    }
+
+    /// Replaces all the points of the envelope. The points are sorted by
+    /// their `x` position.
+    pub fn set_points(&mut self, mut points: Vec<EnvelopePoint>) {
+        points.sort_by(|a, b| {
+            a.x.as_f32()
+                .partial_cmp(&b.x.as_f32())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        self.points = points;
+        self.drag_target = None;
+    }
+
+    /// Returns the points of the envelope, ordered by their `x` position.
+    pub fn points(&self) -> &[EnvelopePoint] {
+        &self.points
+    }
+
+    /// Sets the minimum number of points in the envelope. Points cannot be
+    /// removed by the user past this number.
+    ///
+    /// The default is `2`.
+    pub fn set_min_points(&mut self, min_points: usize) {
+        self.min_points = min_points;
+    }
+
+    /// Returns the part of the [`EnvelopeEditor`] that is currently being
+    /// dragged, if any.
+    ///
+    /// [`EnvelopeEditor`]: struct.EnvelopeEditor.html
+    pub fn drag_target(&self) -> Option<DragTarget> {
+        self.drag_target
+    }
+
+    fn insert_point(&mut self, point: EnvelopePoint) -> usize {
+        let index = self
+            .points
+            .iter()
+            .position(|p| p.x.as_f32() > point.x.as_f32())
+            .unwrap_or(self.points.len());
+
+        self.points.insert(index, point);
+
+        index
+    }
+
+    fn x_limits(&self, index: usize) -> (f32, f32) {
+        let min = if index > 0 {
+            self.points[index - 1].x.as_f32()
+        } else {
+            0.0
+        };
+        let max = if let Some(next) = self.points.get(index + 1) {
+            next.x.as_f32()
+        } else {
+            1.0
+        };
+
+        (min, max)
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for EnvelopeEditor<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse_event) => match mouse_event {
+                mouse::Event::CursorMoved { .. } => {
+                    if bounds.width <= 0.0 || bounds.height <= 0.0 {
+                        return event::Status::Ignored;
+                    }
+
+                    let is_fine = self
+                        .state
+                        .pressed_modifiers
+                        .matches(self.modifier_keys);
+
+                    match self.state.drag_target {
+                        Some(DragTarget::Point(index)) => {
+                            let mut movement_x = (cursor_position.x
+                                - self.state.prev_drag_x)
+                                / bounds.width;
+                            let mut movement_y = (cursor_position.y
+                                - self.state.prev_drag_y)
+                                / bounds.height;
+
+                            if is_fine {
+                                movement_x *= self.modifier_scalar;
+                                movement_y *= self.modifier_scalar;
+                            }
+
+                            self.state.prev_drag_x = cursor_position.x;
+                            self.state.prev_drag_y = cursor_position.y;
+
+                            let (min_x, max_x) = self.state.x_limits(index);
+
+                            let x = (self.state.continuous_x + movement_x)
+                                .max(min_x)
+                                .min(max_x);
+                            let y = (self.state.continuous_y - movement_y)
+                                .clamp(0.0, 1.0);
+
+                            self.state.continuous_x = x;
+                            self.state.continuous_y = y;
+
+                            let point = &mut self.state.points[index];
+                            point.x = x.into();
+                            point.y = y.into();
+
+                            messages.push((self.on_edit)(Edit::PointMoved {
+                                index,
+                                x: point.x,
+                                y: point.y,
+                            }));
+
+                            return event::Status::Captured;
+                        }
+                        Some(DragTarget::Segment(index)) => {
+                            let mut normal_delta = (cursor_position.y
+                                - self.state.prev_drag_y)
+                                * self.curve_scalar;
+
+                            if is_fine {
+                                normal_delta *= self.modifier_scalar;
+                            }
+
+                            self.state.prev_drag_y = cursor_position.y;
+
+                            let curve = (self.state.continuous_y
+                                - normal_delta)
+                                .clamp(0.0, 1.0);
+
+                            self.state.continuous_y = curve;
+                            self.state.points[index].curve = curve.into();
+
+                            messages.push((self.on_edit)(Edit::CurveChanged {
+                                index,
+                                curve: curve.into(),
+                            }));
+
+                            return event::Status::Captured;
+                        }
+                        None => {}
+                    }
+                }
+                mouse::Event::ButtonPressed(mouse::Button::Left)
+                    if bounds.contains(cursor_position) =>
+                {
+                    let click = mouse::Click::new(
+                        cursor_position,
+                        self.state.last_click,
+                    );
+
+                    self.state.prev_drag_x = cursor_position.x;
+                    self.state.prev_drag_y = cursor_position.y;
+
+                    match click.kind() {
+                        mouse::click::Kind::Single => {
+                            if let Some(index) =
+                                self.point_at(&bounds, cursor_position)
+                            {
+                                let point = self.state.points[index];
+
+                                self.state.drag_target =
+                                    Some(DragTarget::Point(index));
+                                self.state.continuous_x = point.x.as_f32();
+                                self.state.continuous_y = point.y.as_f32();
+                            } else if let Some(index) =
+                                self.segment_at(&bounds, cursor_position)
+                            {
+                                self.state.drag_target =
+                                    Some(DragTarget::Segment(index));
+                                self.state.continuous_y =
+                                    self.state.points[index].curve.as_f32();
+                            }
+                        }
+                        _ => {
+                            self.state.drag_target = None;
+
+                            if self.point_at(&bounds, cursor_position).is_none()
+                            {
+                                let point = EnvelopePoint::new(
+                                    ((cursor_position.x - bounds.x)
+                                        / bounds.width)
+                                        .into(),
+                                    (1.0 - ((cursor_position.y - bounds.y)
+                                        / bounds.height))
+                                        .into(),
+                                );
+
+                                let index = self.state.insert_point(point);
+
+                                messages.push((self.on_edit)(
+                                    Edit::PointAdded { index, point },
+                                ));
+                            }
+                        }
+                    }
+
+                    self.state.last_click = Some(click);
+
+                    return event::Status::Captured;
+                }
+                mouse::Event::ButtonPressed(mouse::Button::Right)
+                    if bounds.contains(cursor_position)
+                        && self.state.drag_target.is_none() =>
+                {
+                    if let Some(index) = self.point_at(&bounds, cursor_position)
+                    {
+                        if self.state.points.len() > self.state.min_points {
+                            let _ = self.state.points.remove(index);
+
+                            messages.push((self.on_edit)(Edit::PointRemoved {
+                                index,
+                            }));
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
+                mouse::Event::ButtonReleased(mouse::Button::Left)
+                    if self.state.drag_target.is_some() =>
+                {
+                    self.state.drag_target = None;
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            },
+            Event::Keyboard(keyboard_event) => match keyboard_event {
+                keyboard::Event::KeyPressed { modifiers, .. } => {
+                    self.state.pressed_modifiers = modifiers;
+
+                    return event::Status::Captured;
+                }
+                keyboard::Event::KeyReleased { modifiers, .. } => {
+                    self.state.pressed_modifiers = modifiers;
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+
+        let hovered_point = if self.state.drag_target.is_none() {
+            self.point_at(&bounds, cursor_position)
+        } else {
+            None
+        };
+
+        renderer.draw(
+            bounds,
+            cursor_position,
+            &self.state.points,
+            hovered_point,
+            self.state.drag_target,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of an [`EnvelopeEditor`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use an [`EnvelopeEditor`] in your user interface.
+///
+/// [`EnvelopeEditor`]: struct.EnvelopeEditor.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws an [`EnvelopeEditor`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`EnvelopeEditor`]
+    ///   * the current cursor position
+    ///   * the points of the envelope
+    ///   * the index of the point under the cursor, if any
+    ///   * the part of the envelope that is being dragged, if any
+    ///   * the style of the [`EnvelopeEditor`]
+    ///
+    /// [`EnvelopeEditor`]: struct.EnvelopeEditor.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        points: &[EnvelopePoint],
+        hovered_point: Option<usize>,
+        drag_target: Option<DragTarget>,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<EnvelopeEditor<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        envelope_editor: EnvelopeEditor<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(envelope_editor)
+    }
+}
@@ -0,0 +1,147 @@
+//! Keyboard focus for parameter widgets.
+//!
+//! A parameter widget gains keyboard focus when it is clicked, and loses it
+//! when the user clicks anywhere outside of it. While a widget is focused:
+//!
+//!   * `Up`/`Right` and `Down`/`Left` step the value up and down
+//!   * `PageUp` and `PageDown` step the value up and down in coarse steps
+//!   * `Home` and `End` jump to the minimum and maximum value
+//!   * `Delete` and `Backspace` reset the value to its default
+//!
+//! The range sliders move one handle at a time while they are focused,
+//! starting with the handle that was clicked last.
+//!
+//! Iced has no focus chain of its own, so cycling focus with `Tab` is up to
+//! your application. Listen for `Tab` (for example with a keyboard
+//! subscription), and pass the states of your widgets in tab order to
+//! [`focus_next`] or [`focus_previous`].
+//!
+//! # Example
+//!
+//! ```
+//! use iced_audio::focus::{self, Focusable};
+//! use iced_audio::{h_slider, knob, v_slider};
+//!
+//! let mut slider_state = h_slider::State::new(Default::default());
+//! let mut knob_state = knob::State::new(Default::default());
+//! let mut fader_state = v_slider::State::new(Default::default());
+//!
+//! // `Tab` was pressed while no widget was focused
+//! focus::focus_next(&mut [
+//!     &mut slider_state,
+//!     &mut knob_state,
+//!     &mut fader_state,
+//! ]);
+//! assert!(slider_state.is_focused());
+//!
+//! // `Shift+Tab` wraps around to the last widget
+//! focus::focus_previous(&mut [
+//!     &mut slider_state,
+//!     &mut knob_state,
+//!     &mut fader_state,
+//! ]);
+//! assert!(fader_state.is_focused());
+//! ```
+//!
+//! [`focus_next`]: fn.focus_next.html
+//! [`focus_previous`]: fn.focus_previous.html
+
+use iced_native::keyboard;
+
+use crate::core::{Normal, NormalParam};
+
+/// The state of a widget that can receive keyboard focus.
+pub trait Focusable {
+    /// Returns `true` if the widget currently has keyboard focus.
+    fn is_focused(&self) -> bool;
+
+    /// Gives keyboard focus to the widget.
+    fn focus(&mut self);
+
+    /// Removes keyboard focus from the widget.
+    fn unfocus(&mut self);
+}
+
+/// Moves keyboard focus to the state after the currently focused one,
+/// wrapping around to the first state.
+///
+/// If no state is focused, the first state receives focus.
+pub fn focus_next(states: &mut [&mut dyn Focusable]) {
+    let len = states.len();
+
+    if len == 0 {
+        return;
+    }
+
+    let next = match unfocus_all(states) {
+        Some(index) => (index + 1) % len,
+        None => 0,
+    };
+
+    states[next].focus();
+}
+
+/// Moves keyboard focus to the state before the currently focused one,
+/// wrapping around to the last state.
+///
+/// If no state is focused, the last state receives focus.
+pub fn focus_previous(states: &mut [&mut dyn Focusable]) {
+    let len = states.len();
+
+    if len == 0 {
+        return;
+    }
+
+    let previous = match unfocus_all(states) {
+        Some(index) => (index + len - 1) % len,
+        None => len - 1,
+    };
+
+    states[previous].focus();
+}
+
+/// Removes keyboard focus from every state, returning the index of the
+/// first state that was focused.
+fn unfocus_all(states: &mut [&mut dyn Focusable]) -> Option<usize> {
+    let mut focused = None;
+
+    for (i, state) in states.iter_mut().enumerate() {
+        if state.is_focused() {
+            if focused.is_none() {
+                focused = Some(i);
+            }
+
+            state.unfocus();
+        }
+    }
+
+    focused
+}
+
+/// Returns the new value of a [`NormalParam`] after a key is pressed on a
+/// focused widget, or `None` if the key does not change the value.
+///
+/// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+pub(crate) fn key_normal(
+    key_code: keyboard::KeyCode,
+    normal_param: &NormalParam,
+    step: f32,
+    page_step: f32,
+) -> Option<Normal> {
+    let value = normal_param.value.as_f32();
+
+    let normal = match key_code {
+        keyboard::KeyCode::Up | keyboard::KeyCode::Right => value + step,
+        keyboard::KeyCode::Down | keyboard::KeyCode::Left => value - step,
+        keyboard::KeyCode::PageUp => value + page_step,
+        keyboard::KeyCode::PageDown => value - page_step,
+        keyboard::KeyCode::Home => 0.0,
+        keyboard::KeyCode::End => 1.0,
+        keyboard::KeyCode::Delete | keyboard::KeyCode::Backspace => {
+            normal_param.default.as_f32()
+        }
+        _ => return None,
+    };
+
+    Some(normal.into())
+}
@@ -0,0 +1,309 @@
+//! Display a goniometer that plots the stereo phase of an audio signal,
+//! along with a correlation meter.
+
+use std::collections::VecDeque;
+use std::fmt::Debug;
+
+use iced_native::{
+    event, layout, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+static DEFAULT_WIDTH: u16 = 160;
+static DEFAULT_HEIGHT: u16 = 176;
+
+/// A goniometer GUI widget that plots left/right sample pairs as a rotated
+/// Lissajous (mid/side) display, with a correlation meter below it.
+///
+/// Mono signals are drawn as a vertical line, out of phase signals as a
+/// horizontal line, and a signal in only the left or right channel as a
+/// diagonal line leaning to that side.
+///
+/// [`Goniometer`]: struct.Goniometer.html
+#[allow(missing_debug_implementations)]
+pub struct Goniometer<'a, Renderer: self::Renderer> {
+    state: &'a mut State,
+    width: Length,
+    height: Length,
+    style: Renderer::Style,
+}
+
+impl<'a, Renderer: self::Renderer> Goniometer<'a, Renderer> {
+    /// Creates a new [`Goniometer`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`Goniometer`]
+    ///
+    /// [`State`]: struct.State.html
+    /// [`Goniometer`]: struct.Goniometer.html
+    pub fn new(state: &'a mut State) -> Self {
+        Goniometer {
+            state,
+            width: Length::Units(DEFAULT_WIDTH),
+            height: Length::Units(DEFAULT_HEIGHT),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`Goniometer`].
+    /// The default width is `Length::from(Length::Units(160))`.
+    ///
+    /// [`Goniometer`]: struct.Goniometer.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Goniometer`].
+    /// The default height is `Length::from(Length::Units(176))`.
+    ///
+    /// [`Goniometer`]: struct.Goniometer.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`Goniometer`].
+    ///
+    /// [`Goniometer`]: struct.Goniometer.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The local state of a [`Goniometer`].
+///
+/// The state holds a ring buffer of the most recent left/right sample
+/// pairs. Push new samples into it with [`push_samples`] every time new
+/// audio is available. Older samples are drawn fainter than newer ones.
+///
+/// [`Goniometer`]: struct.Goniometer.html
+/// [`push_samples`]: struct.State.html#method.push_samples
+#[derive(Debug, Clone)]
+pub struct State {
+    buffer: VecDeque<(f32, f32)>,
+    window_len: usize,
+}
+
+impl State {
+    /// Creates a new [`Goniometer`] state.
+    ///
+    /// It expects:
+    /// * the number of left/right sample pairs to display
+    ///
+    /// [`Goniometer`]: struct.Goniometer.html
+    pub fn new(window_len: usize) -> Self {
+        let window_len = window_len.max(2);
+
+        Self {
+            buffer: VecDeque::with_capacity(window_len),
+            window_len,
+        }
+    }
+
+    /// Sets the number of left/right sample pairs to display.
+    pub fn set_window_len(&mut self, window_len: usize) {
+        self.window_len = window_len.max(2);
+
+        while self.buffer.len() > self.window_len {
+            let _ = self.buffer.pop_front();
+        }
+    }
+
+    /// Returns the number of left/right sample pairs to display.
+    pub fn window_len(&self) -> usize {
+        self.window_len
+    }
+
+    /// Pushes new samples into the ring buffer. The oldest samples are
+    /// discarded.
+    ///
+    /// If the slices have different lengths, the extra samples in the
+    /// longer slice are ignored.
+    pub fn push_samples(&mut self, left: &[f32], right: &[f32]) {
+        let len = left.len().min(right.len());
+        let skip = len.saturating_sub(self.window_len);
+
+        while self.buffer.len() + (len - skip) > self.window_len {
+            let _ = self.buffer.pop_front();
+        }
+
+        self.buffer.extend(
+            left[skip..len]
+                .iter()
+                .copied()
+                .zip(right[skip..len].iter().copied()),
+        );
+    }
+
+    /// Pushes new interleaved stereo samples into the ring buffer. The
+    /// oldest samples are discarded.
+    pub fn push_interleaved(&mut self, samples: &[f32]) {
+        let pairs = samples.len() / 2;
+        let skip = pairs.saturating_sub(self.window_len);
+
+        while self.buffer.len() + (pairs - skip) > self.window_len {
+            let _ = self.buffer.pop_front();
+        }
+
+        self.buffer.extend(
+            samples[skip * 2..pairs * 2]
+                .chunks(2)
+                .map(|pair| (pair[0], pair[1])),
+        );
+    }
+
+    /// Clears all samples in the ring buffer.
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+    }
+
+    /// Returns the correlation between the left and right channels of the
+    /// samples in the ring buffer, from `-1.0` (out of phase) to `1.0`
+    /// (mono). Silence has a correlation of `0.0`.
+    pub fn correlation(&self) -> f32 {
+        let (sum_lr, sum_ll, sum_rr) = self.buffer.iter().fold(
+            (0.0, 0.0, 0.0),
+            |(sum_lr, sum_ll, sum_rr), (l, r)| {
+                (sum_lr + (l * r), sum_ll + (l * l), sum_rr + (r * r))
+            },
+        );
+
+        let denominator = (sum_ll * sum_rr).sqrt();
+
+        if denominator <= f32::EPSILON {
+            0.0
+        } else {
+            (sum_lr / denominator).clamp(-1.0, 1.0)
+        }
+    }
+
+    /// Returns the samples in the ring buffer as mid/side points, ordered
+    /// from oldest to newest.
+    ///
+    /// The `x` coordinate is the side signal (positive to the right) and
+    /// the `y` coordinate is the mid signal (positive upwards), both in the
+    /// range `-1.0..=1.0`.
+    pub fn points(&self) -> Vec<Point> {
+        self.buffer
+            .iter()
+            .map(|(l, r)| {
+                Point::new(
+                    ((r - l) * std::f32::consts::FRAC_1_SQRT_2)
+                        .clamp(-1.0, 1.0),
+                    ((l + r) * std::f32::consts::FRAC_1_SQRT_2)
+                        .clamp(-1.0, 1.0),
+                )
+            })
+            .collect()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Goniometer<'a, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        _event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        _messages: &mut Vec<Message>,
+    ) -> event::Status {
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        renderer.draw(
+            layout.bounds(),
+            cursor_position,
+            &self.state.points(),
+            self.state.correlation(),
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`Goniometer`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`Goniometer`] in your user interface.
+///
+/// [`Goniometer`]: struct.Goniometer.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`Goniometer`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Goniometer`]
+    ///   * the current cursor position
+    ///   * the mid/side points to plot, ordered from oldest to newest
+    ///   * the correlation between the left and right channels
+    ///   * the style of the [`Goniometer`]
+    ///
+    /// [`Goniometer`]: struct.Goniometer.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        points: &[Point],
+        correlation: f32,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Goniometer<'a, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        goniometer: Goniometer<'a, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(goniometer)
+    }
+}
@@ -11,16 +11,22 @@ use iced_native::{
 
 use std::hash::Hash;
 
-use crate::native::{text_marks, tick_marks};
+use crate::native::{
+    focus, scroll, snap_points, steps, text_marks, tick_marks, value_entry,
+};
 use crate::{
-    core::{ModulationRange, Normal, NormalParam},
+    core::{ClickMode, ModulationRange, Normal, NormalParam},
     IntRange,
 };
 
 static DEFAULT_HEIGHT: u16 = 14;
 static DEFAULT_SCALAR: f32 = 0.9575;
 static DEFAULT_WHEEL_SCALAR: f32 = 0.01;
+static DEFAULT_PIXEL_WHEEL_SCALAR: f32 = 0.0005;
 static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+static DEFAULT_KEY_STEP: f32 = 0.01;
+static DEFAULT_PAGE_STEP: f32 = 0.1;
+static DEFAULT_SNAP_WIDTH: f32 = 10.0;
 
 /// A horizontal slider GUI widget that controls a [`NormalParam`]
 ///
@@ -32,10 +38,22 @@ static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
 pub struct HSlider<'a, Message, Renderer: self::Renderer> {
     state: &'a mut State,
     on_change: Box<dyn Fn(Normal) -> Message>,
+    on_grab: Option<Box<dyn Fn() -> Message>>,
+    on_release: Option<Box<dyn Fn() -> Message>>,
+    tooltip: Option<Box<dyn Fn(Normal) -> String>>,
+    value_entry: Option<value_entry::Parse>,
     scalar: f32,
     wheel_scalar: f32,
+    pixel_wheel_scalar: f32,
     modifier_scalar: f32,
     modifier_keys: keyboard::Modifiers,
+    entry_modifier_keys: keyboard::Modifiers,
+    key_step: f32,
+    page_step: f32,
+    steps: Option<steps::Steps<'a>>,
+    snap_points: &'a [Normal],
+    snap_width: f32,
+    click_mode: ClickMode,
     width: Length,
     height: Length,
     style: Renderer::Style,
@@ -43,6 +61,7 @@ pub struct HSlider<'a, Message, Renderer: self::Renderer> {
     text_marks: Option<&'a text_marks::Group>,
     mod_range_1: Option<&'a ModulationRange>,
     mod_range_2: Option<&'a ModulationRange>,
+    mod_value: Option<Normal>,
 }
 
 impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
@@ -61,13 +80,28 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
         HSlider {
             state,
             on_change: Box::new(on_change),
+            on_grab: None,
+            on_release: None,
+            tooltip: None,
+            value_entry: None,
             scalar: DEFAULT_SCALAR,
             wheel_scalar: DEFAULT_WHEEL_SCALAR,
+            pixel_wheel_scalar: DEFAULT_PIXEL_WHEEL_SCALAR,
             modifier_scalar: DEFAULT_MODIFIER_SCALAR,
+            key_step: DEFAULT_KEY_STEP,
+            page_step: DEFAULT_PAGE_STEP,
+            steps: None,
+            snap_points: &[],
+            snap_width: DEFAULT_SNAP_WIDTH,
+            click_mode: ClickMode::default(),
             modifier_keys: keyboard::Modifiers {
                 control: true,
                 ..Default::default()
             },
+            entry_modifier_keys: keyboard::Modifiers {
+                alt: true,
+                ..Default::default()
+            },
             width: Length::Fill,
             height: Length::from(Length::Units(DEFAULT_HEIGHT)),
             style: Renderer::Style::default(),
@@ -75,6 +109,7 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
             text_marks: None,
             mod_range_1: None,
             mod_range_2: None,
+            mod_value: None,
         }
     }
 
@@ -106,6 +141,80 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
         self
     }
 
+    /// Sets a function that will be called when the user grabs the
+    /// [`HSlider`] to start changing its value, before any call to
+    /// `on_change`.
+    ///
+    /// This is also called around each keyboard step and double-click
+    /// reset, and once at the start of a scroll wheel gesture. Use it to
+    /// notify a plugin host that an edit has begun, so that automation is
+    /// recorded correctly.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn on_grab<F>(mut self, on_grab: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_grab = Some(Box::new(on_grab));
+        self
+    }
+
+    /// Sets a function that will be called when the user releases the
+    /// [`HSlider`] after changing its value.
+    ///
+    /// This is also called around each keyboard step and double-click
+    /// reset. A scroll wheel gesture is released once, with the first event
+    /// that arrives after the wheel has been idle for 300 milliseconds, or
+    /// as soon as the cursor leaves the [`HSlider`] or any other input
+    /// arrives. Use it to notify a plugin host that an edit has ended.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn on_release<F>(mut self, on_release: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_release = Some(Box::new(on_release));
+        self
+    }
+
+    /// Enables typing in an exact value for the [`HSlider`].
+    ///
+    /// Double-clicking the [`HSlider`], clicking it while holding down the
+    /// entry modifier key (`Alt` by default), or pressing `Enter` while it
+    /// has keyboard focus opens a text field over it. Double-clicking no longer
+    /// resets it to its default value. Pressing `Enter` sets the value if `parse`
+    /// returns `Some`, and pressing `Escape` or clicking outside of the
+    /// [`HSlider`] closes the text field without changing the value.
+    ///
+    /// `parse` is usually [`ParamRange::parse`] of the range the parameter
+    /// uses, for example `move |text| range.parse(text)`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`ParamRange::parse`]: ../../core/range/trait.ParamRange.html#tymethod.parse
+    pub fn value_entry<F>(mut self, parse: F) -> Self
+    where
+        F: 'static + Fn(&str) -> Option<Normal>,
+    {
+        self.value_entry = Some(Box::new(parse));
+        self
+    }
+
+    /// Sets a function that formats the value of the [`HSlider`] for a
+    /// tooltip, which is displayed while the [`HSlider`] is hovered or dragged.
+    ///
+    /// `format` is usually [`ParamRange::format`] of the range the
+    /// parameter uses, for example `move |normal| range.format(normal)`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`ParamRange::format`]: ../../core/range/trait.ParamRange.html#tymethod.format
+    pub fn tooltip<F>(mut self, format: F) -> Self
+    where
+        F: 'static + Fn(Normal) -> String,
+    {
+        self.tooltip = Some(Box::new(format));
+        self
+    }
+
     /// Sets the modifier keys of the [`HSlider`].
     ///
     /// The default modifier key is `Ctrl`.
@@ -116,6 +225,25 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the modifier keys that open the value entry when the [`HSlider`]
+    /// is clicked while they are held down. This has no effect unless
+    /// [`value_entry`] is set.
+    ///
+    /// Clicking with the same keys as [`modifier_keys`] always starts a
+    /// fine drag instead, and setting no keys disables opening the entry
+    /// with a click. The default entry modifier key is `Alt`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`value_entry`]: #method.value_entry
+    /// [`modifier_keys`]: #method.modifier_keys
+    pub fn entry_modifier_keys(
+        mut self,
+        entry_modifier_keys: keyboard::Modifiers,
+    ) -> Self {
+        self.entry_modifier_keys = entry_modifier_keys;
+        self
+    }
+
     /// Sets the scalar to use when the user drags the slider per pixel.
     ///
     /// For example, a scalar of `0.5` will cause the slider to move half a
@@ -132,6 +260,9 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
     /// Sets how much the [`Normal`] value will change for the [`HSlider`] per line scrolled
     /// by the mouse wheel.
     ///
+    /// Scrolling up or right increases the value, and scrolling down or left
+    /// decreases it.
+    ///
     /// This can be set to `0.0` to disable the scroll wheel from moving the parameter.
     ///
     /// The default value is `0.01`
@@ -143,6 +274,22 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
         self
     }
 
+    /// Sets how much the [`Normal`] value will change for the [`HSlider`] per
+    /// pixel scrolled on a trackpad, which reports the exact distance
+    /// scrolled instead of lines.
+    ///
+    /// This can be set to `0.0` to disable trackpad scrolling while still
+    /// allowing the scroll wheel.
+    ///
+    /// The default value is `0.0005`
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn pixel_wheel_scalar(mut self, pixel_wheel_scalar: f32) -> Self {
+        self.pixel_wheel_scalar = pixel_wheel_scalar;
+        self
+    }
+
     /// Sets the scalar to use when the user drags the slider while holding down
     /// the modifier key.
     ///
@@ -157,6 +304,90 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
         self
     }
 
+    /// Sets how much the [`Normal`] value of the [`HSlider`] will change when
+    /// an arrow key is pressed while it has keyboard focus.
+    ///
+    /// The default value is `0.01`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn key_step(mut self, key_step: f32) -> Self {
+        self.key_step = key_step;
+        self
+    }
+
+    /// Sets how much the [`Normal`] value of the [`HSlider`] will change when
+    /// `PageUp` or `PageDown` is pressed while it has keyboard focus.
+    ///
+    /// The default value is `0.1`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn page_step(mut self, page_step: f32) -> Self {
+        self.page_step = page_step;
+        self
+    }
+
+    /// Makes the [`HSlider`] stepped, so that its value snaps to the integer
+    /// values of `range` while it is dragged. There is no need to call
+    /// [`State::snap_visible_to`] after every change.
+    ///
+    /// `on_change` is only called when the snapped value changes, and the
+    /// arrow keys move the value by one step.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`State::snap_visible_to`]: struct.State.html#method.snap_visible_to
+    pub fn stepped(mut self, range: IntRange) -> Self {
+        self.steps = Some(steps::Steps::Range(range));
+        self
+    }
+
+    /// Makes the [`HSlider`] stepped, so that its value snaps to the closest
+    /// of the given `detents` while it is dragged.
+    ///
+    /// `on_change` is only called when the snapped value changes, and the
+    /// arrow keys move the value to the next or previous detent.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn detents(mut self, detents: &'a [Normal]) -> Self {
+        self.steps = Some(steps::Steps::Detents(detents));
+        self
+    }
+
+    /// Sets positions that the [`HSlider`] briefly sticks to while it is
+    /// dragged, such as the center of a pan control. Unlike
+    /// [`detents`], the [`HSlider`] can still be set to any value in between.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`detents`]: #method.detents
+    pub fn snap_points(mut self, snap_points: &'a [Normal]) -> Self {
+        self.snap_points = snap_points;
+        self
+    }
+
+    /// Sets how many pixels the mouse must move past a snap point before
+    /// the [`HSlider`] lets go of it.
+    ///
+    /// The default value is `10.0`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn snap_width(mut self, snap_width: f32) -> Self {
+        self.snap_width = snap_width;
+        self
+    }
+
+    /// Sets what happens when the rail of the [`HSlider`] is clicked outside
+    /// of its handle.
+    ///
+    /// The default click mode is [`ClickMode::Relative`].
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`ClickMode::Relative`]: ../../core/click_mode/enum.ClickMode.html#variant.Relative
+    pub fn click_mode(mut self, click_mode: ClickMode) -> Self {
+        self.click_mode = click_mode;
+        self
+    }
+
     /// Sets the tick marks to display. Note your [`StyleSheet`] must
     /// also implement `tick_marks_style(&self) -> Option<tick_marks::Style>` for
     /// them to display (which the default style does).
@@ -199,17 +430,193 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
         self
     }
 
+    /// Sets a modulated value to display as a dot that moves independently
+    /// of the value set by the user. This is meant to be updated every frame
+    /// to show modulation (from an LFO, envelope, etc.) happening in real
+    /// time. Note your [`StyleSheet`] must also implement
+    /// `mod_value_style(&self) -> Option<ModValueStyle>` for it to display
+    /// (which the default style does).
+    ///
+    /// [`StyleSheet`]: ../../style/h_slider/trait.StyleSheet.html
+    pub fn mod_value(mut self, mod_value: Normal) -> Self {
+        self.mod_value = Some(mod_value);
+        self
+    }
+
+    fn grab(&self, messages: &mut Vec<Message>) {
+        if let Some(on_grab) = &self.on_grab {
+            messages.push((on_grab)());
+        }
+    }
+
+    fn release(&self, messages: &mut Vec<Message>) {
+        if let Some(on_release) = &self.on_release {
+            messages.push((on_release)());
+        }
+    }
+
+    fn snapped(&self, normal: Normal) -> Normal {
+        match &self.steps {
+            Some(steps) => steps.snapped(normal),
+            None => normal,
+        }
+    }
+
+    fn key_normal(&self, key_code: keyboard::KeyCode) -> Option<Normal> {
+        let value = self.state.normal_param.value;
+
+        let normal = focus::key_normal(
+            key_code,
+            &self.state.normal_param,
+            self.key_step,
+            self.page_step,
+        )?;
+
+        match &self.steps {
+            Some(steps) => {
+                Some(steps.step_toward(value, normal)).filter(|n| *n != value)
+            }
+            None => Some(normal),
+        }
+    }
+
+    fn update_value_entry(
+        &mut self,
+        event: keyboard::Event,
+        messages: &mut Vec<Message>,
+    ) {
+        match event {
+            keyboard::Event::KeyPressed { modifiers, .. }
+            | keyboard::Event::KeyReleased { modifiers, .. } => {
+                self.state.pressed_modifiers = modifiers;
+            }
+            _ => {}
+        }
+
+        let text = match &mut self.state.entry_text {
+            Some(text) => text,
+            None => return,
+        };
+
+        match value_entry::update(text, event) {
+            value_entry::Update::Submit => {
+                let normal =
+                    self.value_entry.as_ref().and_then(|parse| parse(text));
+
+                if let Some(normal) = normal {
+                    let normal = self.snapped(normal);
+
+                    self.state.entry_text = None;
+                    self.state.normal_param.value = normal;
+                    self.state.continuous_normal = normal.as_f32();
+
+                    self.grab(messages);
+                    messages.push((self.on_change)(normal));
+                    self.release(messages);
+                }
+            }
+            value_entry::Update::Cancel => {
+                self.state.entry_text = None;
+            }
+            value_entry::Update::Changed | value_entry::Update::Ignored => {}
+        }
+    }
+
+    fn press(
+        &mut self,
+        messages: &mut Vec<Message>,
+        bounds: &Rectangle,
+        cursor_position: Point,
+        handle_width: f32,
+    ) {
+        // Use the same whole-pixel bounds that the renderer draws with, so
+        // the handle is hit exactly where it is drawn.
+        let bounds = &Rectangle {
+            x: bounds.x.round(),
+            y: bounds.y.round(),
+            width: bounds.width.round(),
+            height: bounds.height.round(),
+        };
+
+        let rail_width = bounds.width - handle_width;
+
+        let handle_x = bounds.x
+            + (handle_width / 2.0)
+            + self.state.normal_param.value.scale(rail_width);
+
+        let is_on_handle =
+            (cursor_position.x - handle_x).abs() <= handle_width / 2.0;
+
+        let cursor_normal = if rail_width > 0.0 {
+            (cursor_position.x - bounds.x - (handle_width / 2.0)) / rail_width
+        } else {
+            self.state.continuous_normal
+        };
+
+        let click_mode = if is_on_handle {
+            ClickMode::Relative
+        } else {
+            self.click_mode
+        };
+
+        self.grab(messages);
+
+        match click_mode {
+            ClickMode::Relative => {}
+            ClickMode::Absolute => {
+                self.set_continuous_normal(messages, cursor_normal);
+            }
+            ClickMode::Page => {
+                let value = self.state.normal_param.value.as_f32();
+
+                let normal = if cursor_normal > value {
+                    (value + self.page_step).min(cursor_normal)
+                } else {
+                    (value - self.page_step).max(cursor_normal)
+                };
+
+                self.set_continuous_normal(messages, normal);
+                self.release(messages);
+
+                return;
+            }
+        }
+
+        self.state.is_dragging = true;
+        self.state.snap_travel = snap_points::held_at(
+            self.snap_points,
+            self.state.continuous_normal,
+        );
+        self.state.prev_drag_x = cursor_position.x;
+    }
+
     fn move_virtual_slider(
         &mut self,
         messages: &mut Vec<Message>,
         mut normal_delta: f32,
+        mut snap_capture: f32,
     ) {
         if self.state.pressed_modifiers.matches(self.modifier_keys) {
             normal_delta *= self.modifier_scalar;
+            snap_capture *= self.modifier_scalar;
         }
 
-        let mut normal = self.state.continuous_normal - normal_delta;
+        let normal = snap_points::drag(
+            self.snap_points,
+            snap_capture,
+            &mut self.state.snap_travel,
+            self.state.continuous_normal,
+            -normal_delta,
+        );
 
+        self.set_continuous_normal(messages, normal);
+    }
+
+    fn set_continuous_normal(
+        &mut self,
+        messages: &mut Vec<Message>,
+        mut normal: f32,
+    ) {
         if normal < 0.0 {
             normal = 0.0;
         } else if normal > 1.0 {
@@ -218,7 +625,13 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
 
         self.state.continuous_normal = normal;
 
-        self.state.normal_param.value = normal.into();
+        let normal = self.snapped(normal.into());
+
+        if self.steps.is_some() && normal == self.state.normal_param.value {
+            return;
+        }
+
+        self.state.normal_param.value = normal;
 
         messages.push((self.on_change)(self.state.normal_param.value));
     }
@@ -231,9 +644,13 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
 pub struct State {
     normal_param: NormalParam,
     is_dragging: bool,
+    is_focused: bool,
+    entry_text: Option<String>,
     prev_drag_x: f32,
     continuous_normal: f32,
+    snap_travel: Option<f32>,
     pressed_modifiers: keyboard::Modifiers,
+    scroll_gesture: scroll::Gesture,
     last_click: Option<mouse::Click>,
     tick_marks_cache: crate::graphics::tick_marks::PrimitiveCache,
     text_marks_cache: crate::graphics::text_marks::PrimitiveCache,
@@ -251,9 +668,13 @@ impl State {
         Self {
             normal_param,
             is_dragging: false,
+            is_focused: false,
+            entry_text: None,
             prev_drag_x: 0.0,
             continuous_normal: normal_param.value.as_f32(),
+            snap_travel: None,
             pressed_modifiers: Default::default(),
+            scroll_gesture: Default::default(),
             last_click: None,
             tick_marks_cache: Default::default(),
             text_marks_cache: Default::default(),
@@ -305,6 +726,28 @@ impl State {
     pub fn is_dragging(&self) -> bool {
         self.is_dragging
     }
+
+    /// Is the text field for typing in an exact value of the [`HSlider`]
+    /// currently open?
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn is_entering_value(&self) -> bool {
+        self.entry_text.is_some()
+    }
+}
+
+impl focus::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -337,10 +780,17 @@ where
         event: Event,
         layout: Layout<'_>,
         cursor_position: Point,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
     ) -> event::Status {
+        self.state.scroll_gesture.update(
+            &event,
+            layout.bounds().contains(cursor_position),
+            &self.on_release,
+            messages,
+        );
+
         match event {
             Event::Mouse(mouse_event) => match mouse_event {
                 mouse::Event::CursorMoved { .. } => {
@@ -353,9 +803,16 @@ where
                                 / bounds_width
                                 * -self.scalar;
 
+                            let snap_capture =
+                                self.snap_width / bounds_width * self.scalar;
+
                             self.state.prev_drag_x = cursor_position.x;
 
-                            self.move_virtual_slider(messages, normal_delta);
+                            self.move_virtual_slider(
+                                messages,
+                                normal_delta,
+                                snap_capture,
+                            );
 
                             return event::Status::Captured;
                         }
@@ -367,35 +824,48 @@ where
                     }
 
                     if layout.bounds().contains(cursor_position) {
-                        let lines = match delta {
-                            iced_native::mouse::ScrollDelta::Lines {
-                                y,
-                                ..
-                            } => y,
-                            iced_native::mouse::ScrollDelta::Pixels {
-                                y,
-                                ..
-                            } => {
-                                if y > 0.0 {
-                                    1.0
-                                } else if y < 0.0 {
-                                    -1.0
-                                } else {
-                                    0.0
-                                }
-                            }
-                        };
+                        // Use whichever direction was scrolled the furthest, as
+                        // trackpads often report a little of both.
+                        let scroll_x = scroll::horizontal(
+                            delta,
+                            self.wheel_scalar,
+                            self.pixel_wheel_scalar,
+                        );
+                        let scroll_y = scroll::vertical(
+                            delta,
+                            self.wheel_scalar,
+                            self.pixel_wheel_scalar,
+                        );
 
-                        if lines != 0.0 {
-                            let normal_delta = -lines * self.wheel_scalar;
+                        let normal_delta = if scroll_x.abs() > scroll_y.abs() {
+                            -scroll_x
+                        } else {
+                            -scroll_y
+                        };
 
-                            self.move_virtual_slider(messages, normal_delta);
+                        if normal_delta != 0.0 {
+                            self.state
+                                .scroll_gesture
+                                .scroll(&self.on_grab, messages);
+                            self.move_virtual_slider(
+                                messages,
+                                normal_delta,
+                                0.0,
+                            );
 
                             return event::Status::Captured;
                         }
                     }
                 }
                 mouse::Event::ButtonPressed(mouse::Button::Left) => {
+                    if self.state.entry_text.is_some() {
+                        if layout.bounds().contains(cursor_position) {
+                            return event::Status::Captured;
+                        }
+
+                        self.state.entry_text = None;
+                    }
+
                     if layout.bounds().contains(cursor_position) {
                         let click = mouse::Click::new(
                             cursor_position,
@@ -403,29 +873,80 @@ where
                         );
 
                         match click.kind() {
+                            mouse::click::Kind::Single
+                                if self.value_entry.is_some()
+                                    && value_entry::is_entry_click(
+                                        self.state.pressed_modifiers,
+                                        self.entry_modifier_keys,
+                                        self.modifier_keys,
+                                    ) =>
+                            {
+                                self.state.entry_text = Some(String::new());
+                            }
                             mouse::click::Kind::Single => {
-                                self.state.is_dragging = true;
-                                self.state.prev_drag_x = cursor_position.x;
+                                let handle_width = renderer.handle_width(
+                                    layout.bounds(),
+                                    cursor_position,
+                                    self.state.is_dragging,
+                                    self.state.is_focused,
+                                    &self.style,
+                                );
+
+                                self.press(
+                                    messages,
+                                    &layout.bounds(),
+                                    cursor_position,
+                                    handle_width,
+                                );
                             }
                             _ => {
                                 self.state.is_dragging = false;
 
-                                self.state.normal_param.value =
-                                    self.state.normal_param.default;
+                                if self.value_entry.is_some() {
+                                    self.state.entry_text = Some(String::new());
+                                } else {
+                                    self.grab(messages);
+
+                                    let default = self.snapped(
+                                        self.state.normal_param.default,
+                                    );
+
+                                    self.state.normal_param.value = default;
+
+                                    messages.push((self.on_change)(
+                                        self.state.normal_param.value,
+                                    ));
 
-                                messages.push((self.on_change)(
-                                    self.state.normal_param.value,
-                                ));
+                                    self.release(messages);
+                                }
                             }
                         }
 
-                        self.state.last_click = Some(click);
+                        // Paging the value or opening the value entry does not
+                        // start a double-click, so that the rail can be
+                        // clicked repeatedly.
+                        self.state.last_click = match click.kind() {
+                            mouse::click::Kind::Single
+                                if !self.state.is_dragging =>
+                            {
+                                None
+                            }
+                            _ => Some(click),
+                        };
+                        self.state.is_focused = true;
 
                         return event::Status::Captured;
+                    } else {
+                        self.state.is_focused = false;
                     }
                 }
                 mouse::Event::ButtonReleased(mouse::Button::Left) => {
+                    if self.state.is_dragging {
+                        self.release(messages);
+                    }
+
                     self.state.is_dragging = false;
+                    self.state.snap_travel = None;
                     self.state.continuous_normal =
                         self.state.normal_param.value.as_f32();
 
@@ -433,10 +954,35 @@ where
                 }
                 _ => {}
             },
+            Event::Keyboard(keyboard_event)
+                if self.state.entry_text.is_some() =>
+            {
+                self.update_value_entry(keyboard_event, messages);
+
+                return event::Status::Captured;
+            }
             Event::Keyboard(keyboard_event) => match keyboard_event {
-                keyboard::Event::KeyPressed { modifiers, .. } => {
+                keyboard::Event::KeyPressed {
+                    key_code,
+                    modifiers,
+                } => {
                     self.state.pressed_modifiers = modifiers;
 
+                    if self.state.is_focused {
+                        if key_code == keyboard::KeyCode::Enter
+                            && self.value_entry.is_some()
+                        {
+                            self.state.entry_text = Some(String::new());
+                        } else if let Some(normal) = self.key_normal(key_code) {
+                            self.state.normal_param.value = normal;
+                            self.state.continuous_normal = normal.as_f32();
+
+                            self.grab(messages);
+                            messages.push((self.on_change)(normal));
+                            self.release(messages);
+                        }
+                    }
+
                     return event::Status::Captured;
                 }
                 keyboard::Event::KeyReleased { modifiers, .. } => {
@@ -458,15 +1004,29 @@ where
         _defaults: &Renderer::Defaults,
         layout: Layout<'_>,
         cursor_position: Point,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) -> Renderer::Output {
+        let show_tooltip = (self.state.is_dragging
+            || layout.bounds().contains(cursor_position))
+            && self.state.entry_text.is_none();
+        let tooltip = self
+            .tooltip
+            .as_ref()
+            .filter(|_| show_tooltip)
+            .map(|format| format(self.state.normal_param.value));
+
         renderer.draw(
             layout.bounds(),
             cursor_position,
             self.state.normal_param.value,
             self.state.is_dragging,
+            self.state.is_focused,
+            self.state.entry_text.as_deref(),
+            tooltip.as_deref(),
+            viewport,
             self.mod_range_1,
             self.mod_range_2,
+            self.mod_value,
             self.tick_marks,
             self.text_marks,
             &self.style,
@@ -494,6 +1054,28 @@ pub trait Renderer: iced_native::Renderer {
     /// The style supported by this renderer.
     type Style: Default;
 
+    /// Returns the width of the handle in pixels, which is used to map a
+    /// click on the rail to a value. This should come from the same style
+    /// that [`draw`] would choose for the given state.
+    ///
+    /// It receives:
+    ///   * the bounds of the [`HSlider`]
+    ///   * the current cursor position
+    ///   * whether the slider is currently being dragged
+    ///   * whether the [`HSlider`] has keyboard focus
+    ///   * the style of the [`HSlider`]
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`draw`]: #tymethod.draw
+    fn handle_width(
+        &self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        is_dragging: bool,
+        is_focused: bool,
+        style: &Self::Style,
+    ) -> f32;
+
     /// Draws an [`HSlider`].
     ///
     /// It receives:
@@ -502,19 +1084,31 @@ pub trait Renderer: iced_native::Renderer {
     ///   * the current normal of the [`HSlider`]
     ///   * the height of the handle in pixels
     ///   * whether the slider is currently being dragged
+    ///   * whether the [`HSlider`] has keyboard focus
+    ///   * the text typed into the value entry, if it is open
+    ///   * the text of the tooltip to display, if any
+    ///   * the visible bounds that the tooltip must stay inside
+    ///   * any modulation ranges to display
+    ///   * any modulated value to display
     ///   * any tick marks to display
     ///   * any text marks to display
     ///   * the style of the [`HSlider`]
     ///
     /// [`HSlider`]: struct.HSlider.html
+    #[allow(clippy::too_many_arguments)]
     fn draw(
         &mut self,
         bounds: Rectangle,
         cursor_position: Point,
         normal: Normal,
         is_dragging: bool,
+        is_focused: bool,
+        entry_text: Option<&str>,
+        tooltip_text: Option<&str>,
+        viewport: &Rectangle,
         mod_range_1: Option<&ModulationRange>,
         mod_range_2: Option<&ModulationRange>,
+        mod_value: Option<Normal>,
         tick_marks: Option<&tick_marks::Group>,
         text_marks: Option<&text_marks::Group>,
         style: &Self::Style,
@@ -0,0 +1,592 @@
+//! Display a piano keyboard that emits note-on and note-off messages.
+
+use std::fmt::Debug;
+
+use iced_native::{
+    event, layout, mouse, Clipboard, Element, Event, Hasher, Layout, Length,
+    Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+use crate::core::Normal;
+
+static DEFAULT_SIZE: u16 = 60;
+static DEFAULT_BLACK_KEY_WIDTH: f32 = 0.6;
+static DEFAULT_BLACK_KEY_LENGTH: f32 = 0.62;
+// The quietest MIDI note-on velocity.
+static MIN_VELOCITY: f32 = 1.0 / 127.0;
+
+/// The orientation of a [`Keys`] widget.
+///
+/// [`Keys`]: struct.Keys.html
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum Orientation {
+    /// Low notes on the left and high notes on the right. The black keys
+    /// are at the top.
+    #[default]
+    Horizontal,
+    /// Low notes at the bottom and high notes at the top. The black keys
+    /// are on the left.
+    Vertical,
+}
+
+/// The state of a single key in a [`Keys`] widget.
+///
+/// [`Keys`]: struct.Keys.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum KeyState {
+    /// The key is not pressed, highlighted, or hovered
+    Idle,
+    /// The cursor is over the key
+    Hovered,
+    /// The key is highlighted from the [`State`] (for example from MIDI
+    /// input)
+    ///
+    /// [`State`]: struct.State.html
+    Highlighted,
+    /// The key is pressed with the mouse
+    Pressed,
+}
+
+/// A single key in a [`Keys`] widget, as passed to the renderer.
+///
+/// [`Keys`]: struct.Keys.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Key {
+    /// The MIDI note number of the key
+    pub note: u8,
+    /// Whether this is a black key
+    pub is_black: bool,
+    /// The bounds of the key
+    pub bounds: Rectangle,
+    /// The state of the key
+    pub state: KeyState,
+}
+
+/// Returns `true` if the given MIDI note number is a black key.
+pub fn is_black_key(note: u8) -> bool {
+    matches!(note % 12, 1 | 3 | 6 | 8 | 10)
+}
+
+/// A piano keyboard GUI widget that emits note-on and note-off messages.
+///
+/// The velocity of a note is derived from where on the key the user
+/// clicked: the further towards the front of the key, the higher the
+/// velocity. Dragging across the keys plays a glissando.
+///
+/// [`Keys`]: struct.Keys.html
+#[allow(missing_debug_implementations)]
+pub struct Keys<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    on_note_on: Box<dyn Fn(u8, Normal) -> Message>,
+    on_note_off: Box<dyn Fn(u8) -> Message>,
+    orientation: Orientation,
+    black_key_width: f32,
+    black_key_length: f32,
+    width: Option<Length>,
+    height: Option<Length>,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer> Keys<'a, Message, Renderer> {
+    /// Creates a new [`Keys`] widget.
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`Keys`]
+    ///   * a function that will be called when a key is pressed, with the
+    ///     MIDI note number and the velocity of the note
+    ///   * a function that will be called when a key is released, with the
+    ///     MIDI note number
+    ///
+    /// [`State`]: struct.State.html
+    /// [`Keys`]: struct.Keys.html
+    pub fn new<F, G>(
+        state: &'a mut State,
+        on_note_on: F,
+        on_note_off: G,
+    ) -> Self
+    where
+        F: 'static + Fn(u8, Normal) -> Message,
+        G: 'static + Fn(u8) -> Message,
+    {
+        Keys {
+            state,
+            on_note_on: Box::new(on_note_on),
+            on_note_off: Box::new(on_note_off),
+            orientation: Orientation::default(),
+            black_key_width: DEFAULT_BLACK_KEY_WIDTH,
+            black_key_length: DEFAULT_BLACK_KEY_LENGTH,
+            width: None,
+            height: None,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the [`Orientation`] of the [`Keys`]. This also swaps the
+    /// default width and height, but not a width or height that was set
+    /// explicitly.
+    ///
+    /// The default orientation is `Orientation::Horizontal`.
+    ///
+    /// [`Orientation`]: enum.Orientation.html
+    /// [`Keys`]: struct.Keys.html
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets the width of the [`Keys`].
+    ///
+    /// The default width of a horizontal keyboard is `Length::Fill`, and
+    /// the default width of a vertical keyboard is
+    /// `Length::from(Length::Units(60))`.
+    ///
+    /// [`Keys`]: struct.Keys.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Sets the height of the [`Keys`].
+    ///
+    /// The default height of a horizontal keyboard is
+    /// `Length::from(Length::Units(60))`, and the default height of a
+    /// vertical keyboard is `Length::Fill`.
+    ///
+    /// [`Keys`]: struct.Keys.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Sets the style of the [`Keys`].
+    ///
+    /// [`Keys`]: struct.Keys.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the width of a black key relative to the width of a white key.
+    ///
+    /// The default value is `0.6`
+    pub fn black_key_width(mut self, ratio: f32) -> Self {
+        self.black_key_width = ratio;
+        self
+    }
+
+    /// Sets the length of a black key relative to the length of a white
+    /// key.
+    ///
+    /// The default value is `0.62`
+    pub fn black_key_length(mut self, ratio: f32) -> Self {
+        self.black_key_length = ratio;
+        self
+    }
+
+    fn keys(&self, bounds: &Rectangle) -> Vec<Key> {
+        let first = self.state.first_note;
+        let last = self.state.last_note;
+
+        let white_count =
+            (first..=last).filter(|note| !is_black_key(*note)).count();
+
+        if white_count == 0 {
+            return Vec::new();
+        }
+
+        let (span, length) = match self.orientation {
+            Orientation::Horizontal => (bounds.width, bounds.height),
+            Orientation::Vertical => (bounds.height, bounds.width),
+        };
+
+        let white_span = span / white_count as f32;
+        let black_span = white_span * self.black_key_width;
+        let black_length = length * self.black_key_length;
+
+        let mut white_keys: Vec<Key> = Vec::with_capacity(white_count);
+        let mut black_keys: Vec<Key> = Vec::new();
+
+        let mut white_index = 0;
+
+        for note in first..=last {
+            // (start along the keyboard, span, length)
+            let (start, key_span, key_length) = if is_black_key(note) {
+                let center = white_index as f32 * white_span;
+
+                let start = (center - (black_span / 2.0))
+                    .max(0.0)
+                    .min(span - black_span);
+
+                (start, black_span, black_length)
+            } else {
+                white_index += 1;
+
+                ((white_index - 1) as f32 * white_span, white_span, length)
+            };
+
+            let key_bounds = match self.orientation {
+                Orientation::Horizontal => Rectangle {
+                    x: bounds.x + start,
+                    y: bounds.y,
+                    width: key_span,
+                    height: key_length,
+                },
+                Orientation::Vertical => Rectangle {
+                    x: bounds.x,
+                    y: bounds.y + bounds.height - start - key_span,
+                    width: key_length,
+                    height: key_span,
+                },
+            };
+
+            let key = Key {
+                note,
+                is_black: is_black_key(note),
+                bounds: key_bounds,
+                state: KeyState::Idle,
+            };
+
+            if key.is_black {
+                black_keys.push(key);
+            } else {
+                white_keys.push(key);
+            }
+        }
+
+        // Black keys are on top, so they come last.
+        white_keys.append(&mut black_keys);
+
+        white_keys
+    }
+
+    fn key_at(&self, keys: &[Key], cursor: Point) -> Option<(u8, Normal)> {
+        keys.iter()
+            .rev()
+            .find(|key| key.bounds.contains(cursor))
+            .map(|key| {
+                (key.note, velocity_at(self.orientation, &key.bounds, cursor))
+            })
+    }
+}
+
+/// Returns the velocity of a note played by clicking on a key with the
+/// given bounds. A click at the very back of the key still plays the
+/// quietest MIDI velocity, since a velocity of `0` would be a note-off.
+fn velocity_at(
+    orientation: Orientation,
+    key_bounds: &Rectangle,
+    cursor: Point,
+) -> Normal {
+    let velocity = match orientation {
+        Orientation::Horizontal => {
+            (cursor.y - key_bounds.y) / key_bounds.height
+        }
+        Orientation::Vertical => (cursor.x - key_bounds.x) / key_bounds.width,
+    };
+
+    velocity.max(MIN_VELOCITY).into()
+}
+
+/// The local state of a [`Keys`] widget.
+///
+/// [`Keys`]: struct.Keys.html
+#[derive(Debug, Copy, Clone)]
+pub struct State {
+    first_note: u8,
+    last_note: u8,
+    highlighted: [bool; 128],
+    pressed_note: Option<u8>,
+    is_dragging: bool,
+}
+
+impl State {
+    /// Creates a new [`Keys`] state.
+    ///
+    /// It expects:
+    /// * the MIDI note number of the lowest key (for example `48` for C3)
+    /// * the MIDI note number of the highest key (for example `72` for C5)
+    ///
+    /// [`Keys`]: struct.Keys.html
+    pub fn new(first_note: u8, last_note: u8) -> Self {
+        let mut state = Self {
+            first_note: 0,
+            last_note: 0,
+            highlighted: [false; 128],
+            pressed_note: None,
+            is_dragging: false,
+        };
+
+        state.set_note_range(first_note, last_note);
+
+        state
+    }
+
+    /// Sets the MIDI note numbers of the lowest and the highest key.
+    pub fn set_note_range(&mut self, first_note: u8, last_note: u8) {
+        let first_note = first_note.min(127);
+        let last_note = last_note.min(127).max(first_note);
+
+        self.first_note = first_note;
+        self.last_note = last_note;
+    }
+
+    /// Returns the MIDI note numbers of the lowest and the highest key.
+    pub fn note_range(&self) -> (u8, u8) {
+        (self.first_note, self.last_note)
+    }
+
+    /// Highlights or un-highlights a key. Use this to display notes that
+    /// are triggered from outside of the widget (for example from MIDI
+    /// input).
+    pub fn set_highlighted(&mut self, note: u8, highlighted: bool) {
+        if let Some(h) = self.highlighted.get_mut(usize::from(note)) {
+            *h = highlighted;
+        }
+    }
+
+    /// Returns `true` if the key is highlighted.
+    pub fn is_highlighted(&self, note: u8) -> bool {
+        self.highlighted
+            .get(usize::from(note))
+            .copied()
+            .unwrap_or(false)
+    }
+
+    /// Un-highlights all keys.
+    pub fn clear_highlighted(&mut self) {
+        self.highlighted = [false; 128];
+    }
+
+    /// Returns the note that is currently pressed with the mouse, if any.
+    pub fn pressed_note(&self) -> Option<u8> {
+        self.pressed_note
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Keys<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width.unwrap_or(match self.orientation {
+            Orientation::Horizontal => Length::Fill,
+            Orientation::Vertical => Length::Units(DEFAULT_SIZE),
+        })
+    }
+
+    fn height(&self) -> Length {
+        self.height.unwrap_or(match self.orientation {
+            Orientation::Horizontal => Length::Units(DEFAULT_SIZE),
+            Orientation::Vertical => Length::Fill,
+        })
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .width(Widget::<Message, Renderer>::width(self))
+            .height(Widget::<Message, Renderer>::height(self));
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        if let Event::Mouse(mouse_event) = event {
+            match mouse_event {
+                mouse::Event::CursorMoved { .. } if self.state.is_dragging => {
+                    let keys = self.keys(&layout.bounds());
+                    let key = self.key_at(&keys, cursor_position);
+
+                    let note = key.map(|(note, _)| note);
+
+                    if note != self.state.pressed_note {
+                        if let Some(pressed_note) = self.state.pressed_note {
+                            messages.push((self.on_note_off)(pressed_note));
+                        }
+
+                        if let Some((note, velocity)) = key {
+                            messages.push((self.on_note_on)(note, velocity));
+                        }
+
+                        self.state.pressed_note = note;
+                    }
+
+                    return event::Status::Captured;
+                }
+                mouse::Event::ButtonPressed(mouse::Button::Left) => {
+                    let keys = self.keys(&layout.bounds());
+
+                    if let Some((note, velocity)) =
+                        self.key_at(&keys, cursor_position)
+                    {
+                        self.state.is_dragging = true;
+                        self.state.pressed_note = Some(note);
+
+                        messages.push((self.on_note_on)(note, velocity));
+
+                        return event::Status::Captured;
+                    }
+                }
+                mouse::Event::ButtonReleased(mouse::Button::Left)
+                    if self.state.is_dragging =>
+                {
+                    self.state.is_dragging = false;
+
+                    if let Some(pressed_note) = self.state.pressed_note.take() {
+                        messages.push((self.on_note_off)(pressed_note));
+                    }
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let mut keys = self.keys(&layout.bounds());
+
+        let hovered_note = if self.state.is_dragging {
+            None
+        } else {
+            self.key_at(&keys, cursor_position).map(|(note, _)| note)
+        };
+
+        for key in keys.iter_mut() {
+            key.state = if Some(key.note) == self.state.pressed_note {
+                KeyState::Pressed
+            } else if self.state.is_highlighted(key.note) {
+                KeyState::Highlighted
+            } else if Some(key.note) == hovered_note {
+                KeyState::Hovered
+            } else {
+                KeyState::Idle
+            };
+        }
+
+        renderer.draw(
+            layout.bounds(),
+            cursor_position,
+            self.orientation,
+            &keys,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        Widget::<Message, Renderer>::width(self).hash(state);
+        Widget::<Message, Renderer>::height(self).hash(state);
+    }
+}
+
+/// The renderer of a [`Keys`] widget.
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`Keys`] widget in your user interface.
+///
+/// [`Keys`]: struct.Keys.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`Keys`] widget.
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Keys`]
+    ///   * the current cursor position
+    ///   * the orientation of the [`Keys`]
+    ///   * the keys to draw, with the white keys first and the black keys
+    ///     last
+    ///   * the style of the [`Keys`]
+    ///
+    /// [`Keys`]: struct.Keys.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        orientation: Orientation,
+        keys: &[Key],
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Keys<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        keys: Keys<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(keys)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY_BOUNDS: Rectangle = Rectangle {
+        x: 10.0,
+        y: 20.0,
+        width: 30.0,
+        height: 100.0,
+    };
+
+    #[test]
+    fn back_edge_plays_quietest_velocity() {
+        let horizontal = velocity_at(
+            Orientation::Horizontal,
+            &KEY_BOUNDS,
+            Point::new(25.0, 20.0),
+        );
+        let vertical = velocity_at(
+            Orientation::Vertical,
+            &KEY_BOUNDS,
+            Point::new(10.0, 70.0),
+        );
+
+        assert_eq!(horizontal.as_f32(), 1.0 / 127.0);
+        assert_eq!(vertical.as_f32(), 1.0 / 127.0);
+    }
+
+    #[test]
+    fn front_edge_plays_full_velocity() {
+        let velocity = velocity_at(
+            Orientation::Horizontal,
+            &KEY_BOUNDS,
+            Point::new(25.0, 120.0),
+        );
+
+        assert_eq!(velocity.as_f32(), 1.0);
+    }
+}
@@ -11,14 +11,21 @@ use iced_native::{
 
 use std::hash::Hash;
 
-use crate::core::{ModulationRange, Normal, NormalParam};
-use crate::native::{text_marks, tick_marks};
+use crate::core::math::TWO_PI;
+use crate::core::{KnobAngleRange, ModulationRange, Normal, NormalParam};
+use crate::native::{
+    focus, scroll, snap_points, steps, text_marks, tick_marks, value_entry,
+};
 use crate::IntRange;
 
 static DEFAULT_SIZE: u16 = 30;
 static DEFAULT_SCALAR: f32 = 0.00385;
 static DEFAULT_WHEEL_SCALAR: f32 = 0.01;
+static DEFAULT_PIXEL_WHEEL_SCALAR: f32 = 0.0005;
 static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+static DEFAULT_KEY_STEP: f32 = 0.01;
+static DEFAULT_PAGE_STEP: f32 = 0.1;
+static DEFAULT_SNAP_WIDTH: f32 = 10.0;
 
 /// A rotating knob GUI widget that controls a [`NormalParam`]
 ///
@@ -28,15 +35,28 @@ pub struct Knob<'a, Message, Renderer: self::Renderer> {
     state: &'a mut State,
     size: Length,
     on_change: Box<dyn Fn(Normal) -> Message>,
+    on_grab: Option<Box<dyn Fn() -> Message>>,
+    on_release: Option<Box<dyn Fn() -> Message>>,
+    tooltip: Option<Box<dyn Fn(Normal) -> String>>,
+    value_entry: Option<value_entry::Parse>,
+    drag_mode: DragMode,
     scalar: f32,
     wheel_scalar: f32,
+    pixel_wheel_scalar: f32,
     modifier_scalar: f32,
     modifier_keys: keyboard::Modifiers,
+    entry_modifier_keys: keyboard::Modifiers,
+    key_step: f32,
+    page_step: f32,
+    steps: Option<steps::Steps<'a>>,
+    snap_points: &'a [Normal],
+    snap_width: f32,
     style: Renderer::Style,
     tick_marks: Option<&'a tick_marks::Group>,
     text_marks: Option<&'a text_marks::Group>,
     mod_range_1: Option<&'a ModulationRange>,
     mod_range_2: Option<&'a ModulationRange>,
+    mod_value: Option<Normal>,
 }
 
 impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
@@ -56,18 +76,34 @@ impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
             state,
             size: Length::from(Length::Units(DEFAULT_SIZE)),
             on_change: Box::new(on_change),
+            on_grab: None,
+            on_release: None,
+            tooltip: None,
+            value_entry: None,
+            drag_mode: DragMode::default(),
             scalar: DEFAULT_SCALAR,
             wheel_scalar: DEFAULT_WHEEL_SCALAR,
+            pixel_wheel_scalar: DEFAULT_PIXEL_WHEEL_SCALAR,
             modifier_scalar: DEFAULT_MODIFIER_SCALAR,
+            key_step: DEFAULT_KEY_STEP,
+            page_step: DEFAULT_PAGE_STEP,
+            steps: None,
+            snap_points: &[],
+            snap_width: DEFAULT_SNAP_WIDTH,
             modifier_keys: keyboard::Modifiers {
                 control: true,
                 ..Default::default()
             },
+            entry_modifier_keys: keyboard::Modifiers {
+                alt: true,
+                ..Default::default()
+            },
             style: Renderer::Style::default(),
             tick_marks: None,
             text_marks: None,
             mod_range_1: None,
             mod_range_2: None,
+            mod_value: None,
         }
     }
 
@@ -88,8 +124,21 @@ impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
         self
     }
 
-    /// Sets how much the [`Normal`] value will change for the [`Knob`] per `y`
-    /// pixel movement of the mouse.
+    /// Sets how the movement of the mouse turns the [`Knob`] while it is
+    /// dragged.
+    ///
+    /// The default drag mode is [`DragMode::Vertical`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`DragMode::Vertical`]: enum.DragMode.html#variant.Vertical
+    pub fn drag_mode(mut self, drag_mode: DragMode) -> Self {
+        self.drag_mode = drag_mode;
+        self
+    }
+
+    /// Sets how much the [`Normal`] value will change for the [`Knob`] per
+    /// pixel movement of the mouse. This is not used by the rotary drag
+    /// modes.
     ///
     /// The default value is `0.00385`
     ///
@@ -114,6 +163,96 @@ impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
         self
     }
 
+    /// Sets how much the [`Normal`] value will change for the [`Knob`] per
+    /// pixel scrolled on a trackpad, which reports the exact distance
+    /// scrolled instead of lines.
+    ///
+    /// This can be set to `0.0` to disable trackpad scrolling while still
+    /// allowing the scroll wheel.
+    ///
+    /// The default value is `0.0005`
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn pixel_wheel_scalar(mut self, pixel_wheel_scalar: f32) -> Self {
+        self.pixel_wheel_scalar = pixel_wheel_scalar;
+        self
+    }
+
+    /// Sets a function that will be called when the user grabs the
+    /// [`Knob`] to start changing its value, before any call to
+    /// `on_change`.
+    ///
+    /// This is also called around each keyboard step and double-click
+    /// reset, and once at the start of a scroll wheel gesture. Use it to
+    /// notify a plugin host that an edit has begun, so that automation is
+    /// recorded correctly.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn on_grab<F>(mut self, on_grab: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_grab = Some(Box::new(on_grab));
+        self
+    }
+
+    /// Sets a function that will be called when the user releases the
+    /// [`Knob`] after changing its value.
+    ///
+    /// This is also called around each keyboard step and double-click
+    /// reset. A scroll wheel gesture is released once, with the first event
+    /// that arrives after the wheel has been idle for 300 milliseconds, or
+    /// as soon as the cursor leaves the [`Knob`] or any other input
+    /// arrives. Use it to notify a plugin host that an edit has ended.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn on_release<F>(mut self, on_release: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_release = Some(Box::new(on_release));
+        self
+    }
+
+    /// Enables typing in an exact value for the [`Knob`].
+    ///
+    /// Double-clicking the [`Knob`], clicking it while holding down the
+    /// entry modifier key (`Alt` by default), or pressing `Enter` while it
+    /// has keyboard focus opens a text field over it. Double-clicking no longer
+    /// resets it to its default value. Pressing `Enter` sets the value if `parse`
+    /// returns `Some`, and pressing `Escape` or clicking outside of the
+    /// [`Knob`] closes the text field without changing the value.
+    ///
+    /// `parse` is usually [`ParamRange::parse`] of the range the parameter
+    /// uses, for example `move |text| range.parse(text)`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`ParamRange::parse`]: ../../core/range/trait.ParamRange.html#tymethod.parse
+    pub fn value_entry<F>(mut self, parse: F) -> Self
+    where
+        F: 'static + Fn(&str) -> Option<Normal>,
+    {
+        self.value_entry = Some(Box::new(parse));
+        self
+    }
+
+    /// Sets a function that formats the value of the [`Knob`] for a
+    /// tooltip, which is displayed while the [`Knob`] is hovered or dragged.
+    ///
+    /// `format` is usually [`ParamRange::format`] of the range the
+    /// parameter uses, for example `move |normal| range.format(normal)`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`ParamRange::format`]: ../../core/range/trait.ParamRange.html#tymethod.format
+    pub fn tooltip<F>(mut self, format: F) -> Self
+    where
+        F: 'static + Fn(Normal) -> String,
+    {
+        self.tooltip = Some(Box::new(format));
+        self
+    }
+
     /// Sets the modifier keys of the [`Knob`].
     ///
     /// The default modifier key is `Ctrl`.
@@ -124,6 +263,25 @@ impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the modifier keys that open the value entry when the [`Knob`]
+    /// is clicked while they are held down. This has no effect unless
+    /// [`value_entry`] is set.
+    ///
+    /// Clicking with the same keys as [`modifier_keys`] always starts a
+    /// fine drag instead, and setting no keys disables opening the entry
+    /// with a click. The default entry modifier key is `Alt`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`value_entry`]: #method.value_entry
+    /// [`modifier_keys`]: #method.modifier_keys
+    pub fn entry_modifier_keys(
+        mut self,
+        entry_modifier_keys: keyboard::Modifiers,
+    ) -> Self {
+        self.entry_modifier_keys = entry_modifier_keys;
+        self
+    }
+
     /// Sets the scalar to use when the user drags the knobs while holding down
     /// the modifier key. This is multiplied to the value set by
     /// `Knob::scalar()` (which the default is `0.00385`).
@@ -140,6 +298,78 @@ impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
         self
     }
 
+    /// Sets how much the [`Normal`] value of the [`Knob`] will change when
+    /// an arrow key is pressed while it has keyboard focus.
+    ///
+    /// The default value is `0.01`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn key_step(mut self, key_step: f32) -> Self {
+        self.key_step = key_step;
+        self
+    }
+
+    /// Sets how much the [`Normal`] value of the [`Knob`] will change when
+    /// `PageUp` or `PageDown` is pressed while it has keyboard focus.
+    ///
+    /// The default value is `0.1`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn page_step(mut self, page_step: f32) -> Self {
+        self.page_step = page_step;
+        self
+    }
+
+    /// Makes the [`Knob`] stepped, so that its value snaps to the integer
+    /// values of `range` while it is turned. There is no need to call
+    /// [`State::snap_visible_to`] after every change.
+    ///
+    /// `on_change` is only called when the snapped value changes, and the
+    /// arrow keys move the value by one step.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`State::snap_visible_to`]: struct.State.html#method.snap_visible_to
+    pub fn stepped(mut self, range: IntRange) -> Self {
+        self.steps = Some(steps::Steps::Range(range));
+        self
+    }
+
+    /// Makes the [`Knob`] stepped, so that its value snaps to the closest
+    /// of the given `detents` while it is turned.
+    ///
+    /// `on_change` is only called when the snapped value changes, and the
+    /// arrow keys move the value to the next or previous detent.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn detents(mut self, detents: &'a [Normal]) -> Self {
+        self.steps = Some(steps::Steps::Detents(detents));
+        self
+    }
+
+    /// Sets positions that the [`Knob`] briefly sticks to while it is
+    /// turned, such as the center of a pan control. Unlike
+    /// [`detents`], the [`Knob`] can still be set to any value in between.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`detents`]: #method.detents
+    pub fn snap_points(mut self, snap_points: &'a [Normal]) -> Self {
+        self.snap_points = snap_points;
+        self
+    }
+
+    /// Sets how many pixels the mouse must move past a snap point before
+    /// the [`Knob`] lets go of it.
+    ///
+    /// The default value is `10.0`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn snap_width(mut self, snap_width: f32) -> Self {
+        self.snap_width = snap_width;
+        self
+    }
+
     /// Sets the tick marks to display. Note your [`StyleSheet`] must
     /// also implement `tick_marks_style(&self) -> Option<tick_marks::Style>` for
     /// them to display (which the default style does).
@@ -182,17 +412,189 @@ impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
         self
     }
 
+    /// Sets a modulated value to display as a dot that moves independently
+    /// of the value set by the user. This is meant to be updated every frame
+    /// to show modulation (from an LFO, envelope, etc.) happening in real
+    /// time. Note your [`StyleSheet`] must also implement
+    /// `mod_value_style(&self) -> Option<ModValueStyle>` for it to display
+    /// (which the default style does).
+    ///
+    /// [`StyleSheet`]: ../../style/knob/trait.StyleSheet.html
+    pub fn mod_value(mut self, mod_value: Normal) -> Self {
+        self.mod_value = Some(mod_value);
+        self
+    }
+
+    fn grab(&self, messages: &mut Vec<Message>) {
+        if let Some(on_grab) = &self.on_grab {
+            messages.push((on_grab)());
+        }
+    }
+
+    fn release(&self, messages: &mut Vec<Message>) {
+        if let Some(on_release) = &self.on_release {
+            messages.push((on_release)());
+        }
+    }
+
+    fn snapped(&self, normal: Normal) -> Normal {
+        match &self.steps {
+            Some(steps) => steps.snapped(normal),
+            None => normal,
+        }
+    }
+
+    fn key_normal(&self, key_code: keyboard::KeyCode) -> Option<Normal> {
+        let value = self.state.normal_param.value;
+
+        let normal = focus::key_normal(
+            key_code,
+            &self.state.normal_param,
+            self.key_step,
+            self.page_step,
+        )?;
+
+        match &self.steps {
+            Some(steps) => {
+                Some(steps.step_toward(value, normal)).filter(|n| *n != value)
+            }
+            None => Some(normal),
+        }
+    }
+
+    fn update_value_entry(
+        &mut self,
+        event: keyboard::Event,
+        messages: &mut Vec<Message>,
+    ) {
+        match event {
+            keyboard::Event::KeyPressed { modifiers, .. }
+            | keyboard::Event::KeyReleased { modifiers, .. } => {
+                self.state.pressed_modifiers = modifiers;
+            }
+            _ => {}
+        }
+
+        let text = match &mut self.state.entry_text {
+            Some(text) => text,
+            None => return,
+        };
+
+        match value_entry::update(text, event) {
+            value_entry::Update::Submit => {
+                let normal =
+                    self.value_entry.as_ref().and_then(|parse| parse(text));
+
+                if let Some(normal) = normal {
+                    let normal = self.snapped(normal);
+
+                    self.state.entry_text = None;
+                    self.state.normal_param.value = normal;
+                    self.state.continuous_normal = normal.as_f32();
+
+                    self.grab(messages);
+                    messages.push((self.on_change)(normal));
+                    self.release(messages);
+                }
+            }
+            value_entry::Update::Cancel => {
+                self.state.entry_text = None;
+            }
+            value_entry::Update::Changed | value_entry::Update::Ignored => {}
+        }
+    }
+
+    fn drag(
+        &mut self,
+        messages: &mut Vec<Message>,
+        bounds: &Rectangle,
+        cursor_position: Point,
+        angle_range: &KnobAngleRange,
+    ) {
+        let delta_x = cursor_position.x - self.state.prev_drag_x;
+        let delta_y = cursor_position.y - self.state.prev_drag_y;
+
+        self.state.prev_drag_x = cursor_position.x;
+        self.state.prev_drag_y = cursor_position.y;
+
+        let pixel_delta = match self.drag_mode {
+            DragMode::Vertical => delta_y,
+            DragMode::Horizontal => -delta_x,
+            DragMode::VerticalAndHorizontal => delta_y - delta_x,
+            DragMode::Rotary => {
+                let angle = cursor_angle(bounds, cursor_position);
+
+                self.state.prev_drag_angle = angle;
+
+                self.set_continuous_normal(
+                    messages,
+                    angle_to_normal(angle, angle_range),
+                );
+
+                return;
+            }
+            DragMode::RelativeRotary => {
+                let angle = cursor_angle(bounds, cursor_position);
+
+                let mut angle_delta = angle - self.state.prev_drag_angle;
+
+                if angle_delta > std::f32::consts::PI {
+                    angle_delta -= TWO_PI;
+                } else if angle_delta < -std::f32::consts::PI {
+                    angle_delta += TWO_PI;
+                }
+
+                self.state.prev_drag_angle = angle;
+
+                let angle_span = angle_range.max() - angle_range.min();
+                let radius = bounds.width.min(bounds.height) / 2.0;
+
+                if angle_span > 0.0 && radius > 0.0 {
+                    self.move_virtual_slider(
+                        messages,
+                        -angle_delta / angle_span,
+                        self.snap_width / (radius * angle_span),
+                    );
+                }
+
+                return;
+            }
+        };
+
+        self.move_virtual_slider(
+            messages,
+            pixel_delta * self.scalar,
+            self.snap_width * self.scalar,
+        );
+    }
+
     fn move_virtual_slider(
         &mut self,
         messages: &mut Vec<Message>,
         mut normal_delta: f32,
+        mut snap_capture: f32,
     ) {
         if self.state.pressed_modifiers.matches(self.modifier_keys) {
             normal_delta *= self.modifier_scalar;
+            snap_capture *= self.modifier_scalar;
         }
 
-        let mut normal = self.state.continuous_normal - normal_delta;
+        let normal = snap_points::drag(
+            self.snap_points,
+            snap_capture,
+            &mut self.state.snap_travel,
+            self.state.continuous_normal,
+            -normal_delta,
+        );
+
+        self.set_continuous_normal(messages, normal);
+    }
 
+    fn set_continuous_normal(
+        &mut self,
+        messages: &mut Vec<Message>,
+        mut normal: f32,
+    ) {
         if normal < 0.0 {
             normal = 0.0;
         } else if normal > 1.0 {
@@ -201,12 +603,90 @@ impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
 
         self.state.continuous_normal = normal;
 
-        self.state.normal_param.value = normal.into();
+        let normal = self.snapped(normal.into());
+
+        if self.steps.is_some() && normal == self.state.normal_param.value {
+            return;
+        }
+
+        self.state.normal_param.value = normal;
 
         messages.push((self.on_change)(self.state.normal_param.value));
     }
 }
 
+/// How the movement of the mouse turns a [`Knob`] while it is dragged
+///
+/// [`Knob`]: struct.Knob.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum DragMode {
+    /// Dragging up turns the [`Knob`] up, and dragging down turns it down.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    #[default]
+    Vertical,
+    /// Dragging right turns the [`Knob`] up, and dragging left turns it
+    /// down.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    Horizontal,
+    /// Dragging up or right turns the [`Knob`] up, and dragging down or
+    /// left turns it down.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    VerticalAndHorizontal,
+    /// The angle of the cursor around the center of the [`Knob`] sets its
+    /// value within the [`KnobAngleRange`] of the style. Clicking the
+    /// [`Knob`] turns it to point at the cursor.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`KnobAngleRange`]: ../../core/struct.KnobAngleRange.html
+    Rotary,
+    /// Moving the cursor around the center of the [`Knob`] turns it by the
+    /// same angle. Unlike [`Rotary`], clicking the [`Knob`] does not
+    /// change its value.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`Rotary`]: #variant.Rotary
+    RelativeRotary,
+}
+
+/// Returns the angle of the cursor around the center of `bounds`, where
+/// `0.0` points straight down and the angles rotate clockwise, like in a
+/// [`KnobAngleRange`].
+///
+/// [`KnobAngleRange`]: ../../core/struct.KnobAngleRange.html
+fn cursor_angle(bounds: &Rectangle, cursor_position: Point) -> f32 {
+    let angle = (bounds.center_x() - cursor_position.x)
+        .atan2(cursor_position.y - bounds.center_y());
+
+    if angle < 0.0 {
+        angle + TWO_PI
+    } else {
+        angle
+    }
+}
+
+/// Returns the normalized position of `angle` within `angle_range`. Angles
+/// outside of the range snap to the closest end.
+fn angle_to_normal(angle: f32, angle_range: &KnobAngleRange) -> f32 {
+    let min = angle_range.min();
+    let max = angle_range.max();
+
+    if angle < min || angle > max {
+        let to_min = (min - angle).rem_euclid(TWO_PI);
+        let to_max = (angle - max).rem_euclid(TWO_PI);
+
+        return if to_min < to_max { 0.0 } else { 1.0 };
+    }
+
+    if max > min {
+        (angle - min) / (max - min)
+    } else {
+        0.0
+    }
+}
+
 /// The local state of a [`Knob`].
 ///
 /// [`Knob`]: struct.Knob.html
@@ -217,9 +697,15 @@ pub struct State {
     /// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
     pub normal_param: NormalParam,
     is_dragging: bool,
+    is_focused: bool,
+    entry_text: Option<String>,
+    prev_drag_x: f32,
     prev_drag_y: f32,
+    prev_drag_angle: f32,
     continuous_normal: f32,
+    snap_travel: Option<f32>,
     pressed_modifiers: keyboard::Modifiers,
+    scroll_gesture: scroll::Gesture,
     last_click: Option<mouse::Click>,
     tick_marks_cache: crate::graphics::tick_marks::PrimitiveCache,
     text_marks_cache: crate::graphics::text_marks::PrimitiveCache,
@@ -237,9 +723,15 @@ impl State {
         Self {
             normal_param,
             is_dragging: false,
+            is_focused: false,
+            entry_text: None,
+            prev_drag_x: 0.0,
             prev_drag_y: 0.0,
+            prev_drag_angle: 0.0,
             continuous_normal: normal_param.value.as_f32(),
+            snap_travel: None,
             pressed_modifiers: Default::default(),
+            scroll_gesture: Default::default(),
             last_click: None,
             tick_marks_cache: Default::default(),
             text_marks_cache: Default::default(),
@@ -291,6 +783,28 @@ impl State {
     pub fn is_dragging(&self) -> bool {
         self.is_dragging
     }
+
+    /// Is the text field for typing in an exact value of the [`Knob`]
+    /// currently open?
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn is_entering_value(&self) -> bool {
+        self.entry_text.is_some()
+    }
+}
+
+impl focus::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -323,21 +837,29 @@ where
         event: Event,
         layout: Layout<'_>,
         cursor_position: Point,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
     ) -> event::Status {
+        self.state.scroll_gesture.update(
+            &event,
+            layout.bounds().contains(cursor_position),
+            &self.on_release,
+            messages,
+        );
+
         match event {
             Event::Mouse(mouse_event) => match mouse_event {
                 mouse::Event::CursorMoved { .. } => {
                     if self.state.is_dragging {
-                        let normal_delta = (cursor_position.y
-                            - self.state.prev_drag_y)
-                            * self.scalar;
-
-                        self.state.prev_drag_y = cursor_position.y;
+                        let angle_range = renderer.angle_range(&self.style);
 
-                        self.move_virtual_slider(messages, normal_delta);
+                        self.drag(
+                            messages,
+                            &layout.bounds(),
+                            cursor_position,
+                            &angle_range,
+                        );
 
                         return event::Status::Captured;
                     }
@@ -348,35 +870,35 @@ where
                     }
 
                     if layout.bounds().contains(cursor_position) {
-                        let lines = match delta {
-                            iced_native::mouse::ScrollDelta::Lines {
-                                y,
-                                ..
-                            } => y,
-                            iced_native::mouse::ScrollDelta::Pixels {
-                                y,
-                                ..
-                            } => {
-                                if y > 0.0 {
-                                    1.0
-                                } else if y < 0.0 {
-                                    -1.0
-                                } else {
-                                    0.0
-                                }
-                            }
-                        };
-
-                        if lines != 0.0 {
-                            let normal_delta = -lines * self.wheel_scalar;
+                        let normal_delta = -scroll::vertical(
+                            delta,
+                            self.wheel_scalar,
+                            self.pixel_wheel_scalar,
+                        );
 
-                            self.move_virtual_slider(messages, normal_delta);
+                        if normal_delta != 0.0 {
+                            self.state
+                                .scroll_gesture
+                                .scroll(&self.on_grab, messages);
+                            self.move_virtual_slider(
+                                messages,
+                                normal_delta,
+                                0.0,
+                            );
 
                             return event::Status::Captured;
                         }
                     }
                 }
                 mouse::Event::ButtonPressed(mouse::Button::Left) => {
+                    if self.state.entry_text.is_some() {
+                        if layout.bounds().contains(cursor_position) {
+                            return event::Status::Captured;
+                        }
+
+                        self.state.entry_text = None;
+                    }
+
                     if layout.bounds().contains(cursor_position) {
                         let click = mouse::Click::new(
                             cursor_position,
@@ -384,29 +906,90 @@ where
                         );
 
                         match click.kind() {
+                            mouse::click::Kind::Single
+                                if self.value_entry.is_some()
+                                    && value_entry::is_entry_click(
+                                        self.state.pressed_modifiers,
+                                        self.entry_modifier_keys,
+                                        self.modifier_keys,
+                                    ) =>
+                            {
+                                self.state.entry_text = Some(String::new());
+                            }
                             mouse::click::Kind::Single => {
                                 self.state.is_dragging = true;
+                                self.state.snap_travel = snap_points::held_at(
+                                    self.snap_points,
+                                    self.state.continuous_normal,
+                                );
+                                self.grab(messages);
+                                self.state.prev_drag_x = cursor_position.x;
                                 self.state.prev_drag_y = cursor_position.y;
+                                self.state.prev_drag_angle = cursor_angle(
+                                    &layout.bounds(),
+                                    cursor_position,
+                                );
+
+                                if self.drag_mode == DragMode::Rotary {
+                                    let angle_range =
+                                        renderer.angle_range(&self.style);
+
+                                    self.drag(
+                                        messages,
+                                        &layout.bounds(),
+                                        cursor_position,
+                                        &angle_range,
+                                    );
+                                }
                             }
                             _ => {
                                 self.state.is_dragging = false;
 
-                                self.state.normal_param.value =
-                                    self.state.normal_param.default;
+                                if self.value_entry.is_some() {
+                                    self.state.entry_text = Some(String::new());
+                                } else {
+                                    self.grab(messages);
+
+                                    let default = self.snapped(
+                                        self.state.normal_param.default,
+                                    );
+
+                                    self.state.normal_param.value = default;
+
+                                    messages.push((self.on_change)(
+                                        self.state.normal_param.value,
+                                    ));
 
-                                messages.push((self.on_change)(
-                                    self.state.normal_param.value,
-                                ));
+                                    self.release(messages);
+                                }
                             }
                         }
 
-                        self.state.last_click = Some(click);
+                        // Opening the value entry does not start a
+                        // double-click, so that the click that opens it is
+                        // not counted again.
+                        self.state.last_click = match click.kind() {
+                            mouse::click::Kind::Single
+                                if !self.state.is_dragging =>
+                            {
+                                None
+                            }
+                            _ => Some(click),
+                        };
+                        self.state.is_focused = true;
 
                         return event::Status::Captured;
+                    } else {
+                        self.state.is_focused = false;
                     }
                 }
                 mouse::Event::ButtonReleased(mouse::Button::Left) => {
+                    if self.state.is_dragging {
+                        self.release(messages);
+                    }
+
                     self.state.is_dragging = false;
+                    self.state.snap_travel = None;
                     self.state.continuous_normal =
                         self.state.normal_param.value.as_f32();
 
@@ -414,10 +997,35 @@ where
                 }
                 _ => {}
             },
+            Event::Keyboard(keyboard_event)
+                if self.state.entry_text.is_some() =>
+            {
+                self.update_value_entry(keyboard_event, messages);
+
+                return event::Status::Captured;
+            }
             Event::Keyboard(keyboard_event) => match keyboard_event {
-                keyboard::Event::KeyPressed { modifiers, .. } => {
+                keyboard::Event::KeyPressed {
+                    key_code,
+                    modifiers,
+                } => {
                     self.state.pressed_modifiers = modifiers;
 
+                    if self.state.is_focused {
+                        if key_code == keyboard::KeyCode::Enter
+                            && self.value_entry.is_some()
+                        {
+                            self.state.entry_text = Some(String::new());
+                        } else if let Some(normal) = self.key_normal(key_code) {
+                            self.state.normal_param.value = normal;
+                            self.state.continuous_normal = normal.as_f32();
+
+                            self.grab(messages);
+                            messages.push((self.on_change)(normal));
+                            self.release(messages);
+                        }
+                    }
+
                     return event::Status::Captured;
                 }
                 keyboard::Event::KeyReleased { modifiers, .. } => {
@@ -439,15 +1047,29 @@ where
         _defaults: &Renderer::Defaults,
         layout: Layout<'_>,
         cursor_position: Point,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) -> Renderer::Output {
+        let show_tooltip = (self.state.is_dragging
+            || layout.bounds().contains(cursor_position))
+            && self.state.entry_text.is_none();
+        let tooltip = self
+            .tooltip
+            .as_ref()
+            .filter(|_| show_tooltip)
+            .map(|format| format(self.state.normal_param.value));
+
         renderer.draw(
             layout.bounds(),
             cursor_position,
             self.state.normal_param.value,
             self.state.is_dragging,
+            self.state.is_focused,
+            self.state.entry_text.as_deref(),
+            tooltip.as_deref(),
+            viewport,
             self.mod_range_1,
             self.mod_range_2,
+            self.mod_value,
             self.tick_marks,
             self.text_marks,
             &self.style,
@@ -474,6 +1096,12 @@ pub trait Renderer: iced_native::Renderer {
     /// The style supported by this renderer.
     type Style: Default;
 
+    /// Returns the [`KnobAngleRange`] of the given style, which the rotary
+    /// drag modes use to map the angle of the cursor to a value.
+    ///
+    /// [`KnobAngleRange`]: ../../core/struct.KnobAngleRange.html
+    fn angle_range(&self, style: &Self::Style) -> KnobAngleRange;
+
     /// Draws a [`Knob`].
     ///
     /// It receives:
@@ -481,19 +1109,31 @@ pub trait Renderer: iced_native::Renderer {
     ///   * the current cursor position
     ///   * the current normal of the [`Knob`]
     ///   * whether the knob is currently being dragged
+    ///   * whether the [`Knob`] has keyboard focus
+    ///   * the text typed into the value entry, if it is open
+    ///   * the text of the tooltip to display, if any
+    ///   * the visible bounds that the tooltip must stay inside
+    ///   * any modulation ranges to display
+    ///   * any modulated value to display
     ///   * any tick marks to display
     ///   * any text marks to display
     ///   * the style of the [`Knob`]
     ///
     /// [`Knob`]: struct.Knob.html
+    #[allow(clippy::too_many_arguments)]
     fn draw(
         &mut self,
         bounds: Rectangle,
         cursor_position: Point,
         normal: Normal,
         is_dragging: bool,
+        is_focused: bool,
+        entry_text: Option<&str>,
+        tooltip_text: Option<&str>,
+        viewport: &Rectangle,
         mod_range_1: Option<&ModulationRange>,
         mod_range_2: Option<&ModulationRange>,
+        mod_value: Option<Normal>,
         tick_marks: Option<&tick_marks::Group>,
         text_marks: Option<&text_marks::Group>,
         style: &Self::Style,
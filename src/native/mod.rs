@@ -1,23 +1,67 @@
 //! A renderer-agnostic native GUI runtime for Iced Audio.
 
+pub mod adsr_envelope;
+pub mod bar_meter;
+pub mod envelope_editor;
+pub mod focus;
+pub mod goniometer;
+pub mod h_range_slider;
 pub mod h_slider;
+pub mod keys;
 pub mod knob;
 pub mod mod_range_input;
+pub mod mod_wheel;
+pub mod oscilloscope;
+pub mod par_eq_editor;
 pub mod ramp;
+pub(crate) mod scroll;
+pub(crate) mod snap_points;
+pub mod spectrum_analyzer;
+pub(crate) mod steps;
 pub mod text_marks;
 pub mod tick_marks;
+pub mod v_range_slider;
 pub mod v_slider;
+pub(crate) mod value_entry;
+pub mod waveform_view;
+pub mod wavetable_view;
 pub mod xy_pad;
 
+#[doc(no_inline)]
+pub use adsr_envelope::ADSREnvelope;
+#[doc(no_inline)]
+pub use bar_meter::BarMeter;
+#[doc(no_inline)]
+pub use envelope_editor::EnvelopeEditor;
+#[doc(no_inline)]
+pub use goniometer::Goniometer;
+#[doc(no_inline)]
+pub use h_range_slider::HRangeSlider;
 #[doc(no_inline)]
 pub use h_slider::HSlider;
 #[doc(no_inline)]
+pub use keys::Keys;
+#[doc(no_inline)]
 pub use knob::Knob;
 #[doc(no_inline)]
 pub use mod_range_input::ModRangeInput;
 #[doc(no_inline)]
+pub use mod_wheel::ModWheel;
+#[doc(no_inline)]
+pub use oscilloscope::Oscilloscope;
+#[doc(no_inline)]
+pub use par_eq_editor::ParEqEditor;
+#[doc(no_inline)]
 pub use ramp::Ramp;
 #[doc(no_inline)]
+pub use spectrum_analyzer::SpectrumAnalyzer;
+#[doc(no_inline)]
+pub use v_range_slider::VRangeSlider;
+#[doc(no_inline)]
 pub use v_slider::VSlider;
 #[doc(no_inline)]
+pub use waveform_view::WaveformView;
+#[doc(no_inline)]
+pub use wavetable_view::WavetableView;
+#[doc(no_inline)]
 pub use xy_pad::XYPad;
@@ -12,12 +12,16 @@ use iced_native::{
 use std::hash::Hash;
 
 use crate::core::{Normal, NormalParam};
+use crate::native::{focus, scroll};
 use crate::IntRange;
 
 static DEFAULT_SIZE: u16 = 10;
 static DEFAULT_SCALAR: f32 = 0.00385 / 2.0;
 static DEFAULT_WHEEL_SCALAR: f32 = 0.01 / 2.0;
+static DEFAULT_PIXEL_WHEEL_SCALAR: f32 = 0.00025;
 static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+static DEFAULT_KEY_STEP: f32 = 0.01;
+static DEFAULT_PAGE_STEP: f32 = 0.1;
 
 /// An interactive dot that controls an [`NormalParam`]
 ///
@@ -27,10 +31,16 @@ pub struct ModRangeInput<'a, Message, Renderer: self::Renderer> {
     state: &'a mut State,
     size: Length,
     on_change: Box<dyn Fn(Normal) -> Message>,
+    on_grab: Option<Box<dyn Fn() -> Message>>,
+    on_release: Option<Box<dyn Fn() -> Message>>,
+    tooltip: Option<Box<dyn Fn(Normal) -> String>>,
     scalar: f32,
     wheel_scalar: f32,
+    pixel_wheel_scalar: f32,
     modifier_scalar: f32,
     modifier_keys: keyboard::Modifiers,
+    key_step: f32,
+    page_step: f32,
     style: Renderer::Style,
 }
 
@@ -53,9 +63,15 @@ impl<'a, Message, Renderer: self::Renderer>
             state,
             size: Length::from(Length::Units(DEFAULT_SIZE)),
             on_change: Box::new(on_change),
+            on_grab: None,
+            on_release: None,
+            tooltip: None,
             scalar: DEFAULT_SCALAR,
             wheel_scalar: DEFAULT_WHEEL_SCALAR,
+            pixel_wheel_scalar: DEFAULT_PIXEL_WHEEL_SCALAR,
             modifier_scalar: DEFAULT_MODIFIER_SCALAR,
+            key_step: DEFAULT_KEY_STEP,
+            page_step: DEFAULT_PAGE_STEP,
             modifier_keys: keyboard::Modifiers {
                 control: true,
                 ..Default::default()
@@ -107,6 +123,75 @@ impl<'a, Message, Renderer: self::Renderer>
         self
     }
 
+    /// Sets how much the [`Normal`] value will change for the
+    /// [`ModRangeInput`] per pixel scrolled on a trackpad, which reports the
+    /// exact distance scrolled instead of lines.
+    ///
+    /// This can be set to `0.0` to disable trackpad scrolling while still
+    /// allowing the scroll wheel.
+    ///
+    /// The default value is `0.00025`
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn pixel_wheel_scalar(mut self, pixel_wheel_scalar: f32) -> Self {
+        self.pixel_wheel_scalar = pixel_wheel_scalar;
+        self
+    }
+
+    /// Sets a function that will be called when the user grabs the
+    /// [`ModRangeInput`] to start changing its value, before any call to
+    /// `on_change`.
+    ///
+    /// This is also called around each keyboard step and double-click
+    /// reset, and once at the start of a scroll wheel gesture. Use it to
+    /// notify a plugin host that an edit has begun, so that automation is
+    /// recorded correctly.
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    pub fn on_grab<F>(mut self, on_grab: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_grab = Some(Box::new(on_grab));
+        self
+    }
+
+    /// Sets a function that will be called when the user releases the
+    /// [`ModRangeInput`] after changing its value.
+    ///
+    /// This is also called around each keyboard step and double-click
+    /// reset. A scroll wheel gesture is released once, with the first event
+    /// that arrives after the wheel has been idle for 300 milliseconds, or
+    /// as soon as the cursor leaves the [`ModRangeInput`] or any other input
+    /// arrives. Use it to notify a plugin host that an edit has ended.
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    pub fn on_release<F>(mut self, on_release: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_release = Some(Box::new(on_release));
+        self
+    }
+
+    /// Sets a function that formats the value of the [`ModRangeInput`] for
+    /// a tooltip, which is displayed while the [`ModRangeInput`] is hovered
+    /// or dragged.
+    ///
+    /// `format` is usually [`ParamRange::format`] of the range the
+    /// parameter uses, for example `move |normal| range.format(normal)`.
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    /// [`ParamRange::format`]: ../../core/range/trait.ParamRange.html#tymethod.format
+    pub fn tooltip<F>(mut self, format: F) -> Self
+    where
+        F: 'static + Fn(Normal) -> String,
+    {
+        self.tooltip = Some(Box::new(format));
+        self
+    }
+
     /// Sets the modifier keys of the [`ModRangeInput`].
     ///
     /// The default modifier key is `Ctrl`.
@@ -133,6 +218,43 @@ impl<'a, Message, Renderer: self::Renderer>
         self
     }
 
+    /// Sets how much the [`Normal`] value of the [`ModRangeInput`] will
+    /// change when an arrow key is pressed while it has keyboard focus.
+    ///
+    /// The default value is `0.01`.
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn key_step(mut self, key_step: f32) -> Self {
+        self.key_step = key_step;
+        self
+    }
+
+    /// Sets how much the [`Normal`] value of the [`ModRangeInput`] will
+    /// change when `PageUp` or `PageDown` is pressed while it has keyboard
+    /// focus.
+    ///
+    /// The default value is `0.1`.
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn page_step(mut self, page_step: f32) -> Self {
+        self.page_step = page_step;
+        self
+    }
+
+    fn grab(&self, messages: &mut Vec<Message>) {
+        if let Some(on_grab) = &self.on_grab {
+            messages.push((on_grab)());
+        }
+    }
+
+    fn release(&self, messages: &mut Vec<Message>) {
+        if let Some(on_release) = &self.on_release {
+            messages.push((on_release)());
+        }
+    }
+
     fn move_virtual_slider(
         &mut self,
         messages: &mut Vec<Message>,
@@ -165,9 +287,11 @@ impl<'a, Message, Renderer: self::Renderer>
 pub struct State {
     normal_param: NormalParam,
     is_dragging: bool,
+    is_focused: bool,
     prev_drag_y: f32,
     continuous_normal: f32,
     pressed_modifiers: keyboard::Modifiers,
+    scroll_gesture: scroll::Gesture,
     last_click: Option<mouse::Click>,
 }
 
@@ -183,9 +307,11 @@ impl State {
         Self {
             normal_param,
             is_dragging: false,
+            is_focused: false,
             prev_drag_y: 0.0,
             continuous_normal: normal_param.value.as_f32(),
             pressed_modifiers: Default::default(),
+            scroll_gesture: Default::default(),
             last_click: None,
         }
     }
@@ -237,6 +363,20 @@ impl State {
     }
 }
 
+impl focus::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
 impl<'a, Message, Renderer> Widget<Message, Renderer>
     for ModRangeInput<'a, Message, Renderer>
 where
@@ -271,6 +411,13 @@ where
         _clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
     ) -> event::Status {
+        self.state.scroll_gesture.update(
+            &event,
+            layout.bounds().contains(cursor_position),
+            &self.on_release,
+            messages,
+        );
+
         match event {
             Event::Mouse(mouse_event) => match mouse_event {
                 mouse::Event::CursorMoved { .. } => {
@@ -292,28 +439,16 @@ where
                     }
 
                     if layout.bounds().contains(cursor_position) {
-                        let lines = match delta {
-                            iced_native::mouse::ScrollDelta::Lines {
-                                y,
-                                ..
-                            } => y,
-                            iced_native::mouse::ScrollDelta::Pixels {
-                                y,
-                                ..
-                            } => {
-                                if y > 0.0 {
-                                    1.0
-                                } else if y < 0.0 {
-                                    -1.0
-                                } else {
-                                    0.0
-                                }
-                            }
-                        };
-
-                        if lines != 0.0 {
-                            let normal_delta = -lines * self.wheel_scalar;
+                        let normal_delta = -scroll::vertical(
+                            delta,
+                            self.wheel_scalar,
+                            self.pixel_wheel_scalar,
+                        );
 
+                        if normal_delta != 0.0 {
+                            self.state
+                                .scroll_gesture
+                                .scroll(&self.on_grab, messages);
                             self.move_virtual_slider(messages, normal_delta);
 
                             return event::Status::Captured;
@@ -330,26 +465,38 @@ where
                         match click.kind() {
                             mouse::click::Kind::Single => {
                                 self.state.is_dragging = true;
+                                self.grab(messages);
                                 self.state.prev_drag_y = cursor_position.y;
                             }
                             _ => {
                                 self.state.is_dragging = false;
 
+                                self.grab(messages);
+
                                 self.state.normal_param.value =
                                     self.state.normal_param.default;
 
                                 messages.push((self.on_change)(
                                     self.state.normal_param.value,
                                 ));
+
+                                self.release(messages);
                             }
                         }
 
                         self.state.last_click = Some(click);
+                        self.state.is_focused = true;
 
                         return event::Status::Captured;
+                    } else {
+                        self.state.is_focused = false;
                     }
                 }
                 mouse::Event::ButtonReleased(mouse::Button::Left) => {
+                    if self.state.is_dragging {
+                        self.release(messages);
+                    }
+
                     self.state.is_dragging = false;
                     self.state.continuous_normal =
                         self.state.normal_param.value.as_f32();
@@ -359,9 +506,28 @@ where
                 _ => {}
             },
             Event::Keyboard(keyboard_event) => match keyboard_event {
-                keyboard::Event::KeyPressed { modifiers, .. } => {
+                keyboard::Event::KeyPressed {
+                    key_code,
+                    modifiers,
+                } => {
                     self.state.pressed_modifiers = modifiers;
 
+                    if self.state.is_focused {
+                        if let Some(normal) = focus::key_normal(
+                            key_code,
+                            &self.state.normal_param,
+                            self.key_step,
+                            self.page_step,
+                        ) {
+                            self.state.normal_param.value = normal;
+                            self.state.continuous_normal = normal.as_f32();
+
+                            self.grab(messages);
+                            messages.push((self.on_change)(normal));
+                            self.release(messages);
+                        }
+                    }
+
                     return event::Status::Captured;
                 }
                 keyboard::Event::KeyReleased { modifiers, .. } => {
@@ -383,12 +549,23 @@ where
         _defaults: &Renderer::Defaults,
         layout: Layout<'_>,
         cursor_position: Point,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) -> Renderer::Output {
+        let show_tooltip =
+            self.state.is_dragging || layout.bounds().contains(cursor_position);
+        let tooltip = self
+            .tooltip
+            .as_ref()
+            .filter(|_| show_tooltip)
+            .map(|format| format(self.state.normal_param.value));
+
         renderer.draw(
             layout.bounds(),
             cursor_position,
             self.state.is_dragging,
+            self.state.is_focused,
+            tooltip.as_deref(),
+            viewport,
             &self.style,
         )
     }
@@ -417,14 +594,21 @@ pub trait Renderer: iced_native::Renderer {
     ///   * the bounds of the [`ModRangeInput`]
     ///   * the current cursor position
     ///   * whether the ModRangeInput is currently being dragged
+    ///   * whether the [`ModRangeInput`] has keyboard focus
+    ///   * the text of the tooltip to display, if any
+    ///   * the visible bounds that the tooltip must stay inside
     ///   * the style of the [`ModRangeInput`]
     ///
     /// [`ModRangeInput`]: struct.ModRangeInput.html
+    #[allow(clippy::too_many_arguments)]
     fn draw(
         &mut self,
         bounds: Rectangle,
         cursor_position: Point,
         is_dragging: bool,
+        is_focused: bool,
+        tooltip_text: Option<&str>,
+        viewport: &Rectangle,
         style: &Self::Style,
     ) -> Self::Output;
 }
@@ -0,0 +1,696 @@
+//! Display an interactive mod wheel that controls a [`NormalParam`]. It can
+//! also act as a pitch wheel that springs back to its default value when it
+//! is released.
+//!
+//! [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
+
+use std::fmt::Debug;
+use std::time::{Duration, Instant};
+
+use iced_native::{
+    event, keyboard, layout, mouse, Clipboard, Element, Event, Hasher, Layout,
+    Length, Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+use crate::core::{Normal, NormalParam};
+use crate::native::{focus, scroll};
+
+static DEFAULT_WIDTH: u16 = 24;
+static DEFAULT_HEIGHT: u16 = 100;
+static DEFAULT_SCALAR: f32 = 0.9575;
+static DEFAULT_WHEEL_SCALAR: f32 = 0.01;
+static DEFAULT_PIXEL_WHEEL_SCALAR: f32 = 0.0005;
+static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+static DEFAULT_KEY_STEP: f32 = 0.01;
+static DEFAULT_PAGE_STEP: f32 = 0.1;
+static DEFAULT_RETURN_MILLIS: u64 = 150;
+
+/// The mode of a [`ModWheel`].
+///
+/// [`ModWheel`]: struct.ModWheel.html
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum WheelMode {
+    /// The wheel stays where it is released.
+    #[default]
+    Mod,
+    /// The wheel springs back to the default value of its [`NormalParam`]
+    /// when it is released.
+    ///
+    /// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+    Pitch,
+}
+
+/// A mod wheel GUI widget that controls a [`NormalParam`]. In
+/// `WheelMode::Pitch` it springs back to its default value when it is
+/// released. The spring-back must be driven by a time subscription that
+/// calls [`State::animate`].
+///
+/// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+/// [`ModWheel`]: struct.ModWheel.html
+/// [`State::animate`]: struct.State.html#method.animate
+#[allow(missing_debug_implementations)]
+pub struct ModWheel<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    on_change: Box<dyn Fn(Normal) -> Message>,
+    tooltip: Option<Box<dyn Fn(Normal) -> String>>,
+    mode: WheelMode,
+    scalar: f32,
+    wheel_scalar: f32,
+    pixel_wheel_scalar: f32,
+    modifier_scalar: f32,
+    modifier_keys: keyboard::Modifiers,
+    key_step: f32,
+    page_step: f32,
+    width: Length,
+    height: Length,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer> ModWheel<'a, Message, Renderer> {
+    /// Creates a new [`ModWheel`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`ModWheel`]
+    ///   * a function that will be called when the [`ModWheel`] is dragged
+    ///     or springs back.
+    ///
+    /// [`State`]: struct.State.html
+    /// [`ModWheel`]: struct.ModWheel.html
+    pub fn new<F>(state: &'a mut State, on_change: F) -> Self
+    where
+        F: 'static + Fn(Normal) -> Message,
+    {
+        ModWheel {
+            state,
+            on_change: Box::new(on_change),
+            tooltip: None,
+            mode: WheelMode::default(),
+            scalar: DEFAULT_SCALAR,
+            wheel_scalar: DEFAULT_WHEEL_SCALAR,
+            pixel_wheel_scalar: DEFAULT_PIXEL_WHEEL_SCALAR,
+            modifier_scalar: DEFAULT_MODIFIER_SCALAR,
+            modifier_keys: keyboard::Modifiers {
+                control: true,
+                ..Default::default()
+            },
+            key_step: DEFAULT_KEY_STEP,
+            page_step: DEFAULT_PAGE_STEP,
+            width: Length::Units(DEFAULT_WIDTH),
+            height: Length::Units(DEFAULT_HEIGHT),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the [`WheelMode`] of the [`ModWheel`].
+    ///
+    /// The default mode is `WheelMode::Mod`.
+    ///
+    /// [`WheelMode`]: enum.WheelMode.html
+    /// [`ModWheel`]: struct.ModWheel.html
+    pub fn mode(mut self, mode: WheelMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets a function that formats the value of the [`ModWheel`] for a
+    /// tooltip, which is displayed while the [`ModWheel`] is hovered or
+    /// dragged.
+    ///
+    /// `format` is usually [`ParamRange::format`] of the range the
+    /// parameter uses, for example `move |normal| range.format(normal)`.
+    ///
+    /// [`ModWheel`]: struct.ModWheel.html
+    /// [`ParamRange::format`]: ../../core/range/trait.ParamRange.html#tymethod.format
+    pub fn tooltip<F>(mut self, format: F) -> Self
+    where
+        F: 'static + Fn(Normal) -> String,
+    {
+        self.tooltip = Some(Box::new(format));
+        self
+    }
+
+    /// Sets the width of the [`ModWheel`].
+    /// The default width is `Length::from(Length::Units(24))`.
+    ///
+    /// [`ModWheel`]: struct.ModWheel.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`ModWheel`].
+    /// The default height is `Length::from(Length::Units(100))`.
+    ///
+    /// [`ModWheel`]: struct.ModWheel.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`ModWheel`].
+    ///
+    /// [`ModWheel`]: struct.ModWheel.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the modifier keys of the [`ModWheel`].
+    ///
+    /// The default modifier key is `Ctrl`.
+    ///
+    /// [`ModWheel`]: struct.ModWheel.html
+    pub fn modifier_keys(mut self, modifier_keys: keyboard::Modifiers) -> Self {
+        self.modifier_keys = modifier_keys;
+        self
+    }
+
+    /// Sets the scalar to use when the user drags the wheel per pixel.
+    ///
+    /// For example, a scalar of `0.5` will cause the wheel to move half a
+    /// pixel for every pixel the mouse moves.
+    ///
+    /// The default scalar is `0.9575`.
+    ///
+    /// [`ModWheel`]: struct.ModWheel.html
+    pub fn scalar(mut self, scalar: f32) -> Self {
+        self.scalar = scalar;
+        self
+    }
+
+    /// Sets how much the [`Normal`] value will change for the [`ModWheel`]
+    /// per line scrolled by the mouse wheel.
+    ///
+    /// This can be set to `0.0` to disable the scroll wheel from moving the
+    /// parameter. The scroll wheel is always disabled in
+    /// `WheelMode::Pitch`.
+    ///
+    /// The default value is `0.01`
+    ///
+    /// [`ModWheel`]: struct.ModWheel.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn wheel_scalar(mut self, wheel_scalar: f32) -> Self {
+        self.wheel_scalar = wheel_scalar;
+        self
+    }
+
+    /// Sets how much the [`Normal`] value will change for the [`ModWheel`] per
+    /// pixel scrolled on a trackpad, which reports the exact distance
+    /// scrolled instead of lines.
+    ///
+    /// This can be set to `0.0` to disable trackpad scrolling while still
+    /// allowing the scroll wheel.
+    ///
+    /// The default value is `0.0005`
+    ///
+    /// [`ModWheel`]: struct.ModWheel.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn pixel_wheel_scalar(mut self, pixel_wheel_scalar: f32) -> Self {
+        self.pixel_wheel_scalar = pixel_wheel_scalar;
+        self
+    }
+
+    /// Sets the scalar to use when the user drags the wheel while holding
+    /// down the modifier key.
+    ///
+    /// For example, a scalar of `0.5` will cause the wheel to move half a
+    /// pixel for every pixel the mouse moves.
+    ///
+    /// The default scalar is `0.02`, and the default modifier key is `Ctrl`.
+    ///
+    /// [`ModWheel`]: struct.ModWheel.html
+    pub fn modifier_scalar(mut self, scalar: f32) -> Self {
+        self.modifier_scalar = scalar;
+        self
+    }
+
+    /// Sets how much the [`Normal`] value of the [`ModWheel`] will change
+    /// when an arrow key is pressed while it has keyboard focus.
+    ///
+    /// The keys work in both modes. A pitch wheel stays where the keys put
+    /// it, and `Delete` returns it to its default value.
+    ///
+    /// The default value is `0.01`.
+    ///
+    /// [`ModWheel`]: struct.ModWheel.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn key_step(mut self, key_step: f32) -> Self {
+        self.key_step = key_step;
+        self
+    }
+
+    /// Sets how much the [`Normal`] value of the [`ModWheel`] will change
+    /// when `PageUp` or `PageDown` is pressed while it has keyboard focus.
+    ///
+    /// The default value is `0.1`.
+    ///
+    /// [`ModWheel`]: struct.ModWheel.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn page_step(mut self, page_step: f32) -> Self {
+        self.page_step = page_step;
+        self
+    }
+
+    fn move_virtual_slider(
+        &mut self,
+        messages: &mut Vec<Message>,
+        mut normal_delta: f32,
+    ) {
+        if self.state.pressed_modifiers.matches(self.modifier_keys) {
+            normal_delta *= self.modifier_scalar;
+        }
+
+        let normal =
+            (self.state.continuous_normal - normal_delta).clamp(0.0, 1.0);
+
+        self.state.continuous_normal = normal;
+
+        self.state.normal_param.value = normal.into();
+
+        messages.push((self.on_change)(self.state.normal_param.value));
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct SpringBack {
+    from: f32,
+    start: Instant,
+}
+
+/// The local state of a [`ModWheel`].
+///
+/// In `WheelMode::Pitch`, the wheel springs back to its default value over
+/// a short time after it is released. The widget only advances the return
+/// when it receives an event, so a time subscription must drive it: while
+/// [`is_returning`] is `true`, subscribe to a timer that ticks every frame
+/// (for example `iced::time::every(Duration::from_millis(16))`) and call
+/// [`animate`] on each tick. The widget does not send its `on_change`
+/// message for the values returned by [`animate`], so forward them to your
+/// parameter the same way you handle `on_change`.
+///
+/// [`ModWheel`]: struct.ModWheel.html
+/// [`animate`]: struct.State.html#method.animate
+/// [`is_returning`]: struct.State.html#method.is_returning
+#[derive(Debug, Copy, Clone)]
+pub struct State {
+    normal_param: NormalParam,
+    is_dragging: bool,
+    prev_drag_y: f32,
+    continuous_normal: f32,
+    pressed_modifiers: keyboard::Modifiers,
+    last_click: Option<mouse::Click>,
+    is_focused: bool,
+    return_duration: Duration,
+    spring_back: Option<SpringBack>,
+}
+
+impl State {
+    /// Creates a new [`ModWheel`] state.
+    ///
+    /// It expects:
+    /// * a [`NormalParam`] to assign to this widget. For a pitch wheel, the
+    ///   default value is usually `Normal::center()`.
+    ///
+    /// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+    /// [`ModWheel`]: struct.ModWheel.html
+    pub fn new(normal_param: NormalParam) -> Self {
+        Self {
+            normal_param,
+            is_dragging: false,
+            prev_drag_y: 0.0,
+            continuous_normal: normal_param.value.as_f32(),
+            pressed_modifiers: Default::default(),
+            last_click: None,
+            is_focused: false,
+            return_duration: Duration::from_millis(DEFAULT_RETURN_MILLIS),
+            spring_back: None,
+        }
+    }
+
+    /// Set the normalized value of the [`ModWheel`].
+    ///
+    /// [`ModWheel`]: struct.ModWheel.html
+    pub fn set_normal(&mut self, normal: Normal) {
+        self.normal_param.value = normal;
+        self.continuous_normal = normal.into();
+        self.spring_back = None;
+    }
+
+    /// Get the normalized value of the [`ModWheel`].
+    ///
+    /// [`ModWheel`]: struct.ModWheel.html
+    pub fn normal(&self) -> Normal {
+        self.normal_param.value
+    }
+
+    /// Set the normalized default value of the [`ModWheel`].
+    ///
+    /// [`ModWheel`]: struct.ModWheel.html
+    pub fn set_default(&mut self, normal: Normal) {
+        self.normal_param.default = normal;
+    }
+
+    /// Get the normalized default value of the [`ModWheel`].
+    ///
+    /// [`ModWheel`]: struct.ModWheel.html
+    pub fn default(&self) -> Normal {
+        self.normal_param.default
+    }
+
+    /// Sets how long it takes for a pitch wheel to spring back to its
+    /// default value after it is released.
+    ///
+    /// The default is 150 milliseconds.
+    pub fn set_return_duration(&mut self, return_duration: Duration) {
+        self.return_duration = return_duration;
+    }
+
+    /// Is the [`ModWheel`] currently in the dragging state?
+    ///
+    /// [`ModWheel`]: struct.ModWheel.html
+    pub fn is_dragging(&self) -> bool {
+        self.is_dragging
+    }
+
+    /// Is the [`ModWheel`] currently springing back to its default value?
+    ///
+    /// [`ModWheel`]: struct.ModWheel.html
+    pub fn is_returning(&self) -> bool {
+        self.spring_back.is_some()
+    }
+
+    /// Advances the spring-back of a pitch wheel. Returns the new
+    /// normalized value if the wheel is springing back, and `None`
+    /// otherwise.
+    ///
+    /// Call this on every tick of a time subscription while
+    /// [`is_returning`] is `true`, and update your parameter with the
+    /// returned value as if it came from `on_change`. The last value
+    /// returned is always the default value.
+    ///
+    /// [`is_returning`]: struct.State.html#method.is_returning
+    pub fn animate(&mut self) -> Option<Normal> {
+        let spring_back = self.spring_back?;
+
+        let duration = self.return_duration.as_secs_f32();
+        let elapsed = spring_back.start.elapsed().as_secs_f32();
+
+        let target = self.normal_param.default.as_f32();
+
+        let normal = if duration <= 0.0 || elapsed >= duration {
+            self.spring_back = None;
+            target
+        } else {
+            // Ease out so the wheel slows down as it reaches the center.
+            let t = elapsed / duration;
+            let eased = 1.0 - ((1.0 - t) * (1.0 - t));
+
+            spring_back.from + ((target - spring_back.from) * eased)
+        };
+
+        self.normal_param.value = normal.into();
+        self.continuous_normal = normal;
+
+        Some(self.normal_param.value)
+    }
+
+    fn start_spring_back(&mut self) {
+        self.spring_back = Some(SpringBack {
+            from: self.normal_param.value.as_f32(),
+            start: Instant::now(),
+        });
+    }
+}
+
+impl focus::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for ModWheel<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        if let Some(normal) = self.state.animate() {
+            messages.push((self.on_change)(normal));
+        }
+
+        match event {
+            Event::Mouse(mouse_event) => match mouse_event {
+                mouse::Event::CursorMoved { .. } if self.state.is_dragging => {
+                    let bounds_height = layout.bounds().height;
+
+                    if bounds_height > 0.0 {
+                        let normal_delta = (cursor_position.y
+                            - self.state.prev_drag_y)
+                            / bounds_height
+                            * self.scalar;
+
+                        self.state.prev_drag_y = cursor_position.y;
+
+                        self.move_virtual_slider(messages, normal_delta);
+
+                        return event::Status::Captured;
+                    }
+                }
+                mouse::Event::WheelScrolled { delta } => {
+                    if self.wheel_scalar == 0.0 || self.mode == WheelMode::Pitch
+                    {
+                        return event::Status::Ignored;
+                    }
+
+                    if layout.bounds().contains(cursor_position) {
+                        let normal_delta = -scroll::vertical(
+                            delta,
+                            self.wheel_scalar,
+                            self.pixel_wheel_scalar,
+                        );
+
+                        if normal_delta != 0.0 {
+                            self.move_virtual_slider(messages, normal_delta);
+
+                            return event::Status::Captured;
+                        }
+                    }
+                }
+                mouse::Event::ButtonPressed(mouse::Button::Left) => {
+                    if layout.bounds().contains(cursor_position) {
+                        let click = mouse::Click::new(
+                            cursor_position,
+                            self.state.last_click,
+                        );
+
+                        self.state.spring_back = None;
+                        self.state.continuous_normal =
+                            self.state.normal_param.value.as_f32();
+
+                        match click.kind() {
+                            mouse::click::Kind::Single => {
+                                self.state.is_dragging = true;
+                                self.state.prev_drag_y = cursor_position.y;
+                            }
+                            _ => {
+                                self.state.is_dragging = false;
+
+                                self.state.normal_param.value =
+                                    self.state.normal_param.default;
+                                self.state.continuous_normal =
+                                    self.state.normal_param.value.as_f32();
+
+                                messages.push((self.on_change)(
+                                    self.state.normal_param.value,
+                                ));
+                            }
+                        }
+
+                        self.state.last_click = Some(click);
+                        self.state.is_focused = true;
+
+                        return event::Status::Captured;
+                    } else {
+                        self.state.is_focused = false;
+                    }
+                }
+                mouse::Event::ButtonReleased(mouse::Button::Left) => {
+                    if self.state.is_dragging && self.mode == WheelMode::Pitch {
+                        self.state.start_spring_back();
+                    }
+
+                    self.state.is_dragging = false;
+                    self.state.continuous_normal =
+                        self.state.normal_param.value.as_f32();
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            },
+            Event::Keyboard(keyboard_event) => match keyboard_event {
+                keyboard::Event::KeyPressed {
+                    key_code,
+                    modifiers,
+                } => {
+                    self.state.pressed_modifiers = modifiers;
+
+                    if self.state.is_focused {
+                        if let Some(normal) = focus::key_normal(
+                            key_code,
+                            &self.state.normal_param,
+                            self.key_step,
+                            self.page_step,
+                        ) {
+                            self.state.spring_back = None;
+                            self.state.normal_param.value = normal;
+                            self.state.continuous_normal = normal.as_f32();
+
+                            messages.push((self.on_change)(normal));
+                        }
+                    }
+
+                    return event::Status::Captured;
+                }
+                keyboard::Event::KeyReleased { modifiers, .. } => {
+                    self.state.pressed_modifiers = modifiers;
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let show_tooltip =
+            self.state.is_dragging || layout.bounds().contains(cursor_position);
+        let tooltip = self
+            .tooltip
+            .as_ref()
+            .filter(|_| show_tooltip)
+            .map(|format| format(self.state.normal_param.value));
+
+        renderer.draw(
+            layout.bounds(),
+            cursor_position,
+            self.state.normal_param.value,
+            self.state.normal_param.default,
+            self.mode,
+            self.state.is_dragging,
+            self.state.is_focused,
+            tooltip.as_deref(),
+            viewport,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`ModWheel`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`ModWheel`] in your user interface.
+///
+/// [`ModWheel`]: struct.ModWheel.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`ModWheel`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`ModWheel`]
+    ///   * the current cursor position
+    ///   * the current normal of the [`ModWheel`]
+    ///   * the default normal of the [`ModWheel`]
+    ///   * the mode of the [`ModWheel`]
+    ///   * whether the wheel is currently being dragged
+    ///   * whether the [`ModWheel`] has keyboard focus
+    ///   * the text of the tooltip to display, if any
+    ///   * the visible bounds that the tooltip must stay inside
+    ///   * the style of the [`ModWheel`]
+    ///
+    /// [`ModWheel`]: struct.ModWheel.html
+    #[allow(clippy::too_many_arguments)]
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        normal: Normal,
+        default: Normal,
+        mode: WheelMode,
+        is_dragging: bool,
+        is_focused: bool,
+        tooltip_text: Option<&str>,
+        viewport: &Rectangle,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<ModWheel<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        mod_wheel: ModWheel<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(mod_wheel)
+    }
+}
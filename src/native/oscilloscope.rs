@@ -0,0 +1,371 @@
+//! Display an oscilloscope that displays the waveform of an audio signal in
+//! a given time window.
+
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::time::Duration;
+
+use iced_native::{
+    event, layout, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+static DEFAULT_WIDTH: u16 = 200;
+static DEFAULT_HEIGHT: u16 = 100;
+
+/// The trigger mode of an [`Oscilloscope`].
+///
+/// [`Oscilloscope`]: struct.Oscilloscope.html
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum TriggerMode {
+    /// Always display the most recent samples. Periodic signals will appear
+    /// to drift.
+    FreeRun,
+    /// Start the displayed window where the signal crosses the trigger
+    /// level while rising.
+    #[default]
+    RisingEdge,
+    /// Start the displayed window where the signal crosses the trigger
+    /// level while falling.
+    FallingEdge,
+}
+
+/// An oscilloscope GUI widget that displays the waveform of an audio signal
+/// in a given time window.
+///
+/// [`Oscilloscope`]: struct.Oscilloscope.html
+#[allow(missing_debug_implementations)]
+pub struct Oscilloscope<'a, Renderer: self::Renderer> {
+    state: &'a mut State,
+    width: Length,
+    height: Length,
+    style: Renderer::Style,
+}
+
+impl<'a, Renderer: self::Renderer> Oscilloscope<'a, Renderer> {
+    /// Creates a new [`Oscilloscope`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`Oscilloscope`]
+    ///
+    /// [`State`]: struct.State.html
+    /// [`Oscilloscope`]: struct.Oscilloscope.html
+    pub fn new(state: &'a mut State) -> Self {
+        Oscilloscope {
+            state,
+            width: Length::Units(DEFAULT_WIDTH),
+            height: Length::Units(DEFAULT_HEIGHT),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`Oscilloscope`].
+    /// The default width is `Length::from(Length::Units(200))`.
+    ///
+    /// [`Oscilloscope`]: struct.Oscilloscope.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Oscilloscope`].
+    /// The default height is `Length::from(Length::Units(100))`.
+    ///
+    /// [`Oscilloscope`]: struct.Oscilloscope.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`Oscilloscope`].
+    ///
+    /// [`Oscilloscope`]: struct.Oscilloscope.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The local state of an [`Oscilloscope`].
+///
+/// The state holds a ring buffer of the most recent samples. Push new
+/// samples into it with [`push_samples`] every time new audio is available.
+///
+/// [`Oscilloscope`]: struct.Oscilloscope.html
+/// [`push_samples`]: struct.State.html#method.push_samples
+#[derive(Debug, Clone)]
+pub struct State {
+    buffer: VecDeque<f32>,
+    window_len: usize,
+    trigger_mode: TriggerMode,
+    trigger_level: f32,
+}
+
+impl State {
+    /// Creates a new [`Oscilloscope`] state.
+    ///
+    /// It expects:
+    /// * the number of samples to display in the time window
+    ///
+    /// [`Oscilloscope`]: struct.Oscilloscope.html
+    pub fn new(window_len: usize) -> Self {
+        let window_len = window_len.max(2);
+
+        Self {
+            buffer: VecDeque::with_capacity(window_len * 2),
+            window_len,
+            trigger_mode: TriggerMode::default(),
+            trigger_level: 0.0,
+        }
+    }
+
+    /// Creates a new [`Oscilloscope`] state from a time window.
+    ///
+    /// It expects:
+    /// * the sample rate of the signal
+    /// * the length of the time window to display
+    ///
+    /// [`Oscilloscope`]: struct.Oscilloscope.html
+    pub fn from_time_window(sample_rate: f32, window: Duration) -> Self {
+        Self::new((sample_rate * window.as_secs_f32()).round() as usize)
+    }
+
+    /// Sets the number of samples to display in the time window.
+    pub fn set_window_len(&mut self, window_len: usize) {
+        self.window_len = window_len.max(2);
+
+        let capacity = self.capacity();
+        while self.buffer.len() > capacity {
+            let _ = self.buffer.pop_front();
+        }
+    }
+
+    /// Sets the length of the time window to display.
+    pub fn set_time_window(&mut self, sample_rate: f32, window: Duration) {
+        self.set_window_len(
+            (sample_rate * window.as_secs_f32()).round() as usize
+        );
+    }
+
+    /// Returns the number of samples displayed in the time window.
+    pub fn window_len(&self) -> usize {
+        self.window_len
+    }
+
+    /// Sets the [`TriggerMode`] of the [`Oscilloscope`].
+    ///
+    /// The default is `TriggerMode::RisingEdge`.
+    ///
+    /// [`TriggerMode`]: enum.TriggerMode.html
+    /// [`Oscilloscope`]: struct.Oscilloscope.html
+    pub fn set_trigger_mode(&mut self, trigger_mode: TriggerMode) {
+        self.trigger_mode = trigger_mode;
+    }
+
+    /// Returns the [`TriggerMode`] of the [`Oscilloscope`].
+    ///
+    /// [`TriggerMode`]: enum.TriggerMode.html
+    /// [`Oscilloscope`]: struct.Oscilloscope.html
+    pub fn trigger_mode(&self) -> TriggerMode {
+        self.trigger_mode
+    }
+
+    /// Sets the level (between `-1.0` and `1.0`) the signal must cross for
+    /// the [`Oscilloscope`] to trigger.
+    ///
+    /// The default is `0.0`.
+    ///
+    /// [`Oscilloscope`]: struct.Oscilloscope.html
+    pub fn set_trigger_level(&mut self, trigger_level: f32) {
+        self.trigger_level = trigger_level;
+    }
+
+    /// Pushes new samples into the ring buffer. The oldest samples are
+    /// discarded.
+    pub fn push_samples(&mut self, samples: &[f32]) {
+        let capacity = self.capacity();
+
+        let samples = if samples.len() > capacity {
+            &samples[samples.len() - capacity..]
+        } else {
+            samples
+        };
+
+        while self.buffer.len() + samples.len() > capacity {
+            let _ = self.buffer.pop_front();
+        }
+
+        self.buffer.extend(samples.iter().copied());
+    }
+
+    /// Replaces all samples in the ring buffer with the given slice.
+    pub fn set_samples(&mut self, samples: &[f32]) {
+        self.buffer.clear();
+        self.push_samples(samples);
+    }
+
+    /// Clears all samples in the ring buffer.
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+    }
+
+    fn capacity(&self) -> usize {
+        // Keep an extra window worth of samples to search for a trigger.
+        self.window_len * 2
+    }
+
+    /// Returns the samples in the displayed time window, taking the
+    /// [`TriggerMode`] into account.
+    ///
+    /// [`TriggerMode`]: enum.TriggerMode.html
+    pub fn window(&self) -> Vec<f32> {
+        let len = self.buffer.len();
+
+        if len <= self.window_len {
+            return self.buffer.iter().copied().collect();
+        }
+
+        let latest_start = len - self.window_len;
+
+        let start = match self.trigger_mode {
+            TriggerMode::FreeRun => latest_start,
+            TriggerMode::RisingEdge => self
+                .find_trigger(latest_start, |prev, next, level| {
+                    prev < level && next >= level
+                })
+                .unwrap_or(latest_start),
+            TriggerMode::FallingEdge => self
+                .find_trigger(latest_start, |prev, next, level| {
+                    prev > level && next <= level
+                })
+                .unwrap_or(latest_start),
+        };
+
+        self.buffer
+            .iter()
+            .skip(start)
+            .take(self.window_len)
+            .copied()
+            .collect()
+    }
+
+    /// Searches backwards from `latest_start` for the most recent trigger
+    /// point that still leaves a full window of samples after it.
+    fn find_trigger<F: Fn(f32, f32, f32) -> bool>(
+        &self,
+        latest_start: usize,
+        is_trigger: F,
+    ) -> Option<usize> {
+        (1..=latest_start).rev().find(|i| {
+            is_trigger(self.buffer[i - 1], self.buffer[*i], self.trigger_level)
+        })
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Oscilloscope<'a, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        _event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        _messages: &mut Vec<Message>,
+    ) -> event::Status {
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        renderer.draw(
+            layout.bounds(),
+            cursor_position,
+            &self.state.window(),
+            self.state.window_len,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of an [`Oscilloscope`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use an [`Oscilloscope`] in your user interface.
+///
+/// [`Oscilloscope`]: struct.Oscilloscope.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws an [`Oscilloscope`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Oscilloscope`]
+    ///   * the current cursor position
+    ///   * the samples in the displayed time window
+    ///   * the number of samples that fit in the time window
+    ///   * the style of the [`Oscilloscope`]
+    ///
+    /// [`Oscilloscope`]: struct.Oscilloscope.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        samples: &[f32],
+        window_len: usize,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Oscilloscope<'a, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        oscilloscope: Oscilloscope<'a, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(oscilloscope)
+    }
+}
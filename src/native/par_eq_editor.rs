@@ -0,0 +1,980 @@
+//! Display a parametric equalizer editor with draggable bands and the
+//! combined frequency response of all the bands.
+
+use std::f32::consts::PI;
+use std::fmt::Debug;
+
+use iced_native::{
+    event, keyboard, layout, mouse, Clipboard, Element, Event, Hasher, Layout,
+    Length, Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+use crate::core::{FloatRange, FreqRange, LogDBRange, Normal};
+use crate::native::scroll;
+
+static DEFAULT_WIDTH: u16 = 400;
+static DEFAULT_HEIGHT: u16 = 160;
+static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+static DEFAULT_Q_WHEEL_SCALAR: f32 = 0.1;
+static DEFAULT_HIT_RADIUS: f32 = 7.0;
+static DEFAULT_Q: f32 = std::f32::consts::FRAC_1_SQRT_2;
+static DEFAULT_MIN_Q: f32 = 0.1;
+static DEFAULT_MAX_Q: f32 = 18.0;
+static DEFAULT_MAX_BANDS: usize = 8;
+static DEFAULT_SAMPLE_RATE: f32 = 48_000.0;
+
+/// The pixel spacing between the points the response curve is computed at
+static CURVE_RESOLUTION: f32 = 2.0;
+
+/// The type of filter of a [`Band`].
+///
+/// [`Band`]: struct.Band.html
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum FilterType {
+    /// A peaking filter that boosts or cuts around the frequency
+    #[default]
+    Bell,
+    /// A shelving filter that boosts or cuts below the frequency
+    LowShelf,
+    /// A shelving filter that boosts or cuts above the frequency
+    HighShelf,
+    /// A filter that removes frequencies above the frequency
+    LowPass,
+    /// A filter that removes frequencies below the frequency
+    HighPass,
+    /// A filter that removes a narrow range around the frequency
+    Notch,
+}
+
+impl FilterType {
+    /// Returns `true` if the gain of the [`Band`] has an effect on this type
+    /// of filter.
+    ///
+    /// [`Band`]: struct.Band.html
+    pub fn has_gain(&self) -> bool {
+        match self {
+            FilterType::Bell | FilterType::LowShelf | FilterType::HighShelf => {
+                true
+            }
+            FilterType::LowPass | FilterType::HighPass | FilterType::Notch => {
+                false
+            }
+        }
+    }
+}
+
+/// A band of a [`ParEqEditor`].
+///
+/// [`ParEqEditor`]: struct.ParEqEditor.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Band {
+    /// The type of filter
+    pub filter_type: FilterType,
+    /// The frequency of the band, mapped with the [`FreqRange`] of the
+    /// [`State`]
+    ///
+    /// [`FreqRange`]: ../../core/range/struct.FreqRange.html
+    /// [`State`]: struct.State.html
+    pub frequency: Normal,
+    /// The gain of the band, mapped with the [`GainRange`] of the
+    /// [`State`]. This is ignored by filter types without a gain.
+    ///
+    /// [`GainRange`]: enum.GainRange.html
+    /// [`State`]: struct.State.html
+    pub gain: Normal,
+    /// The Q (quality factor) of the band
+    pub q: f32,
+    /// Whether the band is bypassed. Bypassed bands are not included in the
+    /// response curve.
+    pub bypassed: bool,
+}
+
+impl Band {
+    /// Creates a new [`Band`] with a Q of `0.707`.
+    ///
+    /// [`Band`]: struct.Band.html
+    pub fn new(
+        filter_type: FilterType,
+        frequency: Normal,
+        gain: Normal,
+    ) -> Self {
+        Self {
+            filter_type,
+            frequency,
+            gain,
+            q: DEFAULT_Q,
+            bypassed: false,
+        }
+    }
+
+    /// Returns the vertical position of the handle of this [`Band`]. Filter
+    /// types without a gain are placed on the `0 dB` line.
+    ///
+    /// [`Band`]: struct.Band.html
+    pub fn handle_y(&self, zero_gain: Normal) -> Normal {
+        if self.filter_type.has_gain() {
+            self.gain
+        } else {
+            zero_gain
+        }
+    }
+}
+
+/// The range used to map the gain of the bands in a [`ParEqEditor`].
+///
+/// [`ParEqEditor`]: struct.ParEqEditor.html
+#[derive(Debug, Copy, Clone)]
+pub enum GainRange {
+    /// A linear range of `dB` values
+    Linear(FloatRange),
+    /// A logarithmic range of `dB` values
+    LogDB(LogDBRange),
+}
+
+impl GainRange {
+    /// Returns the corresponding [`Normal`] from the supplied `dB` value
+    ///
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn map_to_normal(&self, value: f32) -> Normal {
+        match self {
+            GainRange::Linear(range) => range.map_to_normal(value),
+            GainRange::LogDB(range) => range.map_to_normal(value),
+        }
+    }
+
+    /// Returns the corresponding `dB` value from the supplied [`Normal`]
+    ///
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn unmap_to_value(&self, normal: Normal) -> f32 {
+        match self {
+            GainRange::Linear(range) => range.unmap_to_value(normal),
+            GainRange::LogDB(range) => range.unmap_to_value(normal),
+        }
+    }
+}
+
+impl Default for GainRange {
+    fn default() -> Self {
+        GainRange::Linear(FloatRange::new(-18.0, 18.0))
+    }
+}
+
+impl From<FloatRange> for GainRange {
+    fn from(range: FloatRange) -> Self {
+        GainRange::Linear(range)
+    }
+}
+
+impl From<LogDBRange> for GainRange {
+    fn from(range: LogDBRange) -> Self {
+        GainRange::LogDB(range)
+    }
+}
+
+/// An edit made by the user in a [`ParEqEditor`].
+///
+/// [`ParEqEditor`]: struct.ParEqEditor.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Edit {
+    /// A band was added by double-clicking on an empty space. It is always
+    /// added to the end of the list of bands.
+    BandAdded {
+        /// The index of the new band
+        index: usize,
+        /// The new band
+        band: Band,
+    },
+    /// A band was removed by right-clicking on it.
+    BandRemoved {
+        /// The index of the removed band
+        index: usize,
+    },
+    /// A band was dragged.
+    BandMoved {
+        /// The index of the band
+        index: usize,
+        /// The new frequency of the band
+        frequency: Normal,
+        /// The new gain of the band
+        gain: Normal,
+    },
+    /// The Q of a band was changed with the scroll wheel.
+    QChanged {
+        /// The index of the band
+        index: usize,
+        /// The new Q of the band
+        q: f32,
+    },
+    /// A band was bypassed or un-bypassed by double-clicking on it.
+    BypassChanged {
+        /// The index of the band
+        index: usize,
+        /// Whether the band is now bypassed
+        bypassed: bool,
+    },
+}
+
+/// A parametric equalizer GUI widget with draggable bands.
+///
+/// * Drag a band to change its frequency and gain.
+/// * Scroll over a band to change its Q.
+/// * Double-click on an empty space to add a bell band.
+/// * Double-click on a band to bypass or un-bypass it.
+/// * Right-click on a band to remove it.
+///
+/// [`ParEqEditor`]: struct.ParEqEditor.html
+#[allow(missing_debug_implementations)]
+pub struct ParEqEditor<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    on_edit: Box<dyn Fn(Edit) -> Message>,
+    modifier_scalar: f32,
+    modifier_keys: keyboard::Modifiers,
+    q_wheel_scalar: f32,
+    hit_radius: f32,
+    width: Length,
+    height: Length,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer> ParEqEditor<'a, Message, Renderer> {
+    /// Creates a new [`ParEqEditor`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`ParEqEditor`]
+    ///   * a function that will be called with each [`Edit`] made by the
+    ///     user
+    ///
+    /// [`State`]: struct.State.html
+    /// [`Edit`]: enum.Edit.html
+    /// [`ParEqEditor`]: struct.ParEqEditor.html
+    pub fn new<F>(state: &'a mut State, on_edit: F) -> Self
+    where
+        F: 'static + Fn(Edit) -> Message,
+    {
+        ParEqEditor {
+            state,
+            on_edit: Box::new(on_edit),
+            modifier_scalar: DEFAULT_MODIFIER_SCALAR,
+            modifier_keys: keyboard::Modifiers {
+                control: true,
+                ..Default::default()
+            },
+            q_wheel_scalar: DEFAULT_Q_WHEEL_SCALAR,
+            hit_radius: DEFAULT_HIT_RADIUS,
+            width: Length::Units(DEFAULT_WIDTH),
+            height: Length::Units(DEFAULT_HEIGHT),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`ParEqEditor`].
+    /// The default width is `Length::from(Length::Units(400))`.
+    ///
+    /// [`ParEqEditor`]: struct.ParEqEditor.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`ParEqEditor`].
+    /// The default height is `Length::from(Length::Units(160))`.
+    ///
+    /// [`ParEqEditor`]: struct.ParEqEditor.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`ParEqEditor`].
+    ///
+    /// [`ParEqEditor`]: struct.ParEqEditor.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the modifier keys of the [`ParEqEditor`].
+    ///
+    /// The default modifier key is `Ctrl`.
+    ///
+    /// [`ParEqEditor`]: struct.ParEqEditor.html
+    pub fn modifier_keys(mut self, modifier_keys: keyboard::Modifiers) -> Self {
+        self.modifier_keys = modifier_keys;
+        self
+    }
+
+    /// Sets the scalar to use when the user drags a band or scrolls over a
+    /// band while holding down the modifier key.
+    ///
+    /// For example, a `modifier_scalar` of `0.5` will cause bands to move
+    /// half as fast when the modifier key is down.
+    ///
+    /// The default `modifier_scalar` is `0.02`, and the default modifier key
+    /// is `Ctrl`.
+    pub fn modifier_scalar(mut self, scalar: f32) -> Self {
+        self.modifier_scalar = scalar;
+        self
+    }
+
+    /// Sets how much the Q of a band will change per line scrolled by the
+    /// mouse wheel. The Q is multiplied by `1.0 + q_wheel_scalar` for each
+    /// line scrolled up, and divided by it for each line scrolled down.
+    ///
+    /// This can be set to `0.0` to disable the scroll wheel from changing
+    /// the Q.
+    ///
+    /// The default value is `0.1`
+    pub fn q_wheel_scalar(mut self, scalar: f32) -> Self {
+        self.q_wheel_scalar = scalar;
+        self
+    }
+
+    /// Sets the distance in pixels from the center of a band handle within
+    /// which the band can be grabbed, scrolled, or removed.
+    ///
+    /// The default value is `7.0`
+    pub fn hit_radius(mut self, hit_radius: f32) -> Self {
+        self.hit_radius = hit_radius;
+        self
+    }
+
+    fn band_at(&self, bounds: &Rectangle, cursor: Point) -> Option<usize> {
+        let hit_radius_squared = self.hit_radius * self.hit_radius;
+        let zero_gain = self.state.zero_gain();
+
+        self.state
+            .bands
+            .iter()
+            .enumerate()
+            .map(|(i, band)| {
+                let dx =
+                    bounds.x + band.frequency.scale(bounds.width) - cursor.x;
+                let dy = bounds.y
+                    + band.handle_y(zero_gain).scale_inv(bounds.height)
+                    - cursor.y;
+
+                (i, (dx * dx) + (dy * dy))
+            })
+            .filter(|(_, distance)| *distance <= hit_radius_squared)
+            .min_by(|(_, a), (_, b)| {
+                a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|(i, _)| i)
+    }
+}
+
+/// The local state of a [`ParEqEditor`].
+///
+/// [`ParEqEditor`]: struct.ParEqEditor.html
+#[derive(Debug, Clone)]
+pub struct State {
+    bands: Vec<Band>,
+    freq_range: FreqRange,
+    gain_range: GainRange,
+    q_range: (f32, f32),
+    max_bands: usize,
+    sample_rate: f32,
+    dragged_band: Option<usize>,
+    prev_drag_x: f32,
+    prev_drag_y: f32,
+    continuous_x: f32,
+    continuous_y: f32,
+    pressed_modifiers: keyboard::Modifiers,
+    last_click: Option<mouse::Click>,
+}
+
+impl State {
+    /// Creates a new [`ParEqEditor`] state.
+    ///
+    /// It expects:
+    /// * the bands of the equalizer
+    /// * the [`FreqRange`] of the frequency axis
+    /// * the [`GainRange`] of the gain axis. This can be a [`FloatRange`]
+    ///   or a [`LogDBRange`].
+    ///
+    /// [`ParEqEditor`]: struct.ParEqEditor.html
+    /// [`FreqRange`]: ../../core/range/struct.FreqRange.html
+    /// [`FloatRange`]: ../../core/range/struct.FloatRange.html
+    /// [`LogDBRange`]: ../../core/range/struct.LogDBRange.html
+    /// [`GainRange`]: enum.GainRange.html
+    pub fn new(
+        bands: Vec<Band>,
+        freq_range: FreqRange,
+        gain_range: impl Into<GainRange>,
+    ) -> Self {
+        Self {
+            bands,
+            freq_range,
+            gain_range: gain_range.into(),
+            q_range: (DEFAULT_MIN_Q, DEFAULT_MAX_Q),
+            max_bands: DEFAULT_MAX_BANDS,
+            sample_rate: DEFAULT_SAMPLE_RATE,
+            dragged_band: None,
+            prev_drag_x: 0.0,
+            prev_drag_y: 0.0,
+            continuous_x: 0.0,
+            continuous_y: 0.0,
+            pressed_modifiers: Default::default(),
+            last_click: None,
+        }
+    }
+
+    /// Replaces all the bands of the equalizer.
+    pub fn set_bands(&mut self, bands: Vec<Band>) {
+        self.bands = bands;
+        self.dragged_band = None;
+    }
+
+    /// Returns the bands of the equalizer.
+    pub fn bands(&self) -> &[Band] {
+        &self.bands
+    }
+
+    /// Replaces the band with the given index. This can be used to change
+    /// the filter type of a band, or to bypass it.
+    ///
+    /// This does nothing if there is no band with the given index.
+    pub fn set_band(&mut self, index: usize, band: Band) {
+        if let Some(b) = self.bands.get_mut(index) {
+            *b = band;
+        }
+    }
+
+    /// Returns the [`FreqRange`] of the frequency axis.
+    ///
+    /// [`FreqRange`]: ../../core/range/struct.FreqRange.html
+    pub fn freq_range(&self) -> &FreqRange {
+        &self.freq_range
+    }
+
+    /// Returns the [`GainRange`] of the gain axis.
+    ///
+    /// [`GainRange`]: enum.GainRange.html
+    pub fn gain_range(&self) -> &GainRange {
+        &self.gain_range
+    }
+
+    /// Sets the minimum and maximum Q the user can set with the scroll
+    /// wheel.
+    ///
+    /// The default range is `0.1` to `18.0`.
+    pub fn set_q_range(&mut self, min: f32, max: f32) {
+        self.q_range = (min, max);
+    }
+
+    /// Sets the maximum number of bands. Bands cannot be added by the user
+    /// past this number.
+    ///
+    /// The default is `8`.
+    pub fn set_max_bands(&mut self, max_bands: usize) {
+        self.max_bands = max_bands;
+    }
+
+    /// Sets the sample rate used to compute the response curve.
+    ///
+    /// The default is `48000.0`.
+    pub fn set_sample_rate(&mut self, sample_rate: f32) {
+        self.sample_rate = sample_rate;
+    }
+
+    /// Returns the index of the band that is currently being dragged, if
+    /// any.
+    pub fn dragged_band(&self) -> Option<usize> {
+        self.dragged_band
+    }
+
+    /// Returns the combined response in `dB` of all the bands that are not
+    /// bypassed at the given frequency.
+    pub fn response_db(&self, frequency: f32) -> f32 {
+        self.bands
+            .iter()
+            .filter(|band| !band.bypassed)
+            .map(|band| self.response_of(band, frequency))
+            .sum()
+    }
+
+    /// Returns the response in `dB` of a single band at the given
+    /// frequency. The band is included even if it is bypassed.
+    ///
+    /// This returns `0.0` if there is no band with the given index.
+    pub fn band_response_db(&self, index: usize, frequency: f32) -> f32 {
+        if let Some(band) = self.bands.get(index) {
+            self.response_of(band, frequency)
+        } else {
+            0.0
+        }
+    }
+
+    fn response_of(&self, band: &Band, frequency: f32) -> f32 {
+        let coefficients = Coefficients::new(
+            band.filter_type,
+            self.freq_range.unmap_to_value(band.frequency),
+            self.gain_range.unmap_to_value(band.gain),
+            band.q,
+            self.sample_rate,
+        );
+
+        coefficients.magnitude_db(frequency, self.sample_rate)
+    }
+
+    fn zero_gain(&self) -> Normal {
+        self.gain_range.map_to_normal(0.0)
+    }
+
+    fn response_curve(&self, width: f32) -> Vec<Normal> {
+        let num_points = ((width / CURVE_RESOLUTION).ceil() as usize).max(2);
+        let last = (num_points - 1) as f32;
+
+        (0..num_points)
+            .map(|i| {
+                let frequency =
+                    self.freq_range.unmap_to_value((i as f32 / last).into());
+
+                self.gain_range.map_to_normal(self.response_db(frequency))
+            })
+            .collect()
+    }
+}
+
+/// The coefficients of a biquad filter, computed with the formulas from
+/// the "Audio EQ Cookbook" by Robert Bristow-Johnson.
+struct Coefficients {
+    b0: f32,
+    b1: f32,
+    b2: f32,
+    a0: f32,
+    a1: f32,
+    a2: f32,
+}
+
+impl Coefficients {
+    fn new(
+        filter_type: FilterType,
+        frequency: f32,
+        gain_db: f32,
+        q: f32,
+        sample_rate: f32,
+    ) -> Self {
+        // Keep the frequency below the Nyquist frequency so the filter
+        // stays stable.
+        let frequency = frequency.min(sample_rate * 0.499);
+        let q = q.max(0.001);
+
+        let w0 = 2.0 * PI * frequency / sample_rate;
+        let cos_w0 = w0.cos();
+        let alpha = w0.sin() / (2.0 * q);
+        let a = 10.0_f32.powf(gain_db / 40.0);
+
+        match filter_type {
+            FilterType::Bell => Self {
+                b0: 1.0 + (alpha * a),
+                b1: -2.0 * cos_w0,
+                b2: 1.0 - (alpha * a),
+                a0: 1.0 + (alpha / a),
+                a1: -2.0 * cos_w0,
+                a2: 1.0 - (alpha / a),
+            },
+            FilterType::LowShelf => {
+                let sqrt_a_alpha = 2.0 * a.sqrt() * alpha;
+
+                Self {
+                    b0: a * ((a + 1.0) - ((a - 1.0) * cos_w0) + sqrt_a_alpha),
+                    b1: 2.0 * a * ((a - 1.0) - ((a + 1.0) * cos_w0)),
+                    b2: a * ((a + 1.0) - ((a - 1.0) * cos_w0) - sqrt_a_alpha),
+                    a0: (a + 1.0) + ((a - 1.0) * cos_w0) + sqrt_a_alpha,
+                    a1: -2.0 * ((a - 1.0) + ((a + 1.0) * cos_w0)),
+                    a2: (a + 1.0) + ((a - 1.0) * cos_w0) - sqrt_a_alpha,
+                }
+            }
+            FilterType::HighShelf => {
+                let sqrt_a_alpha = 2.0 * a.sqrt() * alpha;
+
+                Self {
+                    b0: a * ((a + 1.0) + ((a - 1.0) * cos_w0) + sqrt_a_alpha),
+                    b1: -2.0 * a * ((a - 1.0) + ((a + 1.0) * cos_w0)),
+                    b2: a * ((a + 1.0) + ((a - 1.0) * cos_w0) - sqrt_a_alpha),
+                    a0: (a + 1.0) - ((a - 1.0) * cos_w0) + sqrt_a_alpha,
+                    a1: 2.0 * ((a - 1.0) - ((a + 1.0) * cos_w0)),
+                    a2: (a + 1.0) - ((a - 1.0) * cos_w0) - sqrt_a_alpha,
+                }
+            }
+            FilterType::LowPass => Self {
+                b0: (1.0 - cos_w0) / 2.0,
+                b1: 1.0 - cos_w0,
+                b2: (1.0 - cos_w0) / 2.0,
+                a0: 1.0 + alpha,
+                a1: -2.0 * cos_w0,
+                a2: 1.0 - alpha,
+            },
+            FilterType::HighPass => Self {
+                b0: (1.0 + cos_w0) / 2.0,
+                b1: -(1.0 + cos_w0),
+                b2: (1.0 + cos_w0) / 2.0,
+                a0: 1.0 + alpha,
+                a1: -2.0 * cos_w0,
+                a2: 1.0 - alpha,
+            },
+            FilterType::Notch => Self {
+                b0: 1.0,
+                b1: -2.0 * cos_w0,
+                b2: 1.0,
+                a0: 1.0 + alpha,
+                a1: -2.0 * cos_w0,
+                a2: 1.0 - alpha,
+            },
+        }
+    }
+
+    fn magnitude_db(&self, frequency: f32, sample_rate: f32) -> f32 {
+        let w = 2.0 * PI * frequency / sample_rate;
+
+        let (sin_w, cos_w) = w.sin_cos();
+        let (sin_2w, cos_2w) = (2.0 * w).sin_cos();
+
+        let num_re = self.b0 + (self.b1 * cos_w) + (self.b2 * cos_2w);
+        let num_im = (self.b1 * sin_w) + (self.b2 * sin_2w);
+        let den_re = self.a0 + (self.a1 * cos_w) + (self.a2 * cos_2w);
+        let den_im = (self.a1 * sin_w) + (self.a2 * sin_2w);
+
+        let num = (num_re * num_re) + (num_im * num_im);
+        let den = (den_re * den_re) + (den_im * den_im);
+
+        if num <= 0.0 || den <= 0.0 {
+            // A notch filter at exactly its center frequency
+            return f32::NEG_INFINITY;
+        }
+
+        10.0 * (num / den).log10()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for ParEqEditor<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse_event) => match mouse_event {
+                mouse::Event::CursorMoved { .. } => {
+                    if bounds.width <= 0.0 || bounds.height <= 0.0 {
+                        return event::Status::Ignored;
+                    }
+
+                    if let Some(index) = self.state.dragged_band {
+                        let mut movement_x = (cursor_position.x
+                            - self.state.prev_drag_x)
+                            / bounds.width;
+                        let mut movement_y = (cursor_position.y
+                            - self.state.prev_drag_y)
+                            / bounds.height;
+
+                        if self
+                            .state
+                            .pressed_modifiers
+                            .matches(self.modifier_keys)
+                        {
+                            movement_x *= self.modifier_scalar;
+                            movement_y *= self.modifier_scalar;
+                        }
+
+                        self.state.prev_drag_x = cursor_position.x;
+                        self.state.prev_drag_y = cursor_position.y;
+
+                        let x = (self.state.continuous_x + movement_x)
+                            .clamp(0.0, 1.0);
+                        let y = (self.state.continuous_y - movement_y)
+                            .clamp(0.0, 1.0);
+
+                        self.state.continuous_x = x;
+                        self.state.continuous_y = y;
+
+                        let band = &mut self.state.bands[index];
+                        band.frequency = x.into();
+                        if band.filter_type.has_gain() {
+                            band.gain = y.into();
+                        }
+
+                        messages.push((self.on_edit)(Edit::BandMoved {
+                            index,
+                            frequency: band.frequency,
+                            gain: band.gain,
+                        }));
+
+                        return event::Status::Captured;
+                    }
+                }
+                mouse::Event::WheelScrolled { delta } => {
+                    if self.q_wheel_scalar == 0.0 {
+                        return event::Status::Ignored;
+                    }
+
+                    let index = if let Some(index) = self.state.dragged_band {
+                        index
+                    } else if let Some(index) =
+                        self.band_at(&bounds, cursor_position)
+                    {
+                        index
+                    } else {
+                        return event::Status::Ignored;
+                    };
+
+                    let (_, lines) = scroll::lines(delta);
+
+                    if lines != 0.0 {
+                        let mut scalar = self.q_wheel_scalar;
+
+                        if self
+                            .state
+                            .pressed_modifiers
+                            .matches(self.modifier_keys)
+                        {
+                            scalar *= self.modifier_scalar;
+                        }
+
+                        let (min_q, max_q) = self.state.q_range;
+
+                        let band = &mut self.state.bands[index];
+                        band.q = (band.q * (1.0 + scalar).powf(lines))
+                            .max(min_q)
+                            .min(max_q);
+
+                        messages.push((self.on_edit)(Edit::QChanged {
+                            index,
+                            q: band.q,
+                        }));
+
+                        return event::Status::Captured;
+                    }
+                }
+                mouse::Event::ButtonPressed(mouse::Button::Left)
+                    if bounds.contains(cursor_position) =>
+                {
+                    let click = mouse::Click::new(
+                        cursor_position,
+                        self.state.last_click,
+                    );
+
+                    self.state.prev_drag_x = cursor_position.x;
+                    self.state.prev_drag_y = cursor_position.y;
+
+                    let band_index = self.band_at(&bounds, cursor_position);
+
+                    match click.kind() {
+                        mouse::click::Kind::Single => {
+                            if let Some(index) = band_index {
+                                let band = self.state.bands[index];
+
+                                self.state.dragged_band = Some(index);
+                                self.state.continuous_x =
+                                    band.frequency.as_f32();
+                                self.state.continuous_y = band.gain.as_f32();
+                            }
+                        }
+                        _ => {
+                            self.state.dragged_band = None;
+
+                            if let Some(index) = band_index {
+                                let band = &mut self.state.bands[index];
+                                band.bypassed = !band.bypassed;
+
+                                messages.push((self.on_edit)(
+                                    Edit::BypassChanged {
+                                        index,
+                                        bypassed: band.bypassed,
+                                    },
+                                ));
+                            } else if self.state.bands.len()
+                                < self.state.max_bands
+                                && bounds.width > 0.0
+                                && bounds.height > 0.0
+                            {
+                                let band = Band::new(
+                                    FilterType::Bell,
+                                    ((cursor_position.x - bounds.x)
+                                        / bounds.width)
+                                        .into(),
+                                    (1.0 - ((cursor_position.y - bounds.y)
+                                        / bounds.height))
+                                        .into(),
+                                );
+
+                                self.state.bands.push(band);
+
+                                messages.push((self.on_edit)(
+                                    Edit::BandAdded {
+                                        index: self.state.bands.len() - 1,
+                                        band,
+                                    },
+                                ));
+                            }
+                        }
+                    }
+
+                    self.state.last_click = Some(click);
+
+                    return event::Status::Captured;
+                }
+                mouse::Event::ButtonPressed(mouse::Button::Right)
+                    if bounds.contains(cursor_position)
+                        && self.state.dragged_band.is_none() =>
+                {
+                    if let Some(index) = self.band_at(&bounds, cursor_position)
+                    {
+                        let _ = self.state.bands.remove(index);
+
+                        messages
+                            .push((self.on_edit)(Edit::BandRemoved { index }));
+
+                        return event::Status::Captured;
+                    }
+                }
+                mouse::Event::ButtonReleased(mouse::Button::Left)
+                    if self.state.dragged_band.is_some() =>
+                {
+                    self.state.dragged_band = None;
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            },
+            Event::Keyboard(keyboard_event) => match keyboard_event {
+                keyboard::Event::KeyPressed { modifiers, .. } => {
+                    self.state.pressed_modifiers = modifiers;
+
+                    return event::Status::Captured;
+                }
+                keyboard::Event::KeyReleased { modifiers, .. } => {
+                    self.state.pressed_modifiers = modifiers;
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+
+        let hovered_band = if self.state.dragged_band.is_none() {
+            self.band_at(&bounds, cursor_position)
+        } else {
+            None
+        };
+
+        let response = self.state.response_curve(bounds.width);
+
+        renderer.draw(
+            bounds,
+            cursor_position,
+            &self.state.bands,
+            &response,
+            self.state.zero_gain(),
+            hovered_band,
+            self.state.dragged_band,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`ParEqEditor`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`ParEqEditor`] in your user interface.
+///
+/// [`ParEqEditor`]: struct.ParEqEditor.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`ParEqEditor`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`ParEqEditor`]
+    ///   * the current cursor position
+    ///   * the bands of the equalizer
+    ///   * the combined response of the bands as gain normals, evenly
+    ///     spaced from the left edge to the right edge of the bounds
+    ///   * the gain normal of `0 dB`
+    ///   * the index of the band under the cursor, if any
+    ///   * the index of the band that is being dragged, if any
+    ///   * the style of the [`ParEqEditor`]
+    ///
+    /// [`ParEqEditor`]: struct.ParEqEditor.html
+    #[allow(clippy::too_many_arguments)]
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        bands: &[Band],
+        response: &[Normal],
+        zero_gain: Normal,
+        hovered_band: Option<usize>,
+        dragged_band: Option<usize>,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<ParEqEditor<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        par_eq_editor: ParEqEditor<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(par_eq_editor)
+    }
+}
@@ -13,13 +13,17 @@ use iced_native::{
 use std::hash::Hash;
 
 use crate::core::{Normal, NormalParam};
+use crate::native::{focus, scroll};
 use crate::IntRange;
 
 static DEFAULT_WIDTH: u16 = 40;
 static DEFAULT_HEIGHT: u16 = 20;
 static DEFAULT_SCALAR: f32 = 0.00385;
 static DEFAULT_WHEEL_SCALAR: f32 = 0.01;
+static DEFAULT_PIXEL_WHEEL_SCALAR: f32 = 0.0005;
 static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+static DEFAULT_KEY_STEP: f32 = 0.01;
+static DEFAULT_PAGE_STEP: f32 = 0.1;
 
 /// The direction of a [`Ramp`] widget.
 #[derive(Debug, Copy, Clone)]
@@ -45,10 +49,16 @@ impl Default for RampDirection {
 pub struct Ramp<'a, Message, Renderer: self::Renderer> {
     state: &'a mut State,
     on_change: Box<dyn Fn(Normal) -> Message>,
+    on_grab: Option<Box<dyn Fn() -> Message>>,
+    on_release: Option<Box<dyn Fn() -> Message>>,
+    tooltip: Option<Box<dyn Fn(Normal) -> String>>,
     scalar: f32,
     wheel_scalar: f32,
+    pixel_wheel_scalar: f32,
     modifier_scalar: f32,
     modifier_keys: keyboard::Modifiers,
+    key_step: f32,
+    page_step: f32,
     width: Length,
     height: Length,
     style: Renderer::Style,
@@ -79,9 +89,15 @@ impl<'a, Message, Renderer: self::Renderer> Ramp<'a, Message, Renderer> {
         Ramp {
             state,
             on_change: Box::new(on_change),
+            on_grab: None,
+            on_release: None,
+            tooltip: None,
             scalar: DEFAULT_SCALAR,
             wheel_scalar: DEFAULT_WHEEL_SCALAR,
+            pixel_wheel_scalar: DEFAULT_PIXEL_WHEEL_SCALAR,
             modifier_scalar: DEFAULT_MODIFIER_SCALAR,
+            key_step: DEFAULT_KEY_STEP,
+            page_step: DEFAULT_PAGE_STEP,
             modifier_keys: keyboard::Modifiers {
                 control: true,
                 ..Default::default()
@@ -145,6 +161,74 @@ impl<'a, Message, Renderer: self::Renderer> Ramp<'a, Message, Renderer> {
         self
     }
 
+    /// Sets how much the [`Normal`] value will change for the [`Ramp`] per
+    /// pixel scrolled on a trackpad, which reports the exact distance
+    /// scrolled instead of lines.
+    ///
+    /// This can be set to `0.0` to disable trackpad scrolling while still
+    /// allowing the scroll wheel.
+    ///
+    /// The default value is `0.0005`
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn pixel_wheel_scalar(mut self, pixel_wheel_scalar: f32) -> Self {
+        self.pixel_wheel_scalar = pixel_wheel_scalar;
+        self
+    }
+
+    /// Sets a function that will be called when the user grabs the
+    /// [`Ramp`] to start changing its value, before any call to
+    /// `on_change`.
+    ///
+    /// This is also called around each keyboard step and double-click
+    /// reset, and once at the start of a scroll wheel gesture. Use it to
+    /// notify a plugin host that an edit has begun, so that automation is
+    /// recorded correctly.
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    pub fn on_grab<F>(mut self, on_grab: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_grab = Some(Box::new(on_grab));
+        self
+    }
+
+    /// Sets a function that will be called when the user releases the
+    /// [`Ramp`] after changing its value.
+    ///
+    /// This is also called around each keyboard step and double-click
+    /// reset. A scroll wheel gesture is released once, with the first event
+    /// that arrives after the wheel has been idle for 300 milliseconds, or
+    /// as soon as the cursor leaves the [`Ramp`] or any other input
+    /// arrives. Use it to notify a plugin host that an edit has ended.
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    pub fn on_release<F>(mut self, on_release: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_release = Some(Box::new(on_release));
+        self
+    }
+
+    /// Sets a function that formats the value of the [`Ramp`] for a
+    /// tooltip, which is displayed while the [`Ramp`] is hovered or dragged.
+    ///
+    /// `format` is usually [`ParamRange::format`] of the range the
+    /// parameter uses, for example `move |normal| range.format(normal)`.
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    /// [`ParamRange::format`]: ../../core/range/trait.ParamRange.html#tymethod.format
+    pub fn tooltip<F>(mut self, format: F) -> Self
+    where
+        F: 'static + Fn(Normal) -> String,
+    {
+        self.tooltip = Some(Box::new(format));
+        self
+    }
+
     /// Sets the modifier keys of the [`Ramp`].
     ///
     /// The default modifier key is `Ctrl`.
@@ -171,6 +255,42 @@ impl<'a, Message, Renderer: self::Renderer> Ramp<'a, Message, Renderer> {
         self
     }
 
+    /// Sets how much the [`Normal`] value of the [`Ramp`] will change when
+    /// an arrow key is pressed while it has keyboard focus.
+    ///
+    /// The default value is `0.01`.
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn key_step(mut self, key_step: f32) -> Self {
+        self.key_step = key_step;
+        self
+    }
+
+    /// Sets how much the [`Normal`] value of the [`Ramp`] will change when
+    /// `PageUp` or `PageDown` is pressed while it has keyboard focus.
+    ///
+    /// The default value is `0.1`.
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn page_step(mut self, page_step: f32) -> Self {
+        self.page_step = page_step;
+        self
+    }
+
+    fn grab(&self, messages: &mut Vec<Message>) {
+        if let Some(on_grab) = &self.on_grab {
+            messages.push((on_grab)());
+        }
+    }
+
+    fn release(&self, messages: &mut Vec<Message>) {
+        if let Some(on_release) = &self.on_release {
+            messages.push((on_release)());
+        }
+    }
+
     fn move_virtual_slider(
         &mut self,
         messages: &mut Vec<Message>,
@@ -203,9 +323,11 @@ impl<'a, Message, Renderer: self::Renderer> Ramp<'a, Message, Renderer> {
 pub struct State {
     normal_param: NormalParam,
     is_dragging: bool,
+    is_focused: bool,
     prev_drag_y: f32,
     continuous_normal: f32,
     pressed_modifiers: keyboard::Modifiers,
+    scroll_gesture: scroll::Gesture,
     last_click: Option<mouse::Click>,
 }
 
@@ -224,9 +346,11 @@ impl State {
         Self {
             normal_param,
             is_dragging: false,
+            is_focused: false,
             prev_drag_y: 0.0,
             continuous_normal: normal_param.value.as_f32(),
             pressed_modifiers: Default::default(),
+            scroll_gesture: Default::default(),
             last_click: None,
         }
     }
@@ -278,6 +402,20 @@ impl State {
     }
 }
 
+impl focus::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
 impl<'a, Message, Renderer> Widget<Message, Renderer>
     for Ramp<'a, Message, Renderer>
 where
@@ -312,6 +450,13 @@ where
         _clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
     ) -> event::Status {
+        self.state.scroll_gesture.update(
+            &event,
+            layout.bounds().contains(cursor_position),
+            &self.on_release,
+            messages,
+        );
+
         match event {
             Event::Mouse(mouse_event) => match mouse_event {
                 mouse::Event::CursorMoved { .. } => {
@@ -338,28 +483,16 @@ where
                     }
 
                     if layout.bounds().contains(cursor_position) {
-                        let lines = match delta {
-                            iced_native::mouse::ScrollDelta::Lines {
-                                y,
-                                ..
-                            } => y,
-                            iced_native::mouse::ScrollDelta::Pixels {
-                                y,
-                                ..
-                            } => {
-                                if y > 0.0 {
-                                    1.0
-                                } else if y < 0.0 {
-                                    -1.0
-                                } else {
-                                    0.0
-                                }
-                            }
-                        };
-
-                        if lines != 0.0 {
-                            let normal_delta = -lines * self.wheel_scalar;
+                        let normal_delta = -scroll::vertical(
+                            delta,
+                            self.wheel_scalar,
+                            self.pixel_wheel_scalar,
+                        );
 
+                        if normal_delta != 0.0 {
+                            self.state
+                                .scroll_gesture
+                                .scroll(&self.on_grab, messages);
                             self.move_virtual_slider(messages, normal_delta);
 
                             return event::Status::Captured;
@@ -376,26 +509,38 @@ where
                         match click.kind() {
                             mouse::click::Kind::Single => {
                                 self.state.is_dragging = true;
+                                self.grab(messages);
                                 self.state.prev_drag_y = cursor_position.y;
                             }
                             _ => {
                                 self.state.is_dragging = false;
 
+                                self.grab(messages);
+
                                 self.state.normal_param.value =
                                     self.state.normal_param.default;
 
                                 messages.push((self.on_change)(
                                     self.state.normal_param.value,
                                 ));
+
+                                self.release(messages);
                             }
                         }
 
                         self.state.last_click = Some(click);
+                        self.state.is_focused = true;
 
                         return event::Status::Captured;
+                    } else {
+                        self.state.is_focused = false;
                     }
                 }
                 mouse::Event::ButtonReleased(mouse::Button::Left) => {
+                    if self.state.is_dragging {
+                        self.release(messages);
+                    }
+
                     self.state.is_dragging = false;
                     self.state.continuous_normal =
                         self.state.normal_param.value.as_f32();
@@ -405,9 +550,28 @@ where
                 _ => {}
             },
             Event::Keyboard(keyboard_event) => match keyboard_event {
-                keyboard::Event::KeyPressed { modifiers, .. } => {
+                keyboard::Event::KeyPressed {
+                    key_code,
+                    modifiers,
+                } => {
                     self.state.pressed_modifiers = modifiers;
 
+                    if self.state.is_focused {
+                        if let Some(normal) = focus::key_normal(
+                            key_code,
+                            &self.state.normal_param,
+                            self.key_step,
+                            self.page_step,
+                        ) {
+                            self.state.normal_param.value = normal;
+                            self.state.continuous_normal = normal.as_f32();
+
+                            self.grab(messages);
+                            messages.push((self.on_change)(normal));
+                            self.release(messages);
+                        }
+                    }
+
                     return event::Status::Captured;
                 }
                 keyboard::Event::KeyReleased { modifiers, .. } => {
@@ -429,13 +593,24 @@ where
         _defaults: &Renderer::Defaults,
         layout: Layout<'_>,
         cursor_position: Point,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) -> Renderer::Output {
+        let show_tooltip =
+            self.state.is_dragging || layout.bounds().contains(cursor_position);
+        let tooltip = self
+            .tooltip
+            .as_ref()
+            .filter(|_| show_tooltip)
+            .map(|format| format(self.state.normal_param.value));
+
         renderer.draw(
             layout.bounds(),
             cursor_position,
             self.state.normal_param.value,
             self.state.is_dragging,
+            self.state.is_focused,
+            tooltip.as_deref(),
+            viewport,
             &self.style,
             self.direction,
         )
@@ -467,16 +642,23 @@ pub trait Renderer: iced_native::Renderer {
     ///   * the current cursor position
     ///   * the current normal of the [`Ramp`]
     ///   * whether the ramp is currently being dragged
+    ///   * whether the [`Ramp`] has keyboard focus
+    ///   * the text of the tooltip to display, if any
+    ///   * the visible bounds that the tooltip must stay inside
     ///   * the style of the [`Ramp`]
     ///   * the direction of the ramp line of the [`Ramp`]
     ///
     /// [`Ramp`]: struct.Ramp.html
+    #[allow(clippy::too_many_arguments)]
     fn draw(
         &mut self,
         bounds: Rectangle,
         cursor_position: Point,
         normal: Normal,
         is_dragging: bool,
+        is_focused: bool,
+        tooltip_text: Option<&str>,
+        viewport: &Rectangle,
         style: &Self::Style,
         direction: RampDirection,
     ) -> Self::Output;
@@ -0,0 +1,172 @@
+//! Converting scroll wheel and trackpad movement into value changes
+
+use std::time::{Duration, Instant};
+
+use iced_native::mouse::{self, ScrollDelta};
+use iced_native::Event;
+
+/// How long the scroll wheel must be idle before a scroll gesture ends.
+pub(crate) const GESTURE_TIMEOUT: Duration = Duration::from_millis(300);
+
+/// How many pixels scrolled on a trackpad count as one line scrolled by a
+/// mouse wheel, for widgets that only have a scalar per line.
+pub(crate) const PIXELS_PER_LINE: f32 = 20.0;
+
+/// Returns how much a value changes when scrolled vertically, where
+/// scrolling up is positive.
+///
+/// Lines scrolled by a mouse wheel are multiplied by `line_scalar`, and
+/// pixels scrolled on a trackpad are multiplied by `pixel_scalar`.
+pub(crate) fn vertical(
+    delta: ScrollDelta,
+    line_scalar: f32,
+    pixel_scalar: f32,
+) -> f32 {
+    match delta {
+        ScrollDelta::Lines { y, .. } => y * line_scalar,
+        ScrollDelta::Pixels { y, .. } => y * pixel_scalar,
+    }
+}
+
+/// Returns how much a value changes when scrolled horizontally, where
+/// scrolling right is positive.
+///
+/// Lines scrolled by a mouse wheel are multiplied by `line_scalar`, and
+/// pixels scrolled on a trackpad are multiplied by `pixel_scalar`.
+pub(crate) fn horizontal(
+    delta: ScrollDelta,
+    line_scalar: f32,
+    pixel_scalar: f32,
+) -> f32 {
+    match delta {
+        ScrollDelta::Lines { x, .. } => -x * line_scalar,
+        ScrollDelta::Pixels { x, .. } => -x * pixel_scalar,
+    }
+}
+
+/// Returns the number of lines scrolled in the `x` and `y` direction,
+/// converting pixels scrolled on a trackpad into fractions of a line.
+pub(crate) fn lines(delta: ScrollDelta) -> (f32, f32) {
+    match delta {
+        ScrollDelta::Lines { x, y } => (x, y),
+        ScrollDelta::Pixels { x, y } => {
+            (x / PIXELS_PER_LINE, y / PIXELS_PER_LINE)
+        }
+    }
+}
+
+/// A run of scroll wheel events that a widget reports to its `on_grab` and
+/// `on_release` callbacks as a single edit.
+///
+/// The gesture starts with the first wheel event that changes the value,
+/// and stays open while more wheel events arrive. It ends with the first
+/// event that arrives after the wheel has been idle for
+/// [`GESTURE_TIMEOUT`], once the cursor leaves the widget, or when any
+/// other input arrives. Iced only runs a widget when it receives an event,
+/// so `on_release` is sent with the next event after the wheel stops
+/// rather than exactly when the timeout runs out.
+#[derive(Debug, Default, Copy, Clone)]
+pub(crate) struct Gesture {
+    last_scroll: Option<Instant>,
+}
+
+impl Gesture {
+    /// Records a wheel event that changes the value. Sends `on_grab` if
+    /// this starts a new gesture.
+    pub(crate) fn scroll<Message>(
+        &mut self,
+        on_grab: &Option<Box<dyn Fn() -> Message>>,
+        messages: &mut Vec<Message>,
+    ) {
+        if self.last_scroll.is_none() {
+            if let Some(on_grab) = on_grab {
+                messages.push((on_grab)());
+            }
+        }
+
+        self.last_scroll = Some(Instant::now());
+    }
+
+    /// Ends the gesture and sends `on_release` unless `event` continues
+    /// it. Call this with every event before handling it.
+    ///
+    /// `is_over` is whether the cursor is over the widget.
+    pub(crate) fn update<Message>(
+        &mut self,
+        event: &Event,
+        is_over: bool,
+        on_release: &Option<Box<dyn Fn() -> Message>>,
+        messages: &mut Vec<Message>,
+    ) {
+        let last_scroll = match self.last_scroll {
+            Some(last_scroll) => last_scroll,
+            None => return,
+        };
+
+        let continues = match event {
+            Event::Mouse(mouse::Event::WheelScrolled { .. })
+            | Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                is_over && last_scroll.elapsed() < GESTURE_TIMEOUT
+            }
+            _ => false,
+        };
+
+        if !continues {
+            self.last_scroll = None;
+
+            if let Some(on_release) = on_release {
+                messages.push((on_release)());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wheel() -> Event {
+        Event::Mouse(mouse::Event::WheelScrolled {
+            delta: ScrollDelta::Lines { x: 0.0, y: 1.0 },
+        })
+    }
+
+    #[test]
+    fn gesture_grabs_and_releases_once() {
+        let on_grab: Option<Box<dyn Fn() -> &'static str>> =
+            Some(Box::new(|| "grab"));
+        let on_release: Option<Box<dyn Fn() -> &'static str>> =
+            Some(Box::new(|| "release"));
+
+        let mut gesture = Gesture::default();
+        let mut messages = Vec::new();
+
+        for _ in 0..3 {
+            gesture.update(&wheel(), true, &on_release, &mut messages);
+            gesture.scroll(&on_grab, &mut messages);
+        }
+
+        assert_eq!(messages, vec!["grab"]);
+
+        let press =
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+        gesture.update(&press, true, &on_release, &mut messages);
+        gesture.update(&press, true, &on_release, &mut messages);
+
+        assert_eq!(messages, vec!["grab", "release"]);
+    }
+
+    #[test]
+    fn gesture_ends_when_cursor_leaves() {
+        let on_release: Option<Box<dyn Fn() -> &'static str>> =
+            Some(Box::new(|| "release"));
+
+        let mut gesture = Gesture::default();
+        let mut messages = Vec::new();
+
+        gesture.scroll(&None, &mut messages);
+        gesture.update(&wheel(), false, &on_release, &mut messages);
+
+        assert_eq!(messages, vec!["release"]);
+    }
+}
@@ -0,0 +1,72 @@
+//! Magnetic snap points that a parameter widget sticks to while dragged
+
+use std::cmp::Ordering;
+
+use crate::core::Normal;
+
+/// Returns the distance dragged while stuck to a snap point if `normal` is
+/// exactly at one of the `points`, so that a drag starting there sticks
+/// to it.
+pub(crate) fn held_at(points: &[Normal], normal: f32) -> Option<f32> {
+    if points.iter().any(|point| point.as_f32() == normal) {
+        Some(0.0)
+    } else {
+        None
+    }
+}
+
+/// Moves `normal` by `delta`, sticking to any of the `points` it reaches
+/// until it has been dragged `capture` past it.
+///
+/// `held` is the distance dragged since the value stuck to a point, or
+/// `None` if it is not stuck to a point.
+pub(crate) fn drag(
+    points: &[Normal],
+    capture: f32,
+    held: &mut Option<f32>,
+    normal: f32,
+    delta: f32,
+) -> f32 {
+    if capture <= 0.0 {
+        *held = None;
+        return normal + delta;
+    }
+
+    if let Some(travel) = held {
+        *travel += delta;
+
+        if travel.abs() <= capture {
+            return normal;
+        }
+
+        let excess = travel.signum() * (travel.abs() - capture);
+        *held = None;
+
+        return drag(points, capture, held, normal, excess);
+    }
+
+    let new_normal = normal + delta;
+
+    let reached = points
+        .iter()
+        .map(Normal::as_f32)
+        .filter(|point| {
+            (normal < *point && *point <= new_normal)
+                || (new_normal <= *point && *point < normal)
+        })
+        .min_by(|a, b| {
+            let a = (a - normal).abs();
+            let b = (b - normal).abs();
+
+            a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+        });
+
+    match reached {
+        Some(point) => {
+            *held = Some(0.0);
+
+            drag(points, capture, held, point, new_normal - point)
+        }
+        None => new_normal,
+    }
+}
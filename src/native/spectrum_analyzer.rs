@@ -0,0 +1,630 @@
+//! Display a spectrum analyzer that displays the amplitude of a range of
+//! frequencies on a logarithmic frequency axis.
+
+use std::fmt::Debug;
+
+use iced_native::{
+    event, layout, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+use crate::core::math::amplitude_to_db_f32;
+use crate::core::{FreqRange, LogDBRange, Normal};
+use crate::native::{text_marks, tick_marks};
+
+static DEFAULT_WIDTH: u16 = 300;
+static DEFAULT_HEIGHT: u16 = 120;
+static DEFAULT_RESOLUTION: usize = 256;
+static DEFAULT_SMOOTHING: f32 = 0.7;
+static DEFAULT_PEAK_DECAY: f32 = 0.005;
+
+/// How the spectrum of a [`SpectrumAnalyzer`] is displayed.
+///
+/// [`SpectrumAnalyzer`]: struct.SpectrumAnalyzer.html
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum DisplayMode {
+    /// A line connecting the amplitude of each point in the spectrum
+    Line,
+    /// Same as `Line`, but the area below the line is filled
+    #[default]
+    Filled,
+    /// The spectrum is divided into a number of evenly spaced bars
+    Bars {
+        /// The number of bars
+        count: usize,
+    },
+}
+
+/// A spectrum analyzer GUI widget that displays the amplitude of a range of
+/// frequencies on a logarithmic frequency axis.
+///
+/// [`SpectrumAnalyzer`]: struct.SpectrumAnalyzer.html
+#[allow(missing_debug_implementations)]
+pub struct SpectrumAnalyzer<'a, Renderer: self::Renderer> {
+    state: &'a mut State,
+    mode: DisplayMode,
+    width: Length,
+    height: Length,
+    style: Renderer::Style,
+    tick_marks: Option<&'a tick_marks::Group>,
+    text_marks: Option<&'a text_marks::Group>,
+}
+
+impl<'a, Renderer: self::Renderer> SpectrumAnalyzer<'a, Renderer> {
+    /// Creates a new [`SpectrumAnalyzer`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`SpectrumAnalyzer`]
+    ///
+    /// [`State`]: struct.State.html
+    /// [`SpectrumAnalyzer`]: struct.SpectrumAnalyzer.html
+    pub fn new(state: &'a mut State) -> Self {
+        SpectrumAnalyzer {
+            state,
+            mode: DisplayMode::default(),
+            width: Length::Units(DEFAULT_WIDTH),
+            height: Length::Units(DEFAULT_HEIGHT),
+            style: Renderer::Style::default(),
+            tick_marks: None,
+            text_marks: None,
+        }
+    }
+
+    /// Sets the width of the [`SpectrumAnalyzer`].
+    /// The default width is `Length::from(Length::Units(300))`.
+    ///
+    /// [`SpectrumAnalyzer`]: struct.SpectrumAnalyzer.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`SpectrumAnalyzer`].
+    /// The default height is `Length::from(Length::Units(120))`.
+    ///
+    /// [`SpectrumAnalyzer`]: struct.SpectrumAnalyzer.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`DisplayMode`] of the [`SpectrumAnalyzer`].
+    ///
+    /// The default mode is `DisplayMode::Filled`.
+    ///
+    /// [`DisplayMode`]: enum.DisplayMode.html
+    /// [`SpectrumAnalyzer`]: struct.SpectrumAnalyzer.html
+    pub fn mode(mut self, mode: DisplayMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets the style of the [`SpectrumAnalyzer`].
+    ///
+    /// [`SpectrumAnalyzer`]: struct.SpectrumAnalyzer.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the tick marks to display along the frequency axis. Note your
+    /// [`StyleSheet`] must also implement
+    /// `tick_marks_style(&self) -> Option<tick_marks::Style>` for them to
+    /// display (which the default style does).
+    ///
+    /// Use [`frequency_tick_marks`] to create a frequency grid that lines
+    /// up with the [`FreqRange`] of the [`State`].
+    ///
+    /// [`StyleSheet`]: ../../style/spectrum_analyzer/trait.StyleSheet.html
+    /// [`frequency_tick_marks`]: fn.frequency_tick_marks.html
+    /// [`FreqRange`]: ../../core/range/struct.FreqRange.html
+    /// [`State`]: struct.State.html
+    pub fn tick_marks(mut self, tick_marks: &'a tick_marks::Group) -> Self {
+        self.tick_marks = Some(tick_marks);
+        self
+    }
+
+    /// Sets the text marks to display along the frequency axis. Note your
+    /// [`StyleSheet`] must also implement
+    /// `text_marks_style(&self) -> Option<text_marks::Style>` for them to
+    /// display (which the default style does).
+    ///
+    /// Use [`frequency_text_marks`] to create frequency labels that line
+    /// up with the [`FreqRange`] of the [`State`].
+    ///
+    /// [`StyleSheet`]: ../../style/spectrum_analyzer/trait.StyleSheet.html
+    /// [`frequency_text_marks`]: fn.frequency_text_marks.html
+    /// [`FreqRange`]: ../../core/range/struct.FreqRange.html
+    /// [`State`]: struct.State.html
+    pub fn text_marks(mut self, text_marks: &'a text_marks::Group) -> Self {
+        self.text_marks = Some(text_marks);
+        self
+    }
+}
+
+/// The local state of a [`SpectrumAnalyzer`].
+///
+/// The spectrum is resampled to a fixed number of points that are evenly
+/// spaced on the logarithmic frequency axis of a [`FreqRange`]. The
+/// amplitude of each point is mapped through a [`LogDBRange`].
+///
+/// [`SpectrumAnalyzer`]: struct.SpectrumAnalyzer.html
+/// [`FreqRange`]: ../../core/range/struct.FreqRange.html
+/// [`LogDBRange`]: ../../core/range/struct.LogDBRange.html
+#[derive(Debug, Clone)]
+pub struct State {
+    freq_range: FreqRange,
+    db_range: LogDBRange,
+    points: Vec<f32>,
+    peaks: Vec<f32>,
+    smoothing: f32,
+    peak_decay: Option<f32>,
+    #[cfg(feature = "fft")]
+    fft: Option<fft::Analyzer>,
+    tick_marks_cache: crate::graphics::tick_marks::PrimitiveCache,
+    text_marks_cache: crate::graphics::text_marks::PrimitiveCache,
+}
+
+impl State {
+    /// Creates a new [`SpectrumAnalyzer`] state.
+    ///
+    /// It expects:
+    /// * the [`FreqRange`] of the frequency axis (usually
+    ///   `FreqRange::default()` for 20 Hz to 20 kHz)
+    /// * the [`LogDBRange`] of the amplitude axis (for example
+    ///   `LogDBRange::new(-90.0, 0.0, 1.0.into())`)
+    ///
+    /// [`SpectrumAnalyzer`]: struct.SpectrumAnalyzer.html
+    /// [`FreqRange`]: ../../core/range/struct.FreqRange.html
+    /// [`LogDBRange`]: ../../core/range/struct.LogDBRange.html
+    pub fn new(freq_range: FreqRange, db_range: LogDBRange) -> Self {
+        Self {
+            freq_range,
+            db_range,
+            points: vec![0.0; DEFAULT_RESOLUTION],
+            peaks: vec![0.0; DEFAULT_RESOLUTION],
+            smoothing: DEFAULT_SMOOTHING,
+            peak_decay: Some(DEFAULT_PEAK_DECAY),
+            #[cfg(feature = "fft")]
+            fft: None,
+            tick_marks_cache: Default::default(),
+            text_marks_cache: Default::default(),
+        }
+    }
+
+    /// Sets the number of points the spectrum is resampled to.
+    ///
+    /// The default is `256`.
+    pub fn set_resolution(&mut self, resolution: usize) {
+        let resolution = resolution.max(2);
+
+        self.points = vec![0.0; resolution];
+        self.peaks = vec![0.0; resolution];
+    }
+
+    /// Sets how slowly the displayed spectrum falls when the amplitude
+    /// decreases, between `0.0` (no smoothing) and `1.0` (never falls).
+    /// Rising amplitudes are always displayed immediately.
+    ///
+    /// The default is `0.7`.
+    pub fn set_smoothing(&mut self, smoothing: f32) {
+        self.smoothing = smoothing.clamp(0.0, 1.0);
+    }
+
+    /// Sets how much the peak-hold curve falls (as a [`Normal`] value) each
+    /// time the spectrum is updated. Set this to `None` to hide the
+    /// peak-hold curve.
+    ///
+    /// The default is `Some(0.005)`.
+    ///
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn set_peak_decay(&mut self, peak_decay: Option<f32>) {
+        self.peak_decay = peak_decay;
+    }
+
+    /// Returns the [`FreqRange`] of the frequency axis.
+    ///
+    /// [`FreqRange`]: ../../core/range/struct.FreqRange.html
+    pub fn freq_range(&self) -> &FreqRange {
+        &self.freq_range
+    }
+
+    /// Returns the [`LogDBRange`] of the amplitude axis.
+    ///
+    /// [`LogDBRange`]: ../../core/range/struct.LogDBRange.html
+    pub fn db_range(&self) -> &LogDBRange {
+        &self.db_range
+    }
+
+    /// Updates the spectrum with new magnitude bins.
+    ///
+    /// It expects:
+    /// * the linear magnitudes (amplitudes) of the bins, where the first bin
+    ///   is at 0 Hz and the last bin is at the Nyquist frequency
+    ///   (`sample_rate / 2`). This is the layout of the first half of a real
+    ///   FFT.
+    /// * the sample rate of the analyzed signal
+    pub fn set_bins(&mut self, magnitudes: &[f32], sample_rate: f32) {
+        if magnitudes.len() < 2 || sample_rate <= 0.0 {
+            return;
+        }
+
+        let last_bin = magnitudes.len() - 1;
+        let bins_per_hz = last_bin as f32 / (sample_rate / 2.0);
+
+        let resolution = self.points.len();
+        let half_step = 0.5 / (resolution - 1) as f32;
+
+        for i in 0..resolution {
+            let normal = i as f32 / (resolution - 1) as f32;
+
+            let low_bin =
+                self.freq_range.unmap_to_value((normal - half_step).into())
+                    * bins_per_hz;
+            let high_bin =
+                self.freq_range.unmap_to_value((normal + half_step).into())
+                    * bins_per_hz;
+
+            let low_index = low_bin.ceil() as usize;
+            let high_index = (high_bin.floor() as usize).min(last_bin);
+
+            let magnitude = if low_index <= high_index {
+                // Multiple bins fall into this point, so use the loudest one.
+                magnitudes[low_index..=high_index]
+                    .iter()
+                    .fold(0.0f32, |max, m| max.max(*m))
+            } else {
+                // The bins are sparser than the points, so interpolate
+                // between the two nearest bins.
+                let bin =
+                    self.freq_range.unmap_to_value(normal.into()) * bins_per_hz;
+                let index = (bin.floor() as usize).min(last_bin);
+                let next_index = (index + 1).min(last_bin);
+                let fract = bin - index as f32;
+
+                magnitudes[index]
+                    + ((magnitudes[next_index] - magnitudes[index]) * fract)
+            };
+
+            let new = self
+                .db_range
+                .map_to_normal(amplitude_to_db_f32(magnitude))
+                .as_f32();
+
+            let point = &mut self.points[i];
+            if new >= *point {
+                *point = new;
+            } else {
+                *point =
+                    (*point * self.smoothing) + (new * (1.0 - self.smoothing));
+            }
+
+            if let Some(peak_decay) = self.peak_decay {
+                let peak = &mut self.peaks[i];
+                *peak = (*peak - peak_decay).max(*point);
+            }
+        }
+    }
+
+    /// Clears the spectrum and the peak-hold curve.
+    pub fn clear(&mut self) {
+        for point in self.points.iter_mut() {
+            *point = 0.0;
+        }
+        for peak in self.peaks.iter_mut() {
+            *peak = 0.0;
+        }
+    }
+}
+
+#[cfg(feature = "fft")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fft")))]
+impl State {
+    /// Enables the internal FFT so raw samples can be pushed into the
+    /// analyzer with [`push_samples`].
+    ///
+    /// * `fft_size` - the number of samples in each FFT. Larger sizes give
+    /// more resolution in the low frequencies at the cost of time
+    /// resolution. `2048` or `4096` is usually a good choice.
+    ///
+    /// [`push_samples`]: struct.State.html#method.push_samples
+    pub fn enable_fft(&mut self, fft_size: usize) {
+        self.fft = Some(fft::Analyzer::new(fft_size));
+    }
+
+    /// Pushes raw samples into the internal FFT and updates the spectrum.
+    /// This does nothing if the internal FFT has not been enabled with
+    /// [`enable_fft`].
+    ///
+    /// [`enable_fft`]: struct.State.html#method.enable_fft
+    pub fn push_samples(&mut self, samples: &[f32], sample_rate: f32) {
+        let magnitudes = if let Some(fft) = &mut self.fft {
+            fft.push_samples(samples);
+            fft.magnitudes()
+        } else {
+            return;
+        };
+
+        self.set_bins(&magnitudes, sample_rate);
+    }
+}
+
+#[cfg(feature = "fft")]
+mod fft {
+    use rustfft::num_complex::Complex;
+    use rustfft::{Fft, FftPlanner};
+    use std::collections::VecDeque;
+    use std::sync::Arc;
+
+    #[derive(Clone)]
+    pub(super) struct Analyzer {
+        fft: Arc<dyn Fft<f32>>,
+        window: Vec<f32>,
+        samples: VecDeque<f32>,
+        buffer: Vec<Complex<f32>>,
+    }
+
+    impl Analyzer {
+        pub fn new(fft_size: usize) -> Self {
+            let fft_size = fft_size.max(4);
+
+            let fft = FftPlanner::new().plan_fft_forward(fft_size);
+
+            // Hann window
+            let window = (0..fft_size)
+                .map(|i| {
+                    0.5 - (0.5
+                        * (crate::core::math::TWO_PI * i as f32
+                            / fft_size as f32)
+                            .cos())
+                })
+                .collect();
+
+            Self {
+                fft,
+                window,
+                samples: VecDeque::from(vec![0.0; fft_size]),
+                buffer: vec![Complex::new(0.0, 0.0); fft_size],
+            }
+        }
+
+        pub fn push_samples(&mut self, samples: &[f32]) {
+            let fft_size = self.window.len();
+
+            let samples = if samples.len() > fft_size {
+                &samples[samples.len() - fft_size..]
+            } else {
+                samples
+            };
+
+            for _ in 0..samples.len() {
+                let _ = self.samples.pop_front();
+            }
+            self.samples.extend(samples.iter().copied());
+        }
+
+        pub fn magnitudes(&mut self) -> Vec<f32> {
+            let fft_size = self.window.len();
+
+            for ((c, s), w) in self
+                .buffer
+                .iter_mut()
+                .zip(self.samples.iter())
+                .zip(self.window.iter())
+            {
+                *c = Complex::new(*s * *w, 0.0);
+            }
+
+            self.fft.process(&mut self.buffer);
+
+            // Compensate for the size of the FFT and the gain of the
+            // Hann window so a full-scale sine wave reads as `1.0`.
+            let scale = 4.0 / fft_size as f32;
+
+            self.buffer[0..=fft_size / 2]
+                .iter()
+                .map(|c| c.norm() * scale)
+                .collect()
+        }
+    }
+
+    impl std::fmt::Debug for Analyzer {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("Analyzer")
+                .field("fft_size", &self.window.len())
+                .finish()
+        }
+    }
+}
+
+/// Returns a [`Group`] of tick marks at common frequencies (decades in
+/// tier 1, and the multiples in between in tier 2 and tier 3) that lines up
+/// with the given [`FreqRange`].
+///
+/// [`Group`]: ../tick_marks/struct.Group.html
+/// [`FreqRange`]: ../../core/range/struct.FreqRange.html
+pub fn frequency_tick_marks(freq_range: &FreqRange) -> tick_marks::Group {
+    let mut marks: Vec<(Normal, tick_marks::Tier)> = Vec::new();
+
+    let min = freq_range.unmap_to_value(Normal::min());
+    let max = freq_range.unmap_to_value(Normal::max());
+
+    let mut decade = 10.0;
+    while decade <= max {
+        for multiple in 1..10 {
+            let freq = decade * multiple as f32;
+
+            if freq < min || freq > max {
+                continue;
+            }
+
+            let tier = match multiple {
+                1 => tick_marks::Tier::One,
+                2 | 5 => tick_marks::Tier::Two,
+                _ => tick_marks::Tier::Three,
+            };
+
+            marks.push((freq_range.map_to_normal(freq), tier));
+        }
+
+        decade *= 10.0;
+    }
+
+    tick_marks::Group::from_normalized(&marks)
+}
+
+/// Returns a [`Group`] of text marks labeling common frequencies that lines
+/// up with the given [`FreqRange`].
+///
+/// [`Group`]: ../text_marks/struct.Group.html
+/// [`FreqRange`]: ../../core/range/struct.FreqRange.html
+pub fn frequency_text_marks(freq_range: &FreqRange) -> text_marks::Group {
+    let min = freq_range.unmap_to_value(Normal::min());
+    let max = freq_range.unmap_to_value(Normal::max());
+
+    let marks: Vec<(Normal, &str)> = [
+        (50.0, "50"),
+        (100.0, "100"),
+        (200.0, "200"),
+        (500.0, "500"),
+        (1_000.0, "1k"),
+        (2_000.0, "2k"),
+        (5_000.0, "5k"),
+        (10_000.0, "10k"),
+    ]
+    .iter()
+    .filter(|(freq, _)| *freq >= min && *freq <= max)
+    .map(|(freq, text)| (freq_range.map_to_normal(*freq), *text))
+    .collect();
+
+    text_marks::Group::from_normalized(&marks)
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for SpectrumAnalyzer<'a, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        _event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        _messages: &mut Vec<Message>,
+    ) -> event::Status {
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let peaks = if self.state.peak_decay.is_some() {
+            Some(&self.state.peaks[..])
+        } else {
+            None
+        };
+
+        renderer.draw(
+            layout.bounds(),
+            cursor_position,
+            &self.state.points,
+            peaks,
+            self.mode,
+            self.tick_marks,
+            self.text_marks,
+            &self.style,
+            &self.state.tick_marks_cache,
+            &self.state.text_marks_cache,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`SpectrumAnalyzer`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`SpectrumAnalyzer`] in your user interface.
+///
+/// [`SpectrumAnalyzer`]: struct.SpectrumAnalyzer.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`SpectrumAnalyzer`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`SpectrumAnalyzer`]
+    ///   * the current cursor position
+    ///   * the normalized amplitude of each point in the spectrum, evenly
+    ///     spaced on the frequency axis
+    ///   * the normalized amplitude of each point in the peak-hold curve
+    ///   * the display mode
+    ///   * any tick marks to display
+    ///   * any text marks to display
+    ///   * the style of the [`SpectrumAnalyzer`]
+    ///
+    /// [`SpectrumAnalyzer`]: struct.SpectrumAnalyzer.html
+    #[allow(clippy::too_many_arguments)]
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        points: &[f32],
+        peaks: Option<&[f32]>,
+        mode: DisplayMode,
+        tick_marks: Option<&tick_marks::Group>,
+        text_marks: Option<&text_marks::Group>,
+        style: &Self::Style,
+        tick_marks_cache: &crate::tick_marks::PrimitiveCache,
+        text_marks_cache: &crate::text_marks::PrimitiveCache,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<SpectrumAnalyzer<'a, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        spectrum_analyzer: SpectrumAnalyzer<'a, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(spectrum_analyzer)
+    }
+}
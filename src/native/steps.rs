@@ -0,0 +1,78 @@
+//! Snapping the value of a stepped parameter widget
+
+use std::cmp::Ordering;
+
+use crate::core::{IntRange, Normal};
+
+/// The values that a stepped parameter widget snaps to
+#[derive(Debug, Copy, Clone)]
+pub(crate) enum Steps<'a> {
+    /// Every integer value of an [`IntRange`]
+    Range(IntRange),
+    /// An explicit list of detents, in any order
+    Detents(&'a [Normal]),
+}
+
+impl<'a> Steps<'a> {
+    /// Returns the step closest to `normal`.
+    pub(crate) fn snapped(&self, normal: Normal) -> Normal {
+        match self {
+            Steps::Range(range) => range.snapped(normal),
+            Steps::Detents(detents) => detents
+                .iter()
+                .copied()
+                .min_by(|a, b| {
+                    let a = (a.as_f32() - normal.as_f32()).abs();
+                    let b = (b.as_f32() - normal.as_f32()).abs();
+
+                    a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+                })
+                .unwrap_or(normal),
+        }
+    }
+
+    /// Returns the step closest to `to`. If that is the same step as the
+    /// one closest to `from`, the neighbouring step in the direction of `to`
+    /// is returned instead, so that small keyboard steps still move the
+    /// value.
+    pub(crate) fn step_toward(&self, from: Normal, to: Normal) -> Normal {
+        let current = self.snapped(from);
+        let target = self.snapped(to);
+
+        if target != current || to == current {
+            target
+        } else if to > current {
+            self.next(current)
+        } else {
+            self.previous(current)
+        }
+    }
+
+    fn next(&self, current: Normal) -> Normal {
+        match self {
+            Steps::Range(range) => {
+                range.map_to_normal(range.unmap_to_value(current) + 1)
+            }
+            Steps::Detents(detents) => detents
+                .iter()
+                .copied()
+                .filter(|detent| *detent > current)
+                .min_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+                .unwrap_or(current),
+        }
+    }
+
+    fn previous(&self, current: Normal) -> Normal {
+        match self {
+            Steps::Range(range) => {
+                range.map_to_normal(range.unmap_to_value(current) - 1)
+            }
+            Steps::Detents(detents) => detents
+                .iter()
+                .copied()
+                .filter(|detent| *detent < current)
+                .max_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+                .unwrap_or(current),
+        }
+    }
+}
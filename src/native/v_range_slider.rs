@@ -0,0 +1,892 @@
+//! Display an interactive vertical slider with multiple handles that each
+//! control a [`NormalParam`]
+//!
+//! [`NormalParam`]: ../core/normal_param/struct.Param.html
+
+use std::fmt::Debug;
+
+use iced_native::{
+    event, keyboard, layout, mouse, Clipboard, Element, Event, Hasher, Layout,
+    Length, Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+use crate::core::{Normal, NormalParam};
+use crate::native::{focus, scroll};
+
+static DEFAULT_WIDTH: u16 = 14;
+static DEFAULT_HANDLE_HEIGHT: u16 = 16;
+static DEFAULT_SCALAR: f32 = 0.9575;
+static DEFAULT_WHEEL_SCALAR: f32 = 0.01;
+static DEFAULT_PIXEL_WHEEL_SCALAR: f32 = 0.0005;
+static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+static DEFAULT_KEY_STEP: f32 = 0.01;
+static DEFAULT_PAGE_STEP: f32 = 0.1;
+
+/// What is currently being dragged in a [`VRangeSlider`].
+///
+/// [`VRangeSlider`]: struct.VRangeSlider.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DragTarget {
+    /// The handle at the given index.
+    Handle(usize),
+    /// The filled span between the two handles at the given indexes.
+    Span(usize, usize),
+}
+
+/// A vertical slider GUI widget with two or more handles, each
+/// controlling a [`NormalParam`]
+///
+/// The filled span between two handles can optionally be dragged to move
+/// both handles at once.
+///
+/// a [`VRangeSlider`] will try to fill the vertical space of its container.
+///
+/// [`NormalParam`]: ../../core/normal_param/struct.Param.html
+/// [`VRangeSlider`]: struct.VRangeSlider.html
+#[allow(missing_debug_implementations)]
+pub struct VRangeSlider<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    on_change: Box<dyn Fn(usize, Normal) -> Message>,
+    tooltip: Option<Box<dyn Fn(usize, Normal) -> String>>,
+    scalar: f32,
+    wheel_scalar: f32,
+    pixel_wheel_scalar: f32,
+    modifier_scalar: f32,
+    modifier_keys: keyboard::Modifiers,
+    prevent_crossing: bool,
+    span_drag: bool,
+    key_step: f32,
+    page_step: f32,
+    handle_height: u16,
+    width: Length,
+    height: Length,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer>
+    VRangeSlider<'a, Message, Renderer>
+{
+    /// Creates a new [`VRangeSlider`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`VRangeSlider`]
+    ///   * a function that will be called with the index of the handle and
+    ///     its new value when a handle is moved. When a span is dragged, this
+    ///     is called once for each of its two handles.
+    ///
+    /// [`State`]: struct.State.html
+    /// [`VRangeSlider`]: struct.VRangeSlider.html
+    pub fn new<F>(state: &'a mut State, on_change: F) -> Self
+    where
+        F: 'static + Fn(usize, Normal) -> Message,
+    {
+        VRangeSlider {
+            state,
+            on_change: Box::new(on_change),
+            tooltip: None,
+            scalar: DEFAULT_SCALAR,
+            wheel_scalar: DEFAULT_WHEEL_SCALAR,
+            pixel_wheel_scalar: DEFAULT_PIXEL_WHEEL_SCALAR,
+            modifier_scalar: DEFAULT_MODIFIER_SCALAR,
+            modifier_keys: keyboard::Modifiers {
+                control: true,
+                ..Default::default()
+            },
+            prevent_crossing: true,
+            span_drag: true,
+            key_step: DEFAULT_KEY_STEP,
+            page_step: DEFAULT_PAGE_STEP,
+            handle_height: DEFAULT_HANDLE_HEIGHT,
+            width: Length::Units(DEFAULT_WIDTH),
+            height: Length::Fill,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`VRangeSlider`].
+    ///
+    /// The default width is `Length::Units(14)`.
+    ///
+    /// [`VRangeSlider`]: struct.VRangeSlider.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`VRangeSlider`].
+    ///
+    /// The default height is `Length::Fill`.
+    ///
+    /// [`VRangeSlider`]: struct.VRangeSlider.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`VRangeSlider`].
+    ///
+    /// [`VRangeSlider`]: struct.VRangeSlider.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets a function that formats the value of a handle of the
+    /// [`VRangeSlider`] for a tooltip. The tooltip is displayed next to each
+    /// handle that is hovered or dragged, and next to both handles of a
+    /// hovered or dragged span.
+    ///
+    /// `format` receives the index of the handle and its value. It is
+    /// usually [`ParamRange::format`] of the range the parameter of that
+    /// handle uses, for example `move |_, normal| range.format(normal)`.
+    ///
+    /// [`VRangeSlider`]: struct.VRangeSlider.html
+    /// [`ParamRange::format`]: ../../core/range/trait.ParamRange.html#tymethod.format
+    pub fn tooltip<F>(mut self, format: F) -> Self
+    where
+        F: 'static + Fn(usize, Normal) -> String,
+    {
+        self.tooltip = Some(Box::new(format));
+        self
+    }
+
+    /// Sets the modifier keys of the [`VRangeSlider`].
+    ///
+    /// The default modifier key is `Ctrl`.
+    ///
+    /// [`VRangeSlider`]: struct.VRangeSlider.html
+    pub fn modifier_keys(mut self, modifier_keys: keyboard::Modifiers) -> Self {
+        self.modifier_keys = modifier_keys;
+        self
+    }
+
+    /// Sets the scalar to use when the user drags a handle per pixel.
+    ///
+    /// For example, a scalar of `0.5` will cause the handle to move half a
+    /// pixel for every pixel the mouse moves.
+    ///
+    /// The default scalar is `0.9575`.
+    ///
+    /// [`VRangeSlider`]: struct.VRangeSlider.html
+    pub fn scalar(mut self, scalar: f32) -> Self {
+        self.scalar = scalar;
+        self
+    }
+
+    /// Sets how much the [`Normal`] value of the hovered handle (or span)
+    /// will change per line scrolled by the mouse wheel.
+    ///
+    /// This can be set to `0.0` to disable the scroll wheel from moving the
+    /// handles.
+    ///
+    /// The default value is `0.01`
+    ///
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn wheel_scalar(mut self, wheel_scalar: f32) -> Self {
+        self.wheel_scalar = wheel_scalar;
+        self
+    }
+
+    /// Sets how much the [`Normal`] value will change for the
+    /// [`VRangeSlider`] per pixel scrolled on a trackpad, which reports the
+    /// exact distance scrolled instead of lines.
+    ///
+    /// This can be set to `0.0` to disable trackpad scrolling while still
+    /// allowing the scroll wheel.
+    ///
+    /// The default value is `0.0005`
+    ///
+    /// [`VRangeSlider`]: struct.VRangeSlider.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn pixel_wheel_scalar(mut self, pixel_wheel_scalar: f32) -> Self {
+        self.pixel_wheel_scalar = pixel_wheel_scalar;
+        self
+    }
+
+    /// Sets the scalar to use when the user drags a handle while holding down
+    /// the modifier key.
+    ///
+    /// For example, a scalar of `0.5` will cause the handle to move half a
+    /// pixel for every pixel the mouse moves.
+    ///
+    /// The default scalar is `0.02`, and the default modifier key is `Ctrl`.
+    ///
+    /// [`VRangeSlider`]: struct.VRangeSlider.html
+    pub fn modifier_scalar(mut self, scalar: f32) -> Self {
+        self.modifier_scalar = scalar;
+        self
+    }
+
+    /// Sets whether a handle is stopped by its neighbors when it is dragged
+    /// into them, keeping the handles in order.
+    ///
+    /// The default is `true`.
+    pub fn prevent_crossing(mut self, prevent_crossing: bool) -> Self {
+        self.prevent_crossing = prevent_crossing;
+        self
+    }
+
+    /// Sets whether dragging the filled span between two handles moves both
+    /// handles at once.
+    ///
+    /// The default is `true`.
+    pub fn span_drag(mut self, span_drag: bool) -> Self {
+        self.span_drag = span_drag;
+        self
+    }
+
+    /// Sets how much the [`Normal`] value of the focused handle will change
+    /// when an arrow key is pressed while the [`VRangeSlider`] has keyboard
+    /// focus.
+    ///
+    /// The default value is `0.01`.
+    ///
+    /// [`VRangeSlider`]: struct.VRangeSlider.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn key_step(mut self, key_step: f32) -> Self {
+        self.key_step = key_step;
+        self
+    }
+
+    /// Sets how much the [`Normal`] value of the focused handle will change
+    /// when `PageUp` or `PageDown` is pressed while the [`VRangeSlider`] has
+    /// keyboard focus.
+    ///
+    /// The default value is `0.1`.
+    ///
+    /// [`VRangeSlider`]: struct.VRangeSlider.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn page_step(mut self, page_step: f32) -> Self {
+        self.page_step = page_step;
+        self
+    }
+
+    /// Sets the height of the handles in pixels. This is used to place the
+    /// handles and to find the handle under the cursor.
+    ///
+    /// The default height is `16`.
+    ///
+    /// [`VRangeSlider`]: struct.VRangeSlider.html
+    pub fn handle_height(mut self, handle_height: u16) -> Self {
+        self.handle_height = handle_height;
+        self
+    }
+
+    fn target_at(
+        &self,
+        bounds: &Rectangle,
+        cursor_y: f32,
+    ) -> Option<DragTarget> {
+        let handle_height = f32::from(self.handle_height);
+        let value_height = bounds.height - handle_height;
+
+        if value_height <= 0.0 {
+            return None;
+        }
+
+        let cursor_normal =
+            (bounds.y + bounds.height - (handle_height / 2.0) - cursor_y)
+                / value_height;
+        let hit_radius = handle_height / 2.0 / value_height;
+
+        let normals = &self.state.continuous_normals;
+
+        // When handles are stacked on top of each other, pick the one that
+        // can move towards the cursor.
+        let mut closest: Option<(usize, f32)> = None;
+        for (i, normal) in normals.iter().enumerate() {
+            let distance = (normal - cursor_normal).abs();
+
+            match closest {
+                Some((_, closest_distance))
+                    if distance > closest_distance
+                        || (distance == closest_distance
+                            && cursor_normal < *normal) => {}
+                _ => closest = Some((i, distance)),
+            }
+        }
+
+        let (closest, distance) = closest?;
+
+        if distance <= hit_radius || !self.span_drag {
+            return Some(DragTarget::Handle(closest));
+        }
+
+        let below = normals
+            .iter()
+            .enumerate()
+            .filter(|(_, normal)| **normal <= cursor_normal)
+            .max_by(|(_, a), (_, b)| {
+                a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|(i, _)| i);
+
+        let above = normals
+            .iter()
+            .enumerate()
+            .filter(|(_, normal)| **normal >= cursor_normal)
+            .min_by(|(_, a), (_, b)| {
+                a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|(i, _)| i);
+
+        match (below, above) {
+            (Some(below), Some(above)) if below != above => {
+                Some(DragTarget::Span(below, above))
+            }
+            _ => Some(DragTarget::Handle(closest)),
+        }
+    }
+
+    fn move_target(
+        &mut self,
+        target: DragTarget,
+        messages: &mut Vec<Message>,
+        mut normal_delta: f32,
+    ) {
+        if self.state.pressed_modifiers.matches(self.modifier_keys) {
+            normal_delta *= self.modifier_scalar;
+        }
+
+        match target {
+            DragTarget::Handle(index) => {
+                let (min, max) = self.limits(&[index]);
+
+                let normal = (self.state.continuous_normals[index]
+                    - normal_delta)
+                    .max(min)
+                    .min(max);
+
+                self.state.continuous_normals[index] = normal;
+                self.state.handles[index].value = normal.into();
+
+                messages.push((self.on_change)(index, normal.into()));
+            }
+            DragTarget::Span(a, b) => {
+                let (min, max) = self.limits(&[a, b]);
+
+                let low = self.state.continuous_normals[a]
+                    .min(self.state.continuous_normals[b]);
+                let high = self.state.continuous_normals[a]
+                    .max(self.state.continuous_normals[b]);
+
+                let shift = (-normal_delta).max(min - low).min(max - high);
+
+                for &index in &[a, b] {
+                    let normal = self.state.continuous_normals[index] + shift;
+
+                    self.state.continuous_normals[index] = normal;
+                    self.state.handles[index].value = normal.into();
+
+                    messages.push((self.on_change)(index, normal.into()));
+                }
+            }
+        }
+    }
+
+    /// Returns the range the given handles may move in without crossing any
+    /// of the other handles.
+    fn limits(&self, indexes: &[usize]) -> (f32, f32) {
+        if !self.prevent_crossing {
+            return (0.0, 1.0);
+        }
+
+        let first = *indexes.iter().min().unwrap_or(&0);
+        let last = *indexes.iter().max().unwrap_or(&0);
+
+        let min = if first > 0 {
+            self.state.continuous_normals[first - 1]
+        } else {
+            0.0
+        };
+
+        let max = self
+            .state
+            .continuous_normals
+            .get(last + 1)
+            .copied()
+            .unwrap_or(1.0);
+
+        (min, max)
+    }
+}
+
+/// The local state of a [`VRangeSlider`].
+///
+/// While the [`VRangeSlider`] has keyboard focus, the keys move one handle at a
+/// time: the handle that was clicked last, or the first handle if none has
+/// been clicked yet. Clicking the span between two handles focuses the
+/// first of the two. The focused handle never crosses the other handles
+/// when the widget prevents crossing.
+///
+/// [`VRangeSlider`]: struct.VRangeSlider.html
+#[derive(Debug, Clone)]
+pub struct State {
+    handles: Vec<NormalParam>,
+    continuous_normals: Vec<f32>,
+    drag_target: Option<DragTarget>,
+    prev_drag_y: f32,
+    pressed_modifiers: keyboard::Modifiers,
+    last_click: Option<mouse::Click>,
+    is_focused: bool,
+    focused_handle: usize,
+}
+
+impl State {
+    /// Creates a new [`VRangeSlider`] state.
+    ///
+    /// It expects:
+    /// * a [`NormalParam`] for each handle of the [`VRangeSlider`]. The
+    ///   handles are sorted by their value, so the first handle is the one
+    ///   furthest to the bottom.
+    ///
+    /// [`NormalParam`]: ../../core/normal_param/struct.Param.html
+    /// [`VRangeSlider`]: struct.VRangeSlider.html
+    pub fn new(mut handles: Vec<NormalParam>) -> Self {
+        handles.sort_by(|a, b| {
+            a.value
+                .as_f32()
+                .partial_cmp(&b.value.as_f32())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let continuous_normals =
+            handles.iter().map(|handle| handle.value.as_f32()).collect();
+
+        Self {
+            handles,
+            continuous_normals,
+            drag_target: None,
+            prev_drag_y: 0.0,
+            pressed_modifiers: Default::default(),
+            last_click: None,
+            is_focused: false,
+            focused_handle: 0,
+        }
+    }
+
+    /// Returns the number of handles of the [`VRangeSlider`].
+    ///
+    /// [`VRangeSlider`]: struct.VRangeSlider.html
+    pub fn len(&self) -> usize {
+        self.handles.len()
+    }
+
+    /// Returns `true` if the [`VRangeSlider`] has no handles.
+    ///
+    /// [`VRangeSlider`]: struct.VRangeSlider.html
+    pub fn is_empty(&self) -> bool {
+        self.handles.is_empty()
+    }
+
+    /// Set the normalized value of the handle at `index`.
+    ///
+    /// This does not keep the handles in order, even if the widget prevents
+    /// handles from crossing.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `index` is out of bounds.
+    pub fn set_normal(&mut self, index: usize, normal: Normal) {
+        self.handles[index].value = normal;
+        self.continuous_normals[index] = normal.into();
+    }
+
+    /// Get the normalized value of the handle at `index`.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `index` is out of bounds.
+    pub fn normal(&self, index: usize) -> Normal {
+        self.handles[index].value
+    }
+
+    /// Get the normalized values of all handles.
+    pub fn normals(&self) -> Vec<Normal> {
+        self.handles.iter().map(|handle| handle.value).collect()
+    }
+
+    /// Set the normalized default value of the handle at `index`.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `index` is out of bounds.
+    pub fn set_default(&mut self, index: usize, normal: Normal) {
+        self.handles[index].default = normal;
+    }
+
+    /// Get the normalized default value of the handle at `index`.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `index` is out of bounds.
+    pub fn default(&self, index: usize) -> Normal {
+        self.handles[index].default
+    }
+
+    /// Returns what is currently being dragged, if anything.
+    pub fn drag_target(&self) -> Option<DragTarget> {
+        self.drag_target
+    }
+
+    /// Is the [`VRangeSlider`] currently in the dragging state?
+    ///
+    /// [`VRangeSlider`]: struct.VRangeSlider.html
+    pub fn is_dragging(&self) -> bool {
+        self.drag_target.is_some()
+    }
+
+    /// Returns the index of the handle that the keys move, if the
+    /// [`VRangeSlider`] has keyboard focus.
+    ///
+    /// [`VRangeSlider`]: struct.VRangeSlider.html
+    pub fn focused_handle(&self) -> Option<usize> {
+        if self.is_focused {
+            Some(self.focused_handle)
+        } else {
+            None
+        }
+    }
+
+    /// Gives keyboard focus to the [`VRangeSlider`] and makes the keys move the
+    /// handle at `index`.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `index` is out of bounds.
+    ///
+    /// [`VRangeSlider`]: struct.VRangeSlider.html
+    pub fn focus_handle(&mut self, index: usize) {
+        assert!(index < self.handles.len(), "handle index out of bounds");
+
+        self.is_focused = true;
+        self.focused_handle = index;
+    }
+
+    fn sync_continuous_normals(&mut self) {
+        for (continuous, handle) in
+            self.continuous_normals.iter_mut().zip(self.handles.iter())
+        {
+            *continuous = handle.value.as_f32();
+        }
+    }
+}
+
+impl focus::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for VRangeSlider<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse_event) => match mouse_event {
+                mouse::Event::CursorMoved { .. } => {
+                    if let Some(target) = self.state.drag_target {
+                        let bounds_height = layout.bounds().height;
+
+                        if bounds_height > 0.0 {
+                            let normal_delta = (cursor_position.y
+                                - self.state.prev_drag_y)
+                                / bounds_height
+                                * self.scalar;
+
+                            self.state.prev_drag_y = cursor_position.y;
+
+                            self.move_target(target, messages, normal_delta);
+
+                            return event::Status::Captured;
+                        }
+                    }
+                }
+                mouse::Event::WheelScrolled { delta } => {
+                    if self.wheel_scalar == 0.0 {
+                        return event::Status::Ignored;
+                    }
+
+                    let bounds = layout.bounds();
+
+                    if bounds.contains(cursor_position) {
+                        let normal_delta = -scroll::vertical(
+                            delta,
+                            self.wheel_scalar,
+                            self.pixel_wheel_scalar,
+                        );
+
+                        if normal_delta != 0.0 {
+                            if let Some(target) =
+                                self.target_at(&bounds, cursor_position.y)
+                            {
+                                self.move_target(
+                                    target,
+                                    messages,
+                                    normal_delta,
+                                );
+
+                                return event::Status::Captured;
+                            }
+                        }
+                    }
+                }
+                mouse::Event::ButtonPressed(mouse::Button::Left) => {
+                    let bounds = layout.bounds();
+
+                    if bounds.contains(cursor_position) {
+                        let target =
+                            match self.target_at(&bounds, cursor_position.y) {
+                                Some(target) => target,
+                                None => return event::Status::Ignored,
+                            };
+
+                        let click = mouse::Click::new(
+                            cursor_position,
+                            self.state.last_click,
+                        );
+
+                        match click.kind() {
+                            mouse::click::Kind::Single => {
+                                self.state.drag_target = Some(target);
+                                self.state.prev_drag_y = cursor_position.y;
+                            }
+                            _ => {
+                                self.state.drag_target = None;
+
+                                let indexes = match target {
+                                    DragTarget::Handle(index) => vec![index],
+                                    DragTarget::Span(a, b) => vec![a, b],
+                                };
+
+                                for index in indexes {
+                                    let (min, max) = self.limits(&[index]);
+
+                                    let normal = self.state.handles[index]
+                                        .default
+                                        .as_f32()
+                                        .max(min)
+                                        .min(max);
+
+                                    self.state.set_normal(index, normal.into());
+
+                                    messages.push((self.on_change)(
+                                        index,
+                                        normal.into(),
+                                    ));
+                                }
+                            }
+                        }
+
+                        self.state.last_click = Some(click);
+                        self.state.is_focused = true;
+                        self.state.focused_handle = match target {
+                            DragTarget::Handle(index) => index,
+                            DragTarget::Span(a, _) => a,
+                        };
+
+                        return event::Status::Captured;
+                    } else {
+                        self.state.is_focused = false;
+                    }
+                }
+                mouse::Event::ButtonReleased(mouse::Button::Left)
+                    if self.state.drag_target.is_some() =>
+                {
+                    self.state.drag_target = None;
+                    self.state.sync_continuous_normals();
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            },
+            Event::Keyboard(keyboard_event) => match keyboard_event {
+                keyboard::Event::KeyPressed {
+                    key_code,
+                    modifiers,
+                } => {
+                    self.state.pressed_modifiers = modifiers;
+
+                    if let Some(index) = self.state.focused_handle() {
+                        if let Some(normal) =
+                            self.state.handles.get(index).and_then(|handle| {
+                                focus::key_normal(
+                                    key_code,
+                                    handle,
+                                    self.key_step,
+                                    self.page_step,
+                                )
+                            })
+                        {
+                            let (min, max) = self.limits(&[index]);
+                            let normal: Normal =
+                                normal.as_f32().max(min).min(max).into();
+
+                            self.state.set_normal(index, normal);
+
+                            messages.push((self.on_change)(index, normal));
+                        }
+                    }
+
+                    return event::Status::Captured;
+                }
+                keyboard::Event::KeyReleased { modifiers, .. } => {
+                    self.state.pressed_modifiers = modifiers;
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+
+        let tooltip_target = self.state.drag_target.or_else(|| {
+            if bounds.contains(cursor_position) {
+                self.target_at(&bounds, cursor_position.y)
+            } else {
+                None
+            }
+        });
+
+        let tooltips: Vec<(usize, String)> =
+            match (&self.tooltip, tooltip_target) {
+                (Some(format), Some(target)) => {
+                    let indexes = match target {
+                        DragTarget::Handle(index) => vec![index],
+                        DragTarget::Span(a, b) => vec![a, b],
+                    };
+
+                    indexes
+                        .into_iter()
+                        .map(|i| (i, format(i, self.state.handles[i].value)))
+                        .collect()
+                }
+                _ => Vec::new(),
+            };
+
+        renderer.draw(
+            bounds,
+            cursor_position,
+            &self.state.normals(),
+            self.handle_height,
+            self.state.drag_target,
+            self.state.focused_handle(),
+            &tooltips,
+            viewport,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`VRangeSlider`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`VRangeSlider`] in your user interface.
+///
+/// [`VRangeSlider`]: struct.VRangeSlider.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`VRangeSlider`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`VRangeSlider`]
+    ///   * the current cursor position
+    ///   * the current normal of each handle
+    ///   * the height of the handles in pixels
+    ///   * what is currently being dragged, if anything
+    ///   * the index of the handle that has keyboard focus, if any
+    ///   * the index and tooltip text of each handle that shows a tooltip
+    ///   * the visible bounds that the tooltips must stay inside
+    ///   * the style of the [`VRangeSlider`]
+    ///
+    /// [`VRangeSlider`]: struct.VRangeSlider.html
+    #[allow(clippy::too_many_arguments)]
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        normals: &[Normal],
+        handle_height: u16,
+        drag_target: Option<DragTarget>,
+        focused_handle: Option<usize>,
+        tooltips: &[(usize, String)],
+        viewport: &Rectangle,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<VRangeSlider<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        v_range_slider: VRangeSlider<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(v_range_slider)
+    }
+}
@@ -0,0 +1,64 @@
+//! Editing the text of the value entry of a parameter widget
+
+use iced_native::keyboard;
+
+use crate::core::Normal;
+
+/// Parses the text of a value entry into a [`Normal`], or returns `None` if
+/// the text is not a valid value.
+///
+/// [`Normal`]: ../../core/normal/struct.Normal.html
+pub(crate) type Parse = Box<dyn Fn(&str) -> Option<Normal>>;
+
+/// The result of a keyboard event on the text of an open value entry
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Update {
+    /// The text was edited
+    Changed,
+    /// The user wants to commit the text
+    Submit,
+    /// The user wants to close the entry without committing
+    Cancel,
+    /// The event did not affect the entry
+    Ignored,
+}
+
+/// Returns whether a click while holding down the `pressed` modifier keys
+/// opens the value entry.
+///
+/// This is never the case when `entry_keys` is empty, or when it is the same
+/// as `drag_keys`, so that a click that starts a fine drag never opens the
+/// value entry.
+pub(crate) fn is_entry_click(
+    pressed: keyboard::Modifiers,
+    entry_keys: keyboard::Modifiers,
+    drag_keys: keyboard::Modifiers,
+) -> bool {
+    entry_keys != keyboard::Modifiers::default()
+        && entry_keys != drag_keys
+        && pressed.matches(entry_keys)
+}
+
+/// Applies a keyboard event to the text of an open value entry.
+pub(crate) fn update(text: &mut String, event: keyboard::Event) -> Update {
+    match event {
+        keyboard::Event::CharacterReceived(c) if !c.is_control() => {
+            text.push(c);
+
+            Update::Changed
+        }
+        keyboard::Event::KeyPressed { key_code, .. } => match key_code {
+            keyboard::KeyCode::Backspace => {
+                let _ = text.pop();
+
+                Update::Changed
+            }
+            keyboard::KeyCode::Enter | keyboard::KeyCode::NumpadEnter => {
+                Update::Submit
+            }
+            keyboard::KeyCode::Escape => Update::Cancel,
+            _ => Update::Ignored,
+        },
+        _ => Update::Ignored,
+    }
+}
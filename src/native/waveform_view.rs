@@ -0,0 +1,903 @@
+//! Display the peak amplitude of a sample buffer over time. The view can be
+//! zoomed and scrolled, and a region and start/loop/end markers can be
+//! selected.
+
+use std::fmt::Debug;
+
+use iced_native::{
+    event, keyboard, layout, mouse, Clipboard, Element, Event, Hasher, Layout,
+    Length, Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+use crate::native::scroll;
+
+static DEFAULT_WIDTH: u16 = 400;
+static DEFAULT_HEIGHT: u16 = 100;
+static DEFAULT_ZOOM_SCALAR: f32 = 0.2;
+static DEFAULT_SCROLL_SCALAR: f32 = 0.1;
+static DEFAULT_MARKER_HIT_RADIUS: f32 = 4.0;
+static MIN_VISIBLE_SAMPLES: f64 = 16.0;
+
+/// The number of samples summarized by each peak in the lowest level of the
+/// peak pyramid
+static BASE_BLOCK_SIZE: usize = 16;
+
+/// A marker in a [`WaveformView`].
+///
+/// [`WaveformView`]: struct.WaveformView.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MarkerKind {
+    /// The position playback starts at
+    Start,
+    /// The position playback loops back to
+    Loop,
+    /// The position playback ends at
+    End,
+}
+
+/// An edit made by the user in a [`WaveformView`].
+///
+/// [`WaveformView`]: struct.WaveformView.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Edit {
+    /// A region was selected by dragging, or the selection was cleared by
+    /// clicking without dragging. The selection is given as the first
+    /// sample and the sample after the last sample of the region.
+    SelectionChanged(Option<(usize, usize)>),
+    /// A marker was placed or dragged to a new sample position.
+    MarkerMoved {
+        /// The marker that was moved
+        marker: MarkerKind,
+        /// The new sample position of the marker
+        position: usize,
+    },
+    /// The view was zoomed or scrolled.
+    ViewChanged {
+        /// The first visible sample
+        start: usize,
+        /// The number of visible samples
+        len: usize,
+    },
+}
+
+/// The part of a [`WaveformView`] that is being dragged.
+///
+/// [`WaveformView`]: struct.WaveformView.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DragTarget {
+    /// A new selection
+    Selection,
+    /// The given marker
+    Marker(MarkerKind),
+}
+
+/// A waveform view GUI widget that displays the peak amplitude of a sample
+/// buffer over time.
+///
+/// * Scroll with the mouse wheel to scroll horizontally.
+/// * Scroll while holding down the modifier key to zoom around the cursor.
+/// * Drag to select a region. Click without dragging to clear the
+///   selection.
+/// * Drag a marker to move it.
+/// * Click while holding down the modifier key to place a marker at the
+///   cursor. This places the first hidden marker out of start, loop and end,
+///   or moves the closest marker if all of them are shown.
+/// * Double-click to zoom out to the whole buffer.
+///
+/// [`WaveformView`]: struct.WaveformView.html
+#[allow(missing_debug_implementations)]
+pub struct WaveformView<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    on_edit: Box<dyn Fn(Edit) -> Message>,
+    zoom_scalar: f32,
+    scroll_scalar: f32,
+    modifier_keys: keyboard::Modifiers,
+    marker_hit_radius: f32,
+    width: Length,
+    height: Length,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer>
+    WaveformView<'a, Message, Renderer>
+{
+    /// Creates a new [`WaveformView`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`WaveformView`]
+    ///   * a function that will be called with each [`Edit`] made by the
+    ///     user
+    ///
+    /// [`State`]: struct.State.html
+    /// [`Edit`]: enum.Edit.html
+    /// [`WaveformView`]: struct.WaveformView.html
+    pub fn new<F>(state: &'a mut State, on_edit: F) -> Self
+    where
+        F: 'static + Fn(Edit) -> Message,
+    {
+        WaveformView {
+            state,
+            on_edit: Box::new(on_edit),
+            zoom_scalar: DEFAULT_ZOOM_SCALAR,
+            scroll_scalar: DEFAULT_SCROLL_SCALAR,
+            modifier_keys: keyboard::Modifiers {
+                control: true,
+                ..Default::default()
+            },
+            marker_hit_radius: DEFAULT_MARKER_HIT_RADIUS,
+            width: Length::Units(DEFAULT_WIDTH),
+            height: Length::Units(DEFAULT_HEIGHT),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`WaveformView`].
+    /// The default width is `Length::from(Length::Units(400))`.
+    ///
+    /// [`WaveformView`]: struct.WaveformView.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`WaveformView`].
+    /// The default height is `Length::from(Length::Units(100))`.
+    ///
+    /// [`WaveformView`]: struct.WaveformView.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`WaveformView`].
+    ///
+    /// [`WaveformView`]: struct.WaveformView.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the modifier keys that must be held down to zoom with the
+    /// mouse wheel, or to place a marker by clicking.
+    ///
+    /// The default modifier key is `Ctrl`.
+    ///
+    /// [`WaveformView`]: struct.WaveformView.html
+    pub fn modifier_keys(mut self, modifier_keys: keyboard::Modifiers) -> Self {
+        self.modifier_keys = modifier_keys;
+        self
+    }
+
+    /// Sets how much the view zooms per line scrolled by the mouse wheel
+    /// while holding down the modifier key. The number of visible samples
+    /// is divided by `1.0 + zoom_scalar` for each line scrolled up.
+    ///
+    /// The default value is `0.2`
+    pub fn zoom_scalar(mut self, scalar: f32) -> Self {
+        self.zoom_scalar = scalar;
+        self
+    }
+
+    /// Sets how far the view scrolls per line scrolled by the mouse wheel,
+    /// as a fraction of the visible width.
+    ///
+    /// The default value is `0.1`
+    pub fn scroll_scalar(mut self, scalar: f32) -> Self {
+        self.scroll_scalar = scalar;
+        self
+    }
+
+    /// Sets the distance in pixels from a marker within which the marker
+    /// can be grabbed.
+    ///
+    /// The default value is `4.0`
+    pub fn marker_hit_radius(mut self, hit_radius: f32) -> Self {
+        self.marker_hit_radius = hit_radius;
+        self
+    }
+
+    fn marker_at(
+        &self,
+        bounds: &Rectangle,
+        cursor: Point,
+    ) -> Option<MarkerKind> {
+        if !bounds.contains(cursor) {
+            return None;
+        }
+
+        self.state
+            .markers()
+            .into_iter()
+            .filter_map(|(kind, position)| {
+                let x = self.state.sample_to_x(bounds, position as f64)?;
+
+                let distance = (x - cursor.x).abs();
+
+                if distance <= self.marker_hit_radius {
+                    Some((kind, distance))
+                } else {
+                    None
+                }
+            })
+            .min_by(|(_, a), (_, b)| {
+                a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|(kind, _)| kind)
+    }
+
+    /// Returns the marker to place at `sample` when clicking while holding
+    /// down the modifier key: the first hidden marker, or the closest one
+    /// if all of them are shown.
+    fn marker_to_place(&self, sample: f64) -> MarkerKind {
+        [MarkerKind::Start, MarkerKind::Loop, MarkerKind::End]
+            .iter()
+            .copied()
+            .find(|marker| self.state.marker(*marker).is_none())
+            .unwrap_or_else(|| {
+                self.state
+                    .markers()
+                    .into_iter()
+                    .min_by(|(_, a), (_, b)| {
+                        let a = (*a as f64 - sample).abs();
+                        let b = (*b as f64 - sample).abs();
+
+                        a.partial_cmp(&b).unwrap_or(std::cmp::Ordering::Equal)
+                    })
+                    .map(|(kind, _)| kind)
+                    .unwrap_or(MarkerKind::Start)
+            })
+    }
+
+    fn push_view_changed(&self, messages: &mut Vec<Message>) {
+        messages.push((self.on_edit)(Edit::ViewChanged {
+            start: self.state.view_start(),
+            len: self.state.view_len(),
+        }));
+    }
+}
+
+/// A min/max summary of a sample buffer at successively halved
+/// resolutions, used to quickly find the peaks at any zoom level.
+#[derive(Debug, Clone, Default)]
+struct PeakPyramid {
+    levels: Vec<Vec<(f32, f32)>>,
+}
+
+impl PeakPyramid {
+    fn new(samples: &[f32]) -> Self {
+        let mut levels: Vec<Vec<(f32, f32)>> = Vec::new();
+
+        let mut level: Vec<(f32, f32)> =
+            samples.chunks(BASE_BLOCK_SIZE).map(fold_samples).collect();
+
+        while level.len() > 1 {
+            let next = level
+                .chunks(2)
+                .map(|pair| {
+                    pair.iter()
+                        .fold((f32::MAX, f32::MIN), |a, b| merge_peaks(a, *b))
+                })
+                .collect();
+
+            levels.push(level);
+            level = next;
+        }
+
+        levels.push(level);
+
+        Self { levels }
+    }
+
+    /// Returns the min and max sample in the range `start..end`.
+    fn peak(&self, samples: &[f32], start: f64, end: f64) -> (f32, f32) {
+        let first = start.floor().max(0.0) as usize;
+        let last = (end.ceil() as usize).max(first + 1).min(samples.len());
+
+        if first >= last {
+            return (0.0, 0.0);
+        }
+
+        if end - start < 1.0 {
+            // Interpolate between samples when zoomed in this far.
+            let a = samples[first];
+            let b = *samples.get(first + 1).unwrap_or(&a);
+            let value = a + ((b - a) * (start - first as f64) as f32);
+
+            return (value, value);
+        }
+
+        // Only the blocks that lie completely inside the range are read from
+        // the pyramid. The samples at either end are read directly.
+        let first_block = first.div_ceil(BASE_BLOCK_SIZE);
+        let last_block = last / BASE_BLOCK_SIZE;
+
+        if first_block >= last_block {
+            return fold_samples(&samples[first..last]);
+        }
+
+        let head = fold_samples(&samples[first..first_block * BASE_BLOCK_SIZE]);
+        let tail = fold_samples(&samples[last_block * BASE_BLOCK_SIZE..last]);
+
+        let mut peak = merge_peaks(head, tail);
+
+        // Walk up the pyramid, reading the unpaired blocks at either end of
+        // the range on each level.
+        let mut lo = first_block;
+        let mut hi = last_block;
+
+        for level in self.levels.iter() {
+            if lo >= hi {
+                break;
+            }
+
+            if lo % 2 == 1 {
+                peak = merge_peaks(peak, level[lo]);
+                lo += 1;
+            }
+            if hi % 2 == 1 {
+                hi -= 1;
+                peak = merge_peaks(peak, level[hi]);
+            }
+
+            lo /= 2;
+            hi /= 2;
+        }
+
+        peak
+    }
+}
+
+fn fold_samples(samples: &[f32]) -> (f32, f32) {
+    samples
+        .iter()
+        .fold((f32::MAX, f32::MIN), |(min, max), sample| {
+            (min.min(*sample), max.max(*sample))
+        })
+}
+
+fn merge_peaks(a: (f32, f32), b: (f32, f32)) -> (f32, f32) {
+    (a.0.min(b.0), a.1.max(b.1))
+}
+
+/// The local state of a [`WaveformView`].
+///
+/// [`WaveformView`]: struct.WaveformView.html
+#[derive(Debug, Clone)]
+pub struct State {
+    samples: Vec<f32>,
+    pyramid: PeakPyramid,
+    view_start: f64,
+    view_len: f64,
+    selection: Option<(usize, usize)>,
+    start_marker: Option<usize>,
+    loop_marker: Option<usize>,
+    end_marker: Option<usize>,
+    drag_target: Option<DragTarget>,
+    selection_anchor: f64,
+    has_dragged: bool,
+    pressed_modifiers: keyboard::Modifiers,
+    last_click: Option<mouse::Click>,
+}
+
+impl State {
+    /// Creates a new [`WaveformView`] state.
+    ///
+    /// It expects:
+    /// * the sample buffer to display, with sample values in the range
+    ///   `-1.0..=1.0`
+    ///
+    /// [`WaveformView`]: struct.WaveformView.html
+    pub fn new(samples: Vec<f32>) -> Self {
+        let mut state = Self {
+            samples: Vec::new(),
+            pyramid: PeakPyramid::default(),
+            view_start: 0.0,
+            view_len: 0.0,
+            selection: None,
+            start_marker: None,
+            loop_marker: None,
+            end_marker: None,
+            drag_target: None,
+            selection_anchor: 0.0,
+            has_dragged: false,
+            pressed_modifiers: Default::default(),
+            last_click: None,
+        };
+
+        state.set_samples(samples);
+
+        state
+    }
+
+    /// Replaces the sample buffer. This rebuilds the peak pyramid, zooms
+    /// out to the whole buffer, and clears the selection.
+    pub fn set_samples(&mut self, samples: Vec<f32>) {
+        self.pyramid = PeakPyramid::new(&samples);
+        self.samples = samples;
+        self.selection = None;
+        self.drag_target = None;
+
+        self.zoom_to_fit();
+    }
+
+    /// Returns the sample buffer.
+    pub fn samples(&self) -> &[f32] {
+        &self.samples
+    }
+
+    /// Sets the visible range of samples.
+    pub fn set_view(&mut self, start: usize, len: usize) {
+        self.view_start = start as f64;
+        self.view_len = len as f64;
+
+        self.constrain_view();
+    }
+
+    /// Returns the first visible sample.
+    pub fn view_start(&self) -> usize {
+        self.view_start.round() as usize
+    }
+
+    /// Returns the number of visible samples.
+    pub fn view_len(&self) -> usize {
+        self.view_len.round() as usize
+    }
+
+    /// Zooms out so that the whole buffer is visible.
+    pub fn zoom_to_fit(&mut self) {
+        self.view_start = 0.0;
+        self.view_len = self.samples.len() as f64;
+
+        self.constrain_view();
+    }
+
+    /// Sets the selected region. The selection is given as the first
+    /// sample and the sample after the last sample of the region.
+    pub fn set_selection(&mut self, selection: Option<(usize, usize)>) {
+        self.selection = selection;
+    }
+
+    /// Returns the selected region, if any.
+    pub fn selection(&self) -> Option<(usize, usize)> {
+        self.selection
+    }
+
+    /// Sets the sample position of a marker. Set this to `None` to hide
+    /// the marker.
+    pub fn set_marker(&mut self, marker: MarkerKind, position: Option<usize>) {
+        match marker {
+            MarkerKind::Start => self.start_marker = position,
+            MarkerKind::Loop => self.loop_marker = position,
+            MarkerKind::End => self.end_marker = position,
+        }
+    }
+
+    /// Returns the sample position of a marker, if it is shown.
+    pub fn marker(&self, marker: MarkerKind) -> Option<usize> {
+        match marker {
+            MarkerKind::Start => self.start_marker,
+            MarkerKind::Loop => self.loop_marker,
+            MarkerKind::End => self.end_marker,
+        }
+    }
+
+    /// Returns the part of the [`WaveformView`] that is currently being
+    /// dragged, if any.
+    ///
+    /// [`WaveformView`]: struct.WaveformView.html
+    pub fn drag_target(&self) -> Option<DragTarget> {
+        self.drag_target
+    }
+
+    fn markers(&self) -> Vec<(MarkerKind, usize)> {
+        [
+            (MarkerKind::Start, self.start_marker),
+            (MarkerKind::Loop, self.loop_marker),
+            (MarkerKind::End, self.end_marker),
+        ]
+        .iter()
+        .filter_map(|(kind, position)| position.map(|p| (*kind, p)))
+        .collect()
+    }
+
+    fn constrain_view(&mut self) {
+        let len = self.samples.len() as f64;
+
+        self.view_len =
+            self.view_len.min(len).max(MIN_VISIBLE_SAMPLES.min(len));
+        self.view_start = self.view_start.min(len - self.view_len).max(0.0);
+    }
+
+    fn x_to_sample(&self, bounds: &Rectangle, x: f32) -> f64 {
+        let normal = f64::from((x - bounds.x) / bounds.width);
+
+        (self.view_start + (normal * self.view_len))
+            .max(0.0)
+            .min(self.samples.len() as f64)
+    }
+
+    /// Returns the sample a marker dragged or placed at `sample` lands on.
+    /// Unlike the end of a selection, a marker must point at a sample, so
+    /// it can not be placed past the last one.
+    fn marker_position(&self, sample: f64) -> usize {
+        (sample.round() as usize).min(self.samples.len().saturating_sub(1))
+    }
+
+    fn sample_to_x(&self, bounds: &Rectangle, sample: f64) -> Option<f32> {
+        if self.view_len <= 0.0 {
+            return None;
+        }
+
+        let normal = (sample - self.view_start) / self.view_len;
+
+        if !(0.0..=1.0).contains(&normal) {
+            None
+        } else {
+            Some(bounds.x + (normal as f32 * bounds.width))
+        }
+    }
+
+    fn zoom(&mut self, bounds: &Rectangle, cursor_x: f32, factor: f64) {
+        let anchor_normal =
+            f64::from(((cursor_x - bounds.x) / bounds.width).clamp(0.0, 1.0));
+        let anchor = self.view_start + (anchor_normal * self.view_len);
+
+        self.view_len /= factor;
+        self.constrain_view();
+
+        self.view_start = anchor - (anchor_normal * self.view_len);
+        self.constrain_view();
+    }
+
+    fn columns(&self, width: f32) -> Vec<(f32, f32)> {
+        let num_columns = width.max(0.0).floor() as usize;
+
+        if num_columns == 0 || self.samples.is_empty() {
+            return Vec::new();
+        }
+
+        let samples_per_column = self.view_len / num_columns as f64;
+
+        (0..num_columns)
+            .map(|i| {
+                let start = self.view_start + (i as f64 * samples_per_column);
+
+                self.pyramid.peak(
+                    &self.samples,
+                    start,
+                    start + samples_per_column,
+                )
+            })
+            .collect()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for WaveformView<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        if bounds.width <= 0.0 || self.state.samples.is_empty() {
+            return event::Status::Ignored;
+        }
+
+        match event {
+            Event::Mouse(mouse_event) => match mouse_event {
+                mouse::Event::CursorMoved { .. } => {
+                    let sample =
+                        self.state.x_to_sample(&bounds, cursor_position.x);
+
+                    match self.state.drag_target {
+                        Some(DragTarget::Selection) => {
+                            self.state.has_dragged = true;
+
+                            let anchor = self.state.selection_anchor;
+
+                            let selection = (
+                                anchor.min(sample).round() as usize,
+                                anchor.max(sample).round() as usize,
+                            );
+
+                            if self.state.selection != Some(selection) {
+                                self.state.selection = Some(selection);
+
+                                messages.push((self.on_edit)(
+                                    Edit::SelectionChanged(Some(selection)),
+                                ));
+                            }
+
+                            return event::Status::Captured;
+                        }
+                        Some(DragTarget::Marker(marker)) => {
+                            let position = self.state.marker_position(sample);
+
+                            if self.state.marker(marker) != Some(position) {
+                                self.state.set_marker(marker, Some(position));
+
+                                messages.push((self.on_edit)(
+                                    Edit::MarkerMoved { marker, position },
+                                ));
+                            }
+
+                            return event::Status::Captured;
+                        }
+                        None => {}
+                    }
+                }
+                mouse::Event::WheelScrolled { delta } => {
+                    if !bounds.contains(cursor_position) {
+                        return event::Status::Ignored;
+                    }
+
+                    let (lines_x, lines_y) = scroll::lines(delta);
+
+                    if self.state.pressed_modifiers.matches(self.modifier_keys)
+                    {
+                        if lines_y == 0.0 || self.zoom_scalar == 0.0 {
+                            return event::Status::Ignored;
+                        }
+
+                        let factor = (1.0 + f64::from(self.zoom_scalar))
+                            .powf(f64::from(lines_y));
+
+                        self.state.zoom(&bounds, cursor_position.x, factor);
+                    } else {
+                        // Scrolling up or left moves the view towards the
+                        // start of the buffer.
+                        let lines =
+                            if lines_x != 0.0 { lines_x } else { lines_y };
+
+                        if lines == 0.0 || self.scroll_scalar == 0.0 {
+                            return event::Status::Ignored;
+                        }
+
+                        self.state.view_start -= f64::from(lines)
+                            * f64::from(self.scroll_scalar)
+                            * self.state.view_len;
+                        self.state.constrain_view();
+                    }
+
+                    self.push_view_changed(messages);
+
+                    return event::Status::Captured;
+                }
+                mouse::Event::ButtonPressed(mouse::Button::Left)
+                    if bounds.contains(cursor_position) =>
+                {
+                    let click = mouse::Click::new(
+                        cursor_position,
+                        self.state.last_click,
+                    );
+
+                    match click.kind() {
+                        mouse::click::Kind::Single => {
+                            if let Some(marker) =
+                                self.marker_at(&bounds, cursor_position)
+                            {
+                                self.state.drag_target =
+                                    Some(DragTarget::Marker(marker));
+                            } else if self
+                                .state
+                                .pressed_modifiers
+                                .matches(self.modifier_keys)
+                            {
+                                let sample = self
+                                    .state
+                                    .x_to_sample(&bounds, cursor_position.x);
+                                let marker = self.marker_to_place(sample);
+                                let position =
+                                    self.state.marker_position(sample);
+
+                                self.state.set_marker(marker, Some(position));
+
+                                messages.push((self.on_edit)(
+                                    Edit::MarkerMoved { marker, position },
+                                ));
+
+                                // Keep the new marker grabbed so it can
+                                // be dragged into place.
+                                self.state.drag_target =
+                                    Some(DragTarget::Marker(marker));
+                            } else {
+                                self.state.drag_target =
+                                    Some(DragTarget::Selection);
+                                self.state.selection_anchor = self
+                                    .state
+                                    .x_to_sample(&bounds, cursor_position.x);
+                                self.state.has_dragged = false;
+                            }
+                        }
+                        _ => {
+                            self.state.drag_target = None;
+
+                            self.state.zoom_to_fit();
+
+                            self.push_view_changed(messages);
+                        }
+                    }
+
+                    self.state.last_click = Some(click);
+
+                    return event::Status::Captured;
+                }
+                mouse::Event::ButtonReleased(mouse::Button::Left) => {
+                    if let Some(drag_target) = self.state.drag_target.take() {
+                        if drag_target == DragTarget::Selection
+                            && !self.state.has_dragged
+                            && self.state.selection.is_some()
+                        {
+                            self.state.selection = None;
+
+                            messages.push((self.on_edit)(
+                                Edit::SelectionChanged(None),
+                            ));
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
+                _ => {}
+            },
+            Event::Keyboard(keyboard_event) => match keyboard_event {
+                keyboard::Event::KeyPressed { modifiers, .. } => {
+                    self.state.pressed_modifiers = modifiers;
+
+                    return event::Status::Captured;
+                }
+                keyboard::Event::KeyReleased { modifiers, .. } => {
+                    self.state.pressed_modifiers = modifiers;
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+
+        let columns = self.state.columns(bounds.width);
+
+        let view_start = self.state.view_start;
+        let view_len = self.state.view_len;
+        let to_view_normal = |sample: usize| -> f32 {
+            if view_len <= 0.0 {
+                0.0
+            } else {
+                ((sample as f64 - view_start) / view_len) as f32
+            }
+        };
+
+        let selection = self
+            .state
+            .selection
+            .map(|(start, end)| (to_view_normal(start), to_view_normal(end)));
+
+        let markers: Vec<(MarkerKind, f32)> = self
+            .state
+            .markers()
+            .into_iter()
+            .map(|(kind, position)| (kind, to_view_normal(position)))
+            .filter(|(_, x)| *x >= 0.0 && *x <= 1.0)
+            .collect();
+
+        let active_marker = match self.state.drag_target {
+            Some(DragTarget::Marker(marker)) => Some(marker),
+            Some(DragTarget::Selection) => None,
+            None => self.marker_at(&bounds, cursor_position),
+        };
+
+        renderer.draw(
+            bounds,
+            cursor_position,
+            &columns,
+            selection,
+            &markers,
+            active_marker,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`WaveformView`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`WaveformView`] in your user interface.
+///
+/// [`WaveformView`]: struct.WaveformView.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`WaveformView`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`WaveformView`]
+    ///   * the current cursor position
+    ///   * the min and max sample of each pixel column
+    ///   * the start and end of the selection, if any, where `0.0` is the
+    ///     left edge and `1.0` is the right edge of the bounds. These may lie
+    ///     outside of the bounds.
+    ///   * the visible markers, where `0.0` is the left edge and `1.0` is
+    ///     the right edge of the bounds
+    ///   * the marker that is hovered or being dragged, if any
+    ///   * the style of the [`WaveformView`]
+    ///
+    /// [`WaveformView`]: struct.WaveformView.html
+    #[allow(clippy::too_many_arguments)]
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        columns: &[(f32, f32)],
+        selection: Option<(f32, f32)>,
+        markers: &[(MarkerKind, f32)],
+        active_marker: Option<MarkerKind>,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<WaveformView<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        waveform_view: WaveformView<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(waveform_view)
+    }
+}
@@ -0,0 +1,500 @@
+//! Display a single-cycle waveform, or a stack of wavetable frames where the
+//! current frame is controlled by a [`NormalParam`].
+//!
+//! [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
+
+use std::fmt::Debug;
+
+use iced_native::{
+    event, keyboard, layout, mouse, Clipboard, Element, Event, Hasher, Layout,
+    Length, Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+use crate::core::{Normal, NormalParam};
+use crate::native::scroll;
+
+static DEFAULT_WIDTH: u16 = 200;
+static DEFAULT_HEIGHT: u16 = 100;
+static DEFAULT_SCALAR: f32 = 0.9575;
+static DEFAULT_WHEEL_SCALAR: f32 = 0.01;
+static DEFAULT_PIXEL_WHEEL_SCALAR: f32 = 0.0005;
+static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+
+/// A wavetable view GUI widget that displays a single-cycle waveform, or a
+/// stack of wavetable frames drawn as a pseudo-3D pile.
+///
+/// When there is more than one frame, drag up and down to select the
+/// current frame. The position of the current frame is controlled by a
+/// [`NormalParam`].
+///
+/// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+/// [`WavetableView`]: struct.WavetableView.html
+#[allow(missing_debug_implementations)]
+pub struct WavetableView<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    on_change: Box<dyn Fn(Normal) -> Message>,
+    scalar: f32,
+    wheel_scalar: f32,
+    pixel_wheel_scalar: f32,
+    modifier_scalar: f32,
+    modifier_keys: keyboard::Modifiers,
+    width: Length,
+    height: Length,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer>
+    WavetableView<'a, Message, Renderer>
+{
+    /// Creates a new [`WavetableView`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`WavetableView`]
+    ///   * a function that will be called when the frame position is
+    ///     changed by the user
+    ///
+    /// [`State`]: struct.State.html
+    /// [`WavetableView`]: struct.WavetableView.html
+    pub fn new<F>(state: &'a mut State, on_change: F) -> Self
+    where
+        F: 'static + Fn(Normal) -> Message,
+    {
+        WavetableView {
+            state,
+            on_change: Box::new(on_change),
+            scalar: DEFAULT_SCALAR,
+            wheel_scalar: DEFAULT_WHEEL_SCALAR,
+            pixel_wheel_scalar: DEFAULT_PIXEL_WHEEL_SCALAR,
+            modifier_scalar: DEFAULT_MODIFIER_SCALAR,
+            modifier_keys: keyboard::Modifiers {
+                control: true,
+                ..Default::default()
+            },
+            width: Length::Units(DEFAULT_WIDTH),
+            height: Length::Units(DEFAULT_HEIGHT),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`WavetableView`].
+    /// The default width is `Length::from(Length::Units(200))`.
+    ///
+    /// [`WavetableView`]: struct.WavetableView.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`WavetableView`].
+    /// The default height is `Length::from(Length::Units(100))`.
+    ///
+    /// [`WavetableView`]: struct.WavetableView.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`WavetableView`].
+    ///
+    /// [`WavetableView`]: struct.WavetableView.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the modifier keys of the [`WavetableView`].
+    ///
+    /// The default modifier key is `Ctrl`.
+    ///
+    /// [`WavetableView`]: struct.WavetableView.html
+    pub fn modifier_keys(mut self, modifier_keys: keyboard::Modifiers) -> Self {
+        self.modifier_keys = modifier_keys;
+        self
+    }
+
+    /// Sets the scalar to use when the user drags up and down to select a
+    /// frame.
+    ///
+    /// For example, a scalar of `0.5` will cause the frame position to move
+    /// half as fast as the mouse.
+    ///
+    /// The default scalar is `0.9575`.
+    ///
+    /// [`WavetableView`]: struct.WavetableView.html
+    pub fn scalar(mut self, scalar: f32) -> Self {
+        self.scalar = scalar;
+        self
+    }
+
+    /// Sets how much the [`Normal`] frame position will change per line
+    /// scrolled by the mouse wheel.
+    ///
+    /// This can be set to `0.0` to disable the scroll wheel from moving the
+    /// frame position.
+    ///
+    /// The default value is `0.01`
+    ///
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn wheel_scalar(mut self, wheel_scalar: f32) -> Self {
+        self.wheel_scalar = wheel_scalar;
+        self
+    }
+
+    /// Sets how much the [`Normal`] value will change for the
+    /// [`WavetableView`] per pixel scrolled on a trackpad, which reports the
+    /// exact distance scrolled instead of lines.
+    ///
+    /// This can be set to `0.0` to disable trackpad scrolling while still
+    /// allowing the scroll wheel.
+    ///
+    /// The default value is `0.0005`
+    ///
+    /// [`WavetableView`]: struct.WavetableView.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn pixel_wheel_scalar(mut self, pixel_wheel_scalar: f32) -> Self {
+        self.pixel_wheel_scalar = pixel_wheel_scalar;
+        self
+    }
+
+    /// Sets the scalar to use when the user drags while holding down the
+    /// modifier key.
+    ///
+    /// For example, a scalar of `0.5` will cause the frame position to move
+    /// half as fast as the mouse.
+    ///
+    /// The default scalar is `0.02`, and the default modifier key is `Ctrl`.
+    ///
+    /// [`WavetableView`]: struct.WavetableView.html
+    pub fn modifier_scalar(mut self, scalar: f32) -> Self {
+        self.modifier_scalar = scalar;
+        self
+    }
+
+    fn move_virtual_slider(
+        &mut self,
+        messages: &mut Vec<Message>,
+        mut normal_delta: f32,
+    ) {
+        if self.state.pressed_modifiers.matches(self.modifier_keys) {
+            normal_delta *= self.modifier_scalar;
+        }
+
+        let normal =
+            (self.state.continuous_normal - normal_delta).clamp(0.0, 1.0);
+
+        self.state.continuous_normal = normal;
+
+        self.state.normal_param.value = normal.into();
+
+        messages.push((self.on_change)(self.state.normal_param.value));
+    }
+}
+
+/// The local state of a [`WavetableView`].
+///
+/// [`WavetableView`]: struct.WavetableView.html
+#[derive(Debug, Clone)]
+pub struct State {
+    frames: Vec<Vec<f32>>,
+    normal_param: NormalParam,
+    is_dragging: bool,
+    prev_drag_y: f32,
+    continuous_normal: f32,
+    pressed_modifiers: keyboard::Modifiers,
+    last_click: Option<mouse::Click>,
+}
+
+impl State {
+    /// Creates a new [`WavetableView`] state that displays a single-cycle
+    /// waveform.
+    ///
+    /// It expects:
+    /// * the samples of one cycle of the waveform, in the range
+    ///   `-1.0..=1.0`
+    ///
+    /// [`WavetableView`]: struct.WavetableView.html
+    pub fn new(waveform: Vec<f32>) -> Self {
+        Self::with_frames(vec![waveform], NormalParam::default())
+    }
+
+    /// Creates a new [`WavetableView`] state that displays a stack of
+    /// wavetable frames.
+    ///
+    /// It expects:
+    /// * the frames of the wavetable, each one holding the samples of one
+    ///   cycle in the range `-1.0..=1.0`
+    /// * a [`NormalParam`] that controls the position of the current frame
+    ///
+    /// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+    /// [`WavetableView`]: struct.WavetableView.html
+    pub fn with_frames(
+        frames: Vec<Vec<f32>>,
+        normal_param: NormalParam,
+    ) -> Self {
+        Self {
+            frames,
+            normal_param,
+            is_dragging: false,
+            prev_drag_y: 0.0,
+            continuous_normal: normal_param.value.as_f32(),
+            pressed_modifiers: Default::default(),
+            last_click: None,
+        }
+    }
+
+    /// Replaces all the frames with a single-cycle waveform.
+    pub fn set_waveform(&mut self, waveform: Vec<f32>) {
+        self.frames = vec![waveform];
+    }
+
+    /// Replaces all the frames of the wavetable.
+    pub fn set_frames(&mut self, frames: Vec<Vec<f32>>) {
+        self.frames = frames;
+    }
+
+    /// Returns the frames of the wavetable.
+    pub fn frames(&self) -> &[Vec<f32>] {
+        &self.frames
+    }
+
+    /// Set the normalized frame position of the [`WavetableView`].
+    ///
+    /// [`WavetableView`]: struct.WavetableView.html
+    pub fn set_normal(&mut self, normal: Normal) {
+        self.normal_param.value = normal;
+        self.continuous_normal = normal.into();
+    }
+
+    /// Get the normalized frame position of the [`WavetableView`].
+    ///
+    /// [`WavetableView`]: struct.WavetableView.html
+    pub fn normal(&self) -> Normal {
+        self.normal_param.value
+    }
+
+    /// Returns the index of the frame at the current frame position.
+    pub fn current_frame(&self) -> usize {
+        if self.frames.len() <= 1 {
+            0
+        } else {
+            (self.normal_param.value.as_f32() * (self.frames.len() - 1) as f32)
+                .round() as usize
+        }
+    }
+
+    /// Is the [`WavetableView`] currently in the dragging state?
+    ///
+    /// [`WavetableView`]: struct.WavetableView.html
+    pub fn is_dragging(&self) -> bool {
+        self.is_dragging
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for WavetableView<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        // A single waveform has no frame position to select.
+        if self.state.frames.len() <= 1 {
+            return event::Status::Ignored;
+        }
+
+        match event {
+            Event::Mouse(mouse_event) => match mouse_event {
+                mouse::Event::CursorMoved { .. } if self.state.is_dragging => {
+                    let bounds_height = layout.bounds().height;
+
+                    if bounds_height > 0.0 {
+                        let normal_delta = (cursor_position.y
+                            - self.state.prev_drag_y)
+                            / bounds_height
+                            * self.scalar;
+
+                        self.state.prev_drag_y = cursor_position.y;
+
+                        self.move_virtual_slider(messages, normal_delta);
+
+                        return event::Status::Captured;
+                    }
+                }
+                mouse::Event::WheelScrolled { delta } => {
+                    if self.wheel_scalar == 0.0 {
+                        return event::Status::Ignored;
+                    }
+
+                    if layout.bounds().contains(cursor_position) {
+                        let normal_delta = -scroll::vertical(
+                            delta,
+                            self.wheel_scalar,
+                            self.pixel_wheel_scalar,
+                        );
+
+                        if normal_delta != 0.0 {
+                            self.move_virtual_slider(messages, normal_delta);
+
+                            return event::Status::Captured;
+                        }
+                    }
+                }
+                mouse::Event::ButtonPressed(mouse::Button::Left)
+                    if layout.bounds().contains(cursor_position) =>
+                {
+                    let click = mouse::Click::new(
+                        cursor_position,
+                        self.state.last_click,
+                    );
+
+                    match click.kind() {
+                        mouse::click::Kind::Single => {
+                            self.state.is_dragging = true;
+                            self.state.prev_drag_y = cursor_position.y;
+                        }
+                        _ => {
+                            self.state.is_dragging = false;
+
+                            self.state.normal_param.value =
+                                self.state.normal_param.default;
+                            self.state.continuous_normal =
+                                self.state.normal_param.value.as_f32();
+
+                            messages.push((self.on_change)(
+                                self.state.normal_param.value,
+                            ));
+                        }
+                    }
+
+                    self.state.last_click = Some(click);
+
+                    return event::Status::Captured;
+                }
+                mouse::Event::ButtonReleased(mouse::Button::Left) => {
+                    self.state.is_dragging = false;
+                    self.state.continuous_normal =
+                        self.state.normal_param.value.as_f32();
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            },
+            Event::Keyboard(keyboard_event) => match keyboard_event {
+                keyboard::Event::KeyPressed { modifiers, .. } => {
+                    self.state.pressed_modifiers = modifiers;
+
+                    return event::Status::Captured;
+                }
+                keyboard::Event::KeyReleased { modifiers, .. } => {
+                    self.state.pressed_modifiers = modifiers;
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        renderer.draw(
+            layout.bounds(),
+            cursor_position,
+            &self.state.frames,
+            self.state.current_frame(),
+            self.state.is_dragging,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`WavetableView`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`WavetableView`] in your user interface.
+///
+/// [`WavetableView`]: struct.WavetableView.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`WavetableView`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`WavetableView`]
+    ///   * the current cursor position
+    ///   * the frames of the wavetable
+    ///   * the index of the current frame
+    ///   * whether the frame position is currently being dragged
+    ///   * the style of the [`WavetableView`]
+    ///
+    /// [`WavetableView`]: struct.WavetableView.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        frames: &[Vec<f32>],
+        current_frame: usize,
+        is_dragging: bool,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<WavetableView<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        wavetable_view: WavetableView<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(wavetable_view)
+    }
+}
@@ -13,9 +13,14 @@ use iced_native::{
 use std::hash::Hash;
 
 use crate::core::{Normal, NormalParam};
+use crate::native::{focus, scroll};
 use crate::IntRange;
 
+static DEFAULT_WHEEL_SCALAR: f32 = 0.01;
+static DEFAULT_PIXEL_WHEEL_SCALAR: f32 = 0.0005;
 static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+static DEFAULT_KEY_STEP: f32 = 0.01;
+static DEFAULT_PAGE_STEP: f32 = 0.1;
 
 /// A 2D XY pad GUI widget that controls two [`NormalParam`] parameters at
 /// once. One in the `x` coordinate and one in the `y` coordinate.
@@ -29,8 +34,15 @@ static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
 pub struct XYPad<'a, Message, Renderer: self::Renderer> {
     state: &'a mut State,
     on_change: Box<dyn Fn(Normal, Normal) -> Message>,
+    on_grab: Option<Box<dyn Fn() -> Message>>,
+    on_release: Option<Box<dyn Fn() -> Message>>,
+    tooltip: Option<Box<dyn Fn(Normal, Normal) -> String>>,
+    wheel_scalar: f32,
+    pixel_wheel_scalar: f32,
     modifier_scalar: f32,
     modifier_keys: keyboard::Modifiers,
+    key_step: f32,
+    page_step: f32,
     size: Length,
     style: Renderer::Style,
 }
@@ -51,7 +63,14 @@ impl<'a, Message, Renderer: self::Renderer> XYPad<'a, Message, Renderer> {
         XYPad {
             state,
             on_change: Box::new(on_change),
+            on_grab: None,
+            on_release: None,
+            tooltip: None,
+            wheel_scalar: DEFAULT_WHEEL_SCALAR,
+            pixel_wheel_scalar: DEFAULT_PIXEL_WHEEL_SCALAR,
             modifier_scalar: DEFAULT_MODIFIER_SCALAR,
+            key_step: DEFAULT_KEY_STEP,
+            page_step: DEFAULT_PAGE_STEP,
             modifier_keys: keyboard::Modifiers {
                 control: true,
                 ..Default::default()
@@ -77,6 +96,92 @@ impl<'a, Message, Renderer: self::Renderer> XYPad<'a, Message, Renderer> {
         self
     }
 
+    /// Sets how much the [`Normal`] values will change for the [`XYPad`] per
+    /// line scrolled by the mouse wheel. Scrolling up and down moves the `y`
+    /// value, and scrolling left and right moves the `x` value.
+    ///
+    /// This can be set to `0.0` to disable the scroll wheel from moving the
+    /// parameters.
+    ///
+    /// The default value is `0.01`
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn wheel_scalar(mut self, wheel_scalar: f32) -> Self {
+        self.wheel_scalar = wheel_scalar;
+        self
+    }
+
+    /// Sets how much the [`Normal`] values will change for the [`XYPad`] per
+    /// pixel scrolled on a trackpad, which reports the exact distance
+    /// scrolled instead of lines.
+    ///
+    /// This can be set to `0.0` to disable trackpad scrolling while still
+    /// allowing the scroll wheel.
+    ///
+    /// The default value is `0.0005`
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn pixel_wheel_scalar(mut self, pixel_wheel_scalar: f32) -> Self {
+        self.pixel_wheel_scalar = pixel_wheel_scalar;
+        self
+    }
+
+    /// Sets a function that will be called when the user grabs the
+    /// [`XYPad`] to start changing its value, before any call to
+    /// `on_change`.
+    ///
+    /// This is also called around each keyboard step and double-click
+    /// reset, and once at the start of a scroll wheel gesture. Use it to
+    /// notify a plugin host that an edit has begun, so that automation is
+    /// recorded correctly.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn on_grab<F>(mut self, on_grab: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_grab = Some(Box::new(on_grab));
+        self
+    }
+
+    /// Sets a function that will be called when the user releases the
+    /// [`XYPad`] after changing its value.
+    ///
+    /// This is also called around each keyboard step and double-click
+    /// reset. A scroll wheel gesture is released once, with the first event
+    /// that arrives after the wheel has been idle for 300 milliseconds, or
+    /// as soon as the cursor leaves the [`XYPad`] or any other input
+    /// arrives. Use it to notify a plugin host that an edit has ended.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn on_release<F>(mut self, on_release: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_release = Some(Box::new(on_release));
+        self
+    }
+
+    /// Sets a function that formats the `x` and `y` values of the [`XYPad`]
+    /// for a tooltip, which is displayed while the [`XYPad`] is hovered or
+    /// dragged.
+    ///
+    /// `format` usually calls [`ParamRange::format`] of the ranges the
+    /// parameters use, for example
+    /// `move |x, y| format!("{}, {}", x_range.format(x), y_range.format(y))`.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`ParamRange::format`]: ../../core/range/trait.ParamRange.html#tymethod.format
+    pub fn tooltip<F>(mut self, format: F) -> Self
+    where
+        F: 'static + Fn(Normal, Normal) -> String,
+    {
+        self.tooltip = Some(Box::new(format));
+        self
+    }
+
     /// Sets the modifier keys of the [`XYPad`].
     ///
     /// The default modifier key is `Ctrl`.
@@ -100,6 +205,43 @@ impl<'a, Message, Renderer: self::Renderer> XYPad<'a, Message, Renderer> {
         self.modifier_scalar = scalar;
         self
     }
+
+    /// Sets how much the [`Normal`] values of the [`XYPad`] will change when
+    /// an arrow key is pressed while it has keyboard focus. `Left` and
+    /// `Right` move the `x` value, `Up` and `Down` move the `y` value.
+    ///
+    /// The default value is `0.01`.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn key_step(mut self, key_step: f32) -> Self {
+        self.key_step = key_step;
+        self
+    }
+
+    /// Sets how much the `y` [`Normal`] value of the [`XYPad`] will change
+    /// when `PageUp` or `PageDown` is pressed while it has keyboard focus.
+    ///
+    /// The default value is `0.1`.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn page_step(mut self, page_step: f32) -> Self {
+        self.page_step = page_step;
+        self
+    }
+
+    fn grab(&self, messages: &mut Vec<Message>) {
+        if let Some(on_grab) = &self.on_grab {
+            messages.push((on_grab)());
+        }
+    }
+
+    fn release(&self, messages: &mut Vec<Message>) {
+        if let Some(on_release) = &self.on_release {
+            messages.push((on_release)());
+        }
+    }
 }
 
 /// The local state of a [`XYPad`].
@@ -110,11 +252,13 @@ pub struct State {
     normal_param_x: NormalParam,
     normal_param_y: NormalParam,
     is_dragging: bool,
+    is_focused: bool,
     prev_drag_x: f32,
     prev_drag_y: f32,
     continuous_normal_x: f32,
     continuous_normal_y: f32,
     pressed_modifiers: keyboard::Modifiers,
+    scroll_gesture: scroll::Gesture,
     last_click: Option<mouse::Click>,
 }
 
@@ -135,11 +279,13 @@ impl State {
             normal_param_x,
             normal_param_y,
             is_dragging: false,
+            is_focused: false,
             prev_drag_x: 0.0,
             prev_drag_y: 0.0,
             continuous_normal_x: normal_param_x.value.as_f32(),
             continuous_normal_y: normal_param_y.value.as_f32(),
             pressed_modifiers: Default::default(),
+            scroll_gesture: Default::default(),
             last_click: None,
         }
     }
@@ -230,6 +376,20 @@ impl State {
     }
 }
 
+impl focus::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
 impl<'a, Message, Renderer> Widget<Message, Renderer>
     for XYPad<'a, Message, Renderer>
 where
@@ -270,6 +430,13 @@ where
         _clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
     ) -> event::Status {
+        self.state.scroll_gesture.update(
+            &event,
+            layout.bounds().contains(cursor_position),
+            &self.on_release,
+            messages,
+        );
+
         match event {
             Event::Mouse(mouse_event) => match mouse_event {
                 mouse::Event::CursorMoved { .. } => {
@@ -322,6 +489,59 @@ where
                         }
                     }
                 }
+                mouse::Event::WheelScrolled { delta } => {
+                    if self.wheel_scalar == 0.0 {
+                        return event::Status::Ignored;
+                    }
+
+                    if layout.bounds().contains(cursor_position) {
+                        let mut movement_x = scroll::horizontal(
+                            delta,
+                            self.wheel_scalar,
+                            self.pixel_wheel_scalar,
+                        );
+                        let mut movement_y = scroll::vertical(
+                            delta,
+                            self.wheel_scalar,
+                            self.pixel_wheel_scalar,
+                        );
+
+                        if movement_x != 0.0 || movement_y != 0.0 {
+                            if self
+                                .state
+                                .pressed_modifiers
+                                .matches(self.modifier_keys)
+                            {
+                                movement_x *= self.modifier_scalar;
+                                movement_y *= self.modifier_scalar;
+                            }
+
+                            let normal_x = (self.state.continuous_normal_x
+                                + movement_x)
+                                .clamp(0.0, 1.0);
+                            let normal_y = (self.state.continuous_normal_y
+                                + movement_y)
+                                .clamp(0.0, 1.0);
+
+                            self.state
+                                .scroll_gesture
+                                .scroll(&self.on_grab, messages);
+
+                            self.state.continuous_normal_x = normal_x;
+                            self.state.normal_param_x.value = normal_x.into();
+
+                            self.state.continuous_normal_y = normal_y;
+                            self.state.normal_param_y.value = normal_y.into();
+
+                            messages.push((self.on_change)(
+                                self.state.normal_param_x.value,
+                                self.state.normal_param_y.value,
+                            ));
+
+                            return event::Status::Captured;
+                        }
+                    }
+                }
                 mouse::Event::ButtonPressed(mouse::Button::Left) => {
                     if layout.bounds().contains(cursor_position) {
                         let click = mouse::Click::new(
@@ -332,6 +552,7 @@ where
                         match click.kind() {
                             mouse::click::Kind::Single => {
                                 self.state.is_dragging = true;
+                                self.grab(messages);
                                 self.state.prev_drag_x = cursor_position.x;
                                 self.state.prev_drag_y = cursor_position.y;
 
@@ -369,6 +590,8 @@ where
                             _ => {
                                 self.state.is_dragging = false;
 
+                                self.grab(messages);
+
                                 self.state.normal_param_x.value =
                                     self.state.normal_param_x.default;
                                 self.state.normal_param_y.value =
@@ -378,15 +601,24 @@ where
                                     self.state.normal_param_x.value,
                                     self.state.normal_param_y.value,
                                 ));
+
+                                self.release(messages);
                             }
                         }
 
                         self.state.last_click = Some(click);
+                        self.state.is_focused = true;
 
                         return event::Status::Captured;
+                    } else {
+                        self.state.is_focused = false;
                     }
                 }
                 mouse::Event::ButtonReleased(mouse::Button::Left) => {
+                    if self.state.is_dragging {
+                        self.release(messages);
+                    }
+
                     self.state.is_dragging = false;
                     self.state.continuous_normal_x =
                         self.state.normal_param_x.value.as_f32();
@@ -398,9 +630,63 @@ where
                 _ => {}
             },
             Event::Keyboard(keyboard_event) => match keyboard_event {
-                keyboard::Event::KeyPressed { modifiers, .. } => {
+                keyboard::Event::KeyPressed {
+                    key_code,
+                    modifiers,
+                } => {
                     self.state.pressed_modifiers = modifiers;
 
+                    if self.state.is_focused {
+                        let step_x = || {
+                            focus::key_normal(
+                                key_code,
+                                &self.state.normal_param_x,
+                                self.key_step,
+                                self.page_step,
+                            )
+                        };
+                        let step_y = || {
+                            focus::key_normal(
+                                key_code,
+                                &self.state.normal_param_y,
+                                self.key_step,
+                                self.page_step,
+                            )
+                        };
+
+                        let (normal_x, normal_y) = match key_code {
+                            keyboard::KeyCode::Left
+                            | keyboard::KeyCode::Right => (step_x(), None),
+                            keyboard::KeyCode::Up
+                            | keyboard::KeyCode::Down
+                            | keyboard::KeyCode::PageUp
+                            | keyboard::KeyCode::PageDown => (None, step_y()),
+                            _ => (step_x(), step_y()),
+                        };
+
+                        if normal_x.is_some() || normal_y.is_some() {
+                            self.grab(messages);
+
+                            if let Some(normal_x) = normal_x {
+                                self.state.normal_param_x.value = normal_x;
+                                self.state.continuous_normal_x =
+                                    normal_x.as_f32();
+                            }
+                            if let Some(normal_y) = normal_y {
+                                self.state.normal_param_y.value = normal_y;
+                                self.state.continuous_normal_y =
+                                    normal_y.as_f32();
+                            }
+
+                            messages.push((self.on_change)(
+                                self.state.normal_param_x.value,
+                                self.state.normal_param_y.value,
+                            ));
+
+                            self.release(messages);
+                        }
+                    }
+
                     return event::Status::Captured;
                 }
                 keyboard::Event::KeyReleased { modifiers, .. } => {
@@ -422,14 +708,30 @@ where
         _defaults: &Renderer::Defaults,
         layout: Layout<'_>,
         cursor_position: Point,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) -> Renderer::Output {
+        let show_tooltip =
+            self.state.is_dragging || layout.bounds().contains(cursor_position);
+        let tooltip =
+            self.tooltip
+                .as_ref()
+                .filter(|_| show_tooltip)
+                .map(|format| {
+                    format(
+                        self.state.normal_param_x.value,
+                        self.state.normal_param_y.value,
+                    )
+                });
+
         renderer.draw(
             layout.bounds(),
             cursor_position,
             self.state.normal_param_x.value,
             self.state.normal_param_y.value,
             self.state.is_dragging,
+            self.state.is_focused,
+            tooltip.as_deref(),
+            viewport,
             &self.style,
         )
     }
@@ -460,9 +762,13 @@ pub trait Renderer: iced_native::Renderer {
     ///   * the current normal of the x coordinate of the [`XYPad`]
     ///   * the current normal of the y coordinate of the [`XYPad`]
     ///   * whether the xy_pad is currently being dragged
+    ///   * whether the [`XYPad`] has keyboard focus
+    ///   * the text of the tooltip to display, if any
+    ///   * the visible bounds that the tooltip must stay inside
     ///   * the style of the [`XYPad`]
     ///
     /// [`XYPad`]: struct.XYPad.html
+    #[allow(clippy::too_many_arguments)]
     fn draw(
         &mut self,
         bounds: Rectangle,
@@ -470,6 +776,9 @@ pub trait Renderer: iced_native::Renderer {
         normal_x: Normal,
         normal_y: Normal,
         is_dragging: bool,
+        is_focused: bool,
+        tooltip_text: Option<&str>,
+        viewport: &Rectangle,
         style: &Self::Style,
     ) -> Self::Output;
 }
@@ -0,0 +1,148 @@
+//! Various styles for the [`ADSREnvelope`] widget
+//!
+//! [`ADSREnvelope`]: ../native/adsr_envelope/struct.ADSREnvelope.html
+
+use iced_graphics::{Color, Font};
+
+use crate::style::default_colors;
+
+/// The appearance of an [`ADSREnvelope`].
+///
+/// [`ADSREnvelope`]: ../../native/adsr_envelope/struct.ADSREnvelope.html
+#[derive(Debug, Clone)]
+pub struct Style {
+    /// The color of the background rectangle
+    pub back_color: Color,
+    /// The width of the border of the background rectangle
+    pub back_border_width: f32,
+    /// The color of the border of the background rectangle
+    pub back_border_color: Color,
+    /// The width of the envelope line
+    pub line_width: f32,
+    /// The color of the envelope line
+    pub line_color: Color,
+    /// The color of the area below the envelope line. Set this to `None`
+    /// for no fill.
+    pub fill_color: Option<Color>,
+    /// The color of a segment that is hovered or being dragged
+    pub active_line_color: Color,
+    /// The style of the stage handles
+    pub handle: HandleStyle,
+    /// The style of a handle that is hovered or being dragged
+    pub active_handle: HandleStyle,
+    /// The style of the formatted value of the hovered or dragged part of
+    /// the envelope. Set this to `None` to hide the value.
+    pub value_text: Option<ValueTextStyle>,
+}
+
+/// The style of a stage handle in an [`ADSREnvelope`]
+///
+/// [`ADSREnvelope`]: ../../native/adsr_envelope/struct.ADSREnvelope.html
+#[derive(Debug, Copy, Clone)]
+pub struct HandleStyle {
+    /// The diameter of the handle
+    pub diameter: f32,
+    /// The color of the handle
+    pub color: Color,
+    /// The width of the border of the handle
+    pub border_width: f32,
+    /// The color of the border of the handle
+    pub border_color: Color,
+}
+
+/// The style of the formatted value in an [`ADSREnvelope`]
+///
+/// [`ADSREnvelope`]: ../../native/adsr_envelope/struct.ADSREnvelope.html
+#[derive(Debug, Copy, Clone)]
+pub struct ValueTextStyle {
+    /// The color of the text
+    pub color: Color,
+    /// The size of the text
+    pub text_size: u16,
+    /// The font of the text
+    pub font: Font,
+    /// The gap in pixels between the text and the hovered or dragged point
+    pub gap: f32,
+}
+
+/// A set of rules that dictate the style of an [`ADSREnvelope`].
+///
+/// [`ADSREnvelope`]: ../../native/adsr_envelope/struct.ADSREnvelope.html
+pub trait StyleSheet {
+    /// Produces the style of an active [`ADSREnvelope`].
+    ///
+    /// [`ADSREnvelope`]: ../../native/adsr_envelope/struct.ADSREnvelope.html
+    fn active(&self) -> Style;
+
+    /// Produces the style of a hovered [`ADSREnvelope`].
+    ///
+    /// [`ADSREnvelope`]: ../../native/adsr_envelope/struct.ADSREnvelope.html
+    fn hovered(&self) -> Style;
+
+    /// Produces the style of an [`ADSREnvelope`] that is being dragged.
+    ///
+    /// [`ADSREnvelope`]: ../../native/adsr_envelope/struct.ADSREnvelope.html
+    fn dragging(&self) -> Style;
+}
+
+struct Default;
+impl Default {
+    const ACTIVE_STYLE: Style = Style {
+        back_color: default_colors::LIGHT_BACK,
+        back_border_width: 1.0,
+        back_border_color: default_colors::BORDER,
+        line_width: 2.0,
+        line_color: default_colors::BORDER,
+        fill_color: Some(default_colors::ENVELOPE_FILL),
+        active_line_color: default_colors::ENVELOPE_ACTIVE,
+        handle: HandleStyle {
+            diameter: 7.0,
+            color: default_colors::LIGHT_BACK,
+            border_width: 1.5,
+            border_color: default_colors::BORDER,
+        },
+        active_handle: HandleStyle {
+            diameter: 9.0,
+            color: default_colors::ENVELOPE_ACTIVE,
+            border_width: 1.5,
+            border_color: default_colors::BORDER,
+        },
+        value_text: Some(ValueTextStyle {
+            color: default_colors::TEXT_MARK,
+            text_size: 12,
+            font: Font::Default,
+            gap: 6.0,
+        }),
+    };
+}
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Self::ACTIVE_STYLE
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            back_color: default_colors::LIGHT_BACK_HOVER,
+            ..Self::ACTIVE_STYLE
+        }
+    }
+
+    fn dragging(&self) -> Style {
+        self.hovered()
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
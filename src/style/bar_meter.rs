@@ -0,0 +1,210 @@
+//! Various styles for the [`BarMeter`] widget
+//!
+//! [`BarMeter`]: ../native/bar_meter/struct.BarMeter.html
+
+use iced_native::Color;
+
+use crate::core::{Normal, Offset};
+use crate::style::{default_colors, text_marks, tick_marks};
+
+/// The appearance of a [`BarMeter`].
+///
+/// [`BarMeter`]: ../../native/bar_meter/struct.BarMeter.html
+#[derive(Debug, Clone)]
+pub struct Style {
+    /// The color of the background rectangle
+    pub back_color: Color,
+    /// The width of the border of the background rectangle
+    pub back_border_width: f32,
+    /// The color of the border of the background rectangle
+    pub back_border_color: Color,
+    /// The colored zones of the meter bar, ordered from the lowest to the
+    /// highest starting position. The portion of the bar between the start
+    /// of one zone and the start of the next zone is filled with the color
+    /// of that zone.
+    pub color_zones: Vec<ColorZone>,
+    /// The width of the gap between the left and right channels of a
+    /// stereo meter
+    pub inner_gap: f32,
+    /// The color of the gap between the left and right channels of a
+    /// stereo meter
+    pub inner_gap_color: Color,
+    /// The style of the peak-hold line. Set this to `None` to hide the
+    /// peak-hold line.
+    pub peak_line: Option<PeakLineStyle>,
+    /// The style of the clip indicator. Set this to `None` to hide the
+    /// clip indicator.
+    pub clip_marker: Option<ClipMarkerStyle>,
+}
+
+/// A colored zone of the bar in a [`BarMeter`]
+///
+/// [`BarMeter`]: ../../native/bar_meter/struct.BarMeter.html
+#[derive(Debug, Copy, Clone)]
+pub struct ColorZone {
+    /// The position where this zone starts
+    pub start: Normal,
+    /// The color of the bar in this zone
+    pub color: Color,
+}
+
+/// The style of the peak-hold line in a [`BarMeter`]
+///
+/// [`BarMeter`]: ../../native/bar_meter/struct.BarMeter.html
+#[derive(Debug, Copy, Clone)]
+pub struct PeakLineStyle {
+    /// The color of the line
+    pub color: Color,
+    /// The width (thickness) of the line
+    pub width: f32,
+}
+
+/// The style of the clip indicator in a [`BarMeter`]. The clip indicator
+/// is placed at the top end of a vertical meter, and at the right end of a
+/// horizontal meter.
+///
+/// [`BarMeter`]: ../../native/bar_meter/struct.BarMeter.html
+#[derive(Debug, Copy, Clone)]
+pub struct ClipMarkerStyle {
+    /// The length of the clip indicator in pixels
+    pub length: f32,
+    /// The gap between the clip indicator and the meter bar in pixels
+    pub gap: f32,
+    /// The color of the clip indicator when it is not latched
+    pub off_color: Color,
+    /// The color of the clip indicator when it is latched
+    pub on_color: Color,
+}
+
+/// Style of tick marks for a [`BarMeter`].
+///
+/// [`BarMeter`]: ../../native/bar_meter/struct.BarMeter.html
+#[derive(Debug, Clone)]
+pub struct TickMarksStyle {
+    /// The style of the tick marks
+    pub style: tick_marks::Style,
+    /// The placement of the tick marks
+    pub placement: tick_marks::Placement,
+}
+
+/// Style of text marks for a [`BarMeter`].
+///
+/// [`BarMeter`]: ../../native/bar_meter/struct.BarMeter.html
+#[derive(Debug, Clone)]
+pub struct TextMarksStyle {
+    /// The style of the text marks
+    pub style: text_marks::Style,
+    /// The placement of the text marks
+    pub placement: text_marks::Placement,
+}
+
+/// A set of rules that dictate the style of a [`BarMeter`].
+///
+/// [`BarMeter`]: ../../native/bar_meter/struct.BarMeter.html
+pub trait StyleSheet {
+    /// Produces the style of an active [`BarMeter`].
+    ///
+    /// [`BarMeter`]: ../../native/bar_meter/struct.BarMeter.html
+    fn active(&self) -> Style;
+
+    /// Produces the style of a hovered [`BarMeter`].
+    ///
+    /// [`BarMeter`]: ../../native/bar_meter/struct.BarMeter.html
+    fn hovered(&self) -> Style {
+        self.active()
+    }
+
+    /// The style of tick marks for a [`BarMeter`]
+    ///
+    /// For no tick marks, don't override this or set this to return `None`.
+    ///
+    /// [`BarMeter`]: ../../native/bar_meter/struct.BarMeter.html
+    fn tick_marks_style(&self) -> Option<TickMarksStyle> {
+        None
+    }
+
+    /// The style of text marks for a [`BarMeter`]
+    ///
+    /// For no text marks, don't override this or set this to return `None`.
+    ///
+    /// [`BarMeter`]: ../../native/bar_meter/struct.BarMeter.html
+    fn text_marks_style(&self) -> Option<TextMarksStyle> {
+        None
+    }
+}
+
+struct Default;
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style {
+            back_color: default_colors::BAR_METER_BACK,
+            back_border_width: 1.0,
+            back_border_color: default_colors::BAR_METER_BORDER,
+            color_zones: vec![
+                ColorZone {
+                    start: Normal::min(),
+                    color: default_colors::BAR_METER_LOW,
+                },
+                ColorZone {
+                    start: 0.6.into(),
+                    color: default_colors::BAR_METER_MED,
+                },
+                ColorZone {
+                    start: 0.8.into(),
+                    color: default_colors::BAR_METER_HIGH,
+                },
+                ColorZone {
+                    start: 0.95.into(),
+                    color: default_colors::BAR_METER_CLIP,
+                },
+            ],
+            inner_gap: 2.0,
+            inner_gap_color: default_colors::BAR_METER_GAP,
+            peak_line: Some(PeakLineStyle {
+                color: default_colors::BAR_METER_PEAK,
+                width: 2.0,
+            }),
+            clip_marker: Some(ClipMarkerStyle {
+                length: 4.0,
+                gap: 2.0,
+                off_color: default_colors::BAR_METER_CLIP_MARKER,
+                on_color: default_colors::BAR_METER_CLIP,
+            }),
+        }
+    }
+
+    fn tick_marks_style(&self) -> Option<TickMarksStyle> {
+        Some(TickMarksStyle {
+            style: tick_marks::Style::default(),
+            placement: tick_marks::Placement::BothSides {
+                offset: Offset::ZERO,
+                inside: false,
+            },
+        })
+    }
+
+    fn text_marks_style(&self) -> Option<TextMarksStyle> {
+        Some(TextMarksStyle {
+            style: text_marks::Style::default(),
+            placement: text_marks::Placement::LeftOrTop {
+                inside: false,
+                offset: Offset { x: -7.0, y: 0.0 },
+            },
+        })
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -65,19 +65,106 @@ pub const XY_PAD_CENTER_LINE: Color = Color {
     a: 0.5,
 };
 
-/*
-pub const DB_METER_BACK: Color = Color::from_rgb(0.45, 0.45, 0.45);
-pub const DB_METER_BORDER: Color = Color::from_rgb(0.2, 0.2, 0.2);
-pub const DB_METER_LOW: Color = Color::from_rgb(0.435, 0.886, 0.11);
-pub const DB_METER_MED: Color = Color::from_rgb(0.737, 1.0, 0.145);
-pub const DB_METER_HIGH: Color = Color::from_rgb(1.0, 0.945, 0.0);
-pub const DB_METER_CLIP: Color = Color::from_rgb(1.0, 0.071, 0.071);
-pub const DB_METER_CLIP_MARKER: Color = Color {
+pub const GRID_LINE: Color = Color {
+    r: 0.56,
+    g: 0.56,
+    b: 0.56,
+    a: 0.5,
+};
+
+pub const BAR_METER_BACK: Color = Color::from_rgb(0.45, 0.45, 0.45);
+pub const BAR_METER_BORDER: Color = Color::from_rgb(0.2, 0.2, 0.2);
+pub const BAR_METER_LOW: Color = Color::from_rgb(0.435, 0.886, 0.11);
+pub const BAR_METER_MED: Color = Color::from_rgb(0.737, 1.0, 0.145);
+pub const BAR_METER_HIGH: Color = Color::from_rgb(1.0, 0.945, 0.0);
+pub const BAR_METER_CLIP: Color = Color::from_rgb(1.0, 0.071, 0.071);
+pub const BAR_METER_CLIP_MARKER: Color = Color {
     r: 0.78,
     g: 0.78,
     b: 0.78,
     a: 0.28,
 };
-pub const DB_METER_GAP: Color = Color::from_rgb(0.25, 0.25, 0.25);
+pub const BAR_METER_GAP: Color = Color::from_rgb(0.25, 0.25, 0.25);
+pub const BAR_METER_PEAK: Color = Color::from_rgb(0.92, 0.92, 0.92);
+
+pub const SPECTRUM_LINE: Color = Color::from_rgb(0.26, 0.52, 0.86);
+pub const SPECTRUM_FILL: Color = Color {
+    r: 0.26,
+    g: 0.52,
+    b: 0.86,
+    a: 0.35,
+};
+pub const SPECTRUM_PEAK: Color = Color {
+    r: 0.315,
+    g: 0.315,
+    b: 0.315,
+    a: 0.6,
+};
+
+pub const ENVELOPE_FILL: Color = Color {
+    r: 0.315,
+    g: 0.315,
+    b: 0.315,
+    a: 0.12,
+};
+pub const ENVELOPE_ACTIVE: Color = Color::from_rgb(0.26, 0.52, 0.86);
+
+pub const KEYS_BLACK: Color = Color::from_rgb(0.2, 0.2, 0.2);
+pub const KEYS_BLACK_HOVER: Color = Color::from_rgb(0.3, 0.3, 0.3);
+pub const KEYS_HIGHLIGHT: Color = Color::from_rgb(0.55, 0.73, 0.95);
+pub const KEYS_PRESSED: Color = Color::from_rgb(0.26, 0.52, 0.86);
+
+pub const MOD_WHEEL_SLOT: Color = Color::from_rgb(0.2, 0.2, 0.2);
+pub const MOD_WHEEL_RIB: Color = Color {
+    r: 0.315,
+    g: 0.315,
+    b: 0.315,
+    a: 0.45,
+};
+pub const MOD_WHEEL_MARKER: Color = Color::from_rgb(0.26, 0.52, 0.86);
+pub const MOD_WHEEL_HIGHLIGHT: Color = Color {
+    r: 1.0,
+    g: 1.0,
+    b: 1.0,
+    a: 0.35,
+};
+pub const MOD_WHEEL_SHADOW: Color = Color {
+    r: 0.0,
+    g: 0.0,
+    b: 0.0,
+    a: 0.12,
+};
+
+pub const WAVEFORM: Color = Color::from_rgb(0.26, 0.52, 0.86);
+pub const WAVEFORM_SELECTION: Color = Color {
+    r: 0.26,
+    g: 0.52,
+    b: 0.86,
+    a: 0.2,
+};
+pub const WAVEFORM_START_MARKER: Color = Color::from_rgb(0.2, 0.7, 0.3);
+pub const WAVEFORM_LOOP_MARKER: Color = Color::from_rgb(0.9, 0.6, 0.1);
+pub const WAVEFORM_END_MARKER: Color = Color::from_rgb(0.85, 0.2, 0.2);
+
+pub const GONIOMETER_LINE: Color = Color::from_rgb(0.26, 0.52, 0.86);
+
+pub const WAVETABLE_ACTIVE: Color = Color::from_rgb(0.26, 0.52, 0.86);
+pub const WAVETABLE_FRAME: Color = Color {
+    r: 0.315,
+    g: 0.315,
+    b: 0.315,
+    a: 0.25,
+};
+
+pub const RANGE_SLIDER_SPAN: Color = Color::from_rgb(0.26, 0.52, 0.86);
+pub const RANGE_SLIDER_SPAN_INVERSE: Color = Color::from_rgb(0.86, 0.45, 0.26);
+
+pub const MOD_VALUE: Color = Color::from_rgb(0.26, 0.52, 0.86);
+
+pub const FOCUS_BORDER: Color = Color::from_rgb(0.26, 0.52, 0.86);
+
+pub const TOOLTIP_BACK: Color = Color::from_rgb(0.2, 0.2, 0.2);
+
+/*
 pub const PHASE_METER_CENTER_LINE: Color = Color::from_rgb(0.92, 0.92, 0.92);
 */
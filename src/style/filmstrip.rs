@@ -0,0 +1,46 @@
+//! Styles for filmstrip (sprite sheet) textures, where each frame of the
+//! texture is a pre-rendered position of a widget
+//!
+//! Filmstrip styles are supported by the [`Knob`], [`HSlider`], and
+//! [`VSlider`] widgets.
+//!
+//! [`Knob`]: ../native/knob/struct.Knob.html
+//! [`HSlider`]: ../native/h_slider/struct.HSlider.html
+//! [`VSlider`]: ../native/v_slider/struct.VSlider.html
+
+use iced_native::image;
+
+/// The direction the frames are laid out in a filmstrip texture
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Orientation {
+    /// The frames are laid out from left to right.
+    Horizontal,
+    /// The frames are laid out from top to bottom.
+    Vertical,
+}
+
+/// A filmstrip texture
+///
+/// The first frame is shown at the minimum value of the widget, and the
+/// last frame is shown at the maximum value. Each frame is stretched to
+/// the bounds of the widget.
+#[derive(Debug, Clone)]
+pub struct Style {
+    /// The [`Handle`] to the filmstrip texture
+    ///
+    /// [`Handle`]: https://docs.rs/iced/0.1.1/iced/widget/image/struct.Handle.html
+    pub texture: image::Handle,
+    /// The number of frames in the texture
+    pub frames: u16,
+    /// The direction the frames are laid out in the texture
+    pub orientation: Orientation,
+}
+
+impl Style {
+    /// Returns the index of the frame to show for the given normal value
+    pub fn frame(&self, normal: f32) -> u16 {
+        let last_frame = self.frames.max(1) - 1;
+
+        (normal.clamp(0.0, 1.0) * f32::from(last_frame)).round() as u16
+    }
+}
@@ -0,0 +1,123 @@
+//! Various styles for the [`Goniometer`] widget
+//!
+//! [`Goniometer`]: ../native/goniometer/struct.Goniometer.html
+
+use iced_native::Color;
+
+use crate::style::default_colors;
+
+/// The appearance of a [`Goniometer`].
+///
+/// [`Goniometer`]: ../../native/goniometer/struct.Goniometer.html
+#[derive(Debug, Clone)]
+pub struct Style {
+    /// The color of the background rectangle
+    pub back_color: Color,
+    /// The width of the border of the background rectangle
+    pub back_border_width: f32,
+    /// The color of the border of the background rectangle
+    pub back_border_color: Color,
+    /// The width of the trace line
+    pub line_width: f32,
+    /// The color of the newest part of the trace line
+    pub line_color: Color,
+    /// The opacity of the oldest part of the trace line, relative to the
+    /// newest part. Older parts of the trace fade out towards this value.
+    /// Set this to `1.0` for no fading.
+    pub persistence_alpha: f32,
+    /// The color of the guide lines for the left, right, mid, and side
+    /// axes. Set this to `None` for no guide lines.
+    pub guide_color: Option<Color>,
+    /// The width of the guide lines
+    pub guide_width: f32,
+    /// The style of the correlation meter. Set this to `None` to hide the
+    /// correlation meter.
+    pub correlation: Option<CorrelationStyle>,
+}
+
+/// The style of the correlation meter of a [`Goniometer`]
+///
+/// [`Goniometer`]: ../../native/goniometer/struct.Goniometer.html
+#[derive(Debug, Copy, Clone)]
+pub struct CorrelationStyle {
+    /// The height of the meter
+    pub height: f32,
+    /// The gap between the plot and the meter
+    pub gap: f32,
+    /// The color of the background of the meter
+    pub back_color: Color,
+    /// The width of the border of the meter
+    pub back_border_width: f32,
+    /// The color of the border of the meter
+    pub back_border_color: Color,
+    /// The color of the bar when the correlation is positive
+    pub positive_color: Color,
+    /// The color of the bar when the correlation is negative
+    pub negative_color: Color,
+    /// The color of the center (zero) line of the meter
+    pub center_line_color: Color,
+    /// The width of the center (zero) line of the meter
+    pub center_line_width: f32,
+}
+
+/// A set of rules that dictate the style of a [`Goniometer`].
+///
+/// [`Goniometer`]: ../../native/goniometer/struct.Goniometer.html
+pub trait StyleSheet {
+    /// Produces the style of an active [`Goniometer`].
+    ///
+    /// [`Goniometer`]: ../../native/goniometer/struct.Goniometer.html
+    fn active(&self) -> Style;
+
+    /// Produces the style of a hovered [`Goniometer`].
+    ///
+    /// [`Goniometer`]: ../../native/goniometer/struct.Goniometer.html
+    fn hovered(&self) -> Style {
+        self.active()
+    }
+}
+
+struct Default;
+impl Default {
+    const ACTIVE_STYLE: Style = Style {
+        back_color: default_colors::LIGHT_BACK,
+        back_border_width: 1.0,
+        back_border_color: default_colors::BORDER,
+        line_width: 1.0,
+        line_color: default_colors::GONIOMETER_LINE,
+        persistence_alpha: 0.1,
+        guide_color: Some(default_colors::GRID_LINE),
+        guide_width: 1.0,
+        correlation: Some(CorrelationStyle {
+            height: 10.0,
+            gap: 6.0,
+            back_color: default_colors::LIGHT_BACK,
+            back_border_width: 1.0,
+            back_border_color: default_colors::BORDER,
+            positive_color: default_colors::BAR_METER_LOW,
+            negative_color: default_colors::BAR_METER_CLIP,
+            center_line_color: default_colors::BORDER,
+            center_line_width: 1.0,
+        }),
+    };
+}
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Self::ACTIVE_STYLE
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
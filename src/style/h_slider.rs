@@ -5,7 +5,9 @@
 use iced_native::{image, Color, Rectangle};
 
 use crate::core::Offset;
-use crate::style::{default_colors, text_marks, tick_marks};
+use crate::style::{
+    default_colors, filmstrip, text_marks, tick_marks, tooltip, value_entry,
+};
 
 /// The appearance of an [`HSlider`].
 ///
@@ -14,6 +16,9 @@ use crate::style::{default_colors, text_marks, tick_marks};
 pub enum Style {
     /// uses an image texture for the handle
     Texture(TextureStyle),
+    /// uses a filmstrip texture where each frame is a pre-rendered
+    /// position of the slider
+    Filmstrip(filmstrip::Style),
     /// modeled after hardware sliders
     Classic(ClassicStyle),
     /// a modern style with a line inside a filled rectangle
@@ -208,6 +213,25 @@ pub struct ModRangeStyle {
     /// `start`.
     pub filled_inverse_color: Color,
 }
+
+/// A style for a modulated value dot for an [`HSlider`]
+///
+/// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+#[derive(Debug, Clone)]
+pub struct ModValueStyle {
+    /// The diameter of the dot
+    pub diameter: f32,
+    /// The vertical offset of the dot from the center of the widget.
+    /// Positive values place the dot below the center.
+    pub offset: f32,
+    /// The color of the dot
+    pub color: Color,
+    /// The width of the border of the dot
+    pub border_width: f32,
+    /// The color of the border of the dot
+    pub border_color: Color,
+}
+
 /// Style of tick marks for an [`HSlider`].
 ///
 /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
@@ -249,6 +273,15 @@ pub trait StyleSheet {
     /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
     fn dragging(&self) -> Style;
 
+    /// Produces the style of an [`HSlider`] that has keyboard focus.
+    ///
+    /// The default is the hovered style.
+    ///
+    /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+    fn focused(&self) -> Style {
+        self.hovered()
+    }
+
     /// The style of tick marks for an [`HSlider`]
     ///
     /// For no tick marks, don't override this or set this to return `None`.
@@ -278,6 +311,30 @@ pub trait StyleSheet {
         None
     }
 
+    /// The style of a modulated value dot for an [`HSlider`]
+    ///
+    /// For no modulated value dot, don't override this or set this to return `None`.
+    ///
+    /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+    fn mod_value_style(&self) -> Option<ModValueStyle> {
+        None
+    }
+
+    /// The style of the text field used to type in an exact value for
+    /// an [`HSlider`]
+    ///
+    /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+    fn value_entry_style(&self) -> value_entry::Style {
+        value_entry::Style::default()
+    }
+
+    /// The style of the tooltip that displays the value of an [`HSlider`]
+    ///
+    /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+    fn tooltip_style(&self) -> tooltip::Style {
+        tooltip::Style::default()
+    }
+
     /// The style of text marks for an [`HSlider`]
     ///
     /// For no text marks, don't override this or set this to return `None`.
@@ -332,6 +389,16 @@ impl StyleSheet for Default {
         })
     }
 
+    fn focused(&self) -> Style {
+        Style::Classic(ClassicStyle {
+            handle: ClassicHandle {
+                border_color: default_colors::FOCUS_BORDER,
+                ..Self::ACTIVE_STYLE.handle
+            },
+            ..Self::ACTIVE_STYLE
+        })
+    }
+
     fn tick_marks_style(&self) -> Option<TickMarksStyle> {
         Some(TickMarksStyle {
             style: tick_marks::Style {
@@ -358,6 +425,16 @@ impl StyleSheet for Default {
         })
     }
 
+    fn mod_value_style(&self) -> Option<ModValueStyle> {
+        Some(ModValueStyle {
+            diameter: 6.0,
+            offset: 0.0,
+            color: default_colors::MOD_VALUE,
+            border_width: 1.0,
+            border_color: default_colors::BORDER,
+        })
+    }
+
     fn text_marks_style(&self) -> Option<TextMarksStyle> {
         Some(TextMarksStyle {
             style: text_marks::Style::default(),
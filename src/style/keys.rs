@@ -0,0 +1,109 @@
+//! Various styles for the [`Keys`] widget
+//!
+//! [`Keys`]: ../native/keys/struct.Keys.html
+
+use iced_native::Color;
+
+use crate::style::default_colors;
+
+/// The appearance of a [`Keys`] widget.
+///
+/// [`Keys`]: ../../native/keys/struct.Keys.html
+#[derive(Debug, Clone)]
+pub struct Style {
+    /// The color of the background rectangle, which shows in the gaps
+    /// between the white keys
+    pub back_color: Color,
+    /// The width of the border of the background rectangle
+    pub back_border_width: f32,
+    /// The color of the border of the background rectangle
+    pub back_border_color: Color,
+    /// The gap between white keys
+    pub white_key_gap: f32,
+    /// The colors of the white keys
+    pub white_key: KeyColors,
+    /// The colors of the black keys
+    pub black_key: KeyColors,
+    /// The width of the border of the black keys
+    pub black_key_border_width: f32,
+    /// The color of the border of the black keys
+    pub black_key_border_color: Color,
+    /// The radius of the front corners of the keys
+    pub key_border_radius: f32,
+}
+
+/// The colors of a key in each of its states.
+#[derive(Debug, Copy, Clone)]
+pub struct KeyColors {
+    /// The color of a key that is not pressed, highlighted, or hovered
+    pub idle: Color,
+    /// The color of a key that is hovered
+    pub hovered: Color,
+    /// The color of a key that is highlighted from the state (for example
+    /// from MIDI input)
+    pub highlighted: Color,
+    /// The color of a key that is pressed with the mouse
+    pub pressed: Color,
+}
+
+/// A set of rules that dictate the style of a [`Keys`] widget.
+///
+/// [`Keys`]: ../../native/keys/struct.Keys.html
+pub trait StyleSheet {
+    /// Produces the style of an active [`Keys`] widget.
+    ///
+    /// [`Keys`]: ../../native/keys/struct.Keys.html
+    fn active(&self) -> Style;
+
+    /// Produces the style of a hovered [`Keys`] widget.
+    ///
+    /// [`Keys`]: ../../native/keys/struct.Keys.html
+    fn hovered(&self) -> Style {
+        self.active()
+    }
+}
+
+struct Default;
+impl Default {
+    const ACTIVE_STYLE: Style = Style {
+        back_color: default_colors::BORDER,
+        back_border_width: 1.0,
+        back_border_color: default_colors::BORDER,
+        white_key_gap: 1.0,
+        white_key: KeyColors {
+            idle: default_colors::LIGHT_BACK,
+            hovered: default_colors::LIGHT_BACK_HOVER,
+            highlighted: default_colors::KEYS_HIGHLIGHT,
+            pressed: default_colors::KEYS_PRESSED,
+        },
+        black_key: KeyColors {
+            idle: default_colors::KEYS_BLACK,
+            hovered: default_colors::KEYS_BLACK_HOVER,
+            highlighted: default_colors::KEYS_HIGHLIGHT,
+            pressed: default_colors::KEYS_PRESSED,
+        },
+        black_key_border_width: 1.0,
+        black_key_border_color: default_colors::BORDER,
+        key_border_radius: 2.0,
+    };
+}
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Self::ACTIVE_STYLE
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
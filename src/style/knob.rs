@@ -2,12 +2,15 @@
 //!
 //! [`Knob`]: ../native/knob/struct.Knob.html
 
-use iced_native::Color;
-//use iced_native::image;
+use std::sync::Arc;
+
+use iced_native::{image, Color};
 
 pub use iced_graphics::canvas::LineCap;
 
-use crate::style::{default_colors, text_marks, tick_marks};
+use crate::style::{
+    default_colors, filmstrip, text_marks, tick_marks, tooltip, value_entry,
+};
 use crate::KnobAngleRange;
 
 /// The appearance of a [`Knob`],
@@ -15,7 +18,11 @@ use crate::KnobAngleRange;
 /// [`Knob`]: ../../native/knob/struct.Knob.html
 #[derive(Debug, Clone)]
 pub enum Style {
-    //Texture(TextureStyle),
+    /// Uses an image texture that rotates with the value of the knob
+    Texture(TextureStyle),
+    /// Uses a filmstrip texture where each frame is a pre-rendered
+    /// position of the knob
+    Filmstrip(filmstrip::Style),
     /// A classic circular style
     Circle(CircleStyle),
     /// A modern arc style
@@ -25,26 +32,376 @@ pub enum Style {
     ArcBipolar(ArcBipolarStyle),
 }
 
-/*
 /// A [`Style`] for a [`Knob`] that uses an image texture for the knob
 ///
+/// The texture rotates with the value of the knob, while the optional
+/// highlight and shadow layers stay in place.
+///
 /// [`Style`]: enum.Style.html
 /// [`Knob`]: ../../native/knob/struct.Knob.html
-/// [`Handle`]: https://docs.rs/iced/0.1.1/iced/widget/image/struct.Handle.html
 #[derive(Debug, Clone)]
 pub struct TextureStyle {
-    /// the [`Handle`] to the image texture
+    /// The rotating texture of the knob
+    pub texture: KnobTexture,
+    /// The texture padding around the knob bounding rectangle. This is
+    /// useful when the texture is of a glowing knob or has a drop shadow,
+    /// etc.
+    pub texture_padding: TexturePadding,
+    /// A layer drawn on top of the rotating texture that does not rotate,
+    /// such as a highlight or reflection. Set to `None` for no highlight.
+    pub highlight: Option<TextureLayer>,
+    /// A layer drawn below the rotating texture that does not rotate, such
+    /// as a drop shadow. Set to `None` for no shadow.
+    pub shadow: Option<TextureLayer>,
+}
+
+/// A non-rotating image layer of a [`TextureStyle`]
+///
+/// [`TextureStyle`]: struct.TextureStyle.html
+#[derive(Debug, Clone)]
+pub struct TextureLayer {
+    /// The [`Handle`] to the image texture
+    ///
+    /// [`Handle`]: https://docs.rs/iced/0.1.1/iced/widget/image/struct.Handle.html
     pub texture: image::Handle,
-    /// the width of the knob, not including padding
-    pub knob_width: u16,
-    /// the height of the knob, not including padding
-    pub knob_height: u16,
-    /// the texture padding around the knob bounding
-    /// rectangle. This is useful when the texture is of a glowing handle or has
-    /// a drop shadow, etc.
-    pub texture_padding: Option<TexturePadding>,
+    /// The texture padding around the knob bounding rectangle
+    pub texture_padding: TexturePadding,
+}
+
+/// The padding of a texture around the bounding rectangle of a [`Knob`].
+/// Positive values extend the texture past the bounds of the [`Knob`].
+///
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct TexturePadding {
+    /// The padding above the knob
+    pub top: f32,
+    /// The padding below the knob
+    pub bottom: f32,
+    /// The padding to the left of the knob
+    pub left: f32,
+    /// The padding to the right of the knob
+    pub right: f32,
+}
+
+impl TexturePadding {
+    /// Padding of `0.0` on all sides
+    pub const ZERO: TexturePadding = TexturePadding {
+        top: 0.0,
+        bottom: 0.0,
+        left: 0.0,
+        right: 0.0,
+    };
+
+    /// Creates the same padding on all sides
+    pub fn all(padding: f32) -> Self {
+        Self {
+            top: padding,
+            bottom: padding,
+            left: padding,
+            right: padding,
+        }
+    }
+}
+
+/// The rotating texture of a [`TextureStyle`]
+///
+/// The renderer has no way to rotate an image, so the texture is rotated
+/// once ahead of time into a number of steps across the span of a
+/// [`KnobAngleRange`], and the step closest to the value of the knob is
+/// drawn. This is cheap to clone, so create it once and keep it around in
+/// your [`StyleSheet`].
+///
+/// Every step is a full copy of the image, so a texture uses
+/// `width * height * 4 * steps` bytes of memory. For example, a 256x256
+/// texture baked into 300 steps uses about 75 MB. Keep the image no larger
+/// than the knob is drawn on screen to keep this down.
+///
+/// [`TextureStyle`]: struct.TextureStyle.html
+/// [`KnobAngleRange`]: ../../core/struct.KnobAngleRange.html
+/// [`StyleSheet`]: trait.StyleSheet.html
+#[derive(Debug, Clone)]
+pub struct KnobTexture {
+    frames: Arc<[image::Handle]>,
+    start: f32,
+    span: f32,
+}
+
+impl KnobTexture {
+    /// Creates a new [`KnobTexture`] from decoded image pixels.
+    ///
+    /// It expects:
+    /// * the width and height of the image
+    /// * the pixels of the image in BGRA format
+    /// * the [`KnobAngleRange`] of the knob. This should be the same range
+    ///   that the [`StyleSheet`] returns, since only the rotations within it
+    ///   are baked.
+    /// * the number of rotation steps across the span of the
+    ///   [`KnobAngleRange`]. One step per degree of the span (`300` for the
+    ///   default range) is enough for the rotation to look smooth. Fewer steps
+    ///   use less memory.
+    ///
+    /// The image should be square, and it should point straight up, which
+    /// is the halfway point of the default [`KnobAngleRange`].
+    ///
+    /// This returns an error if the number of pixels is not
+    /// `width * height * 4`.
+    ///
+    /// [`KnobTexture`]: struct.KnobTexture.html
+    /// [`KnobAngleRange`]: ../../core/struct.KnobAngleRange.html
+    /// [`StyleSheet`]: trait.StyleSheet.html
+    pub fn from_pixels(
+        width: u32,
+        height: u32,
+        pixels: Vec<u8>,
+        angle_range: KnobAngleRange,
+        steps: usize,
+    ) -> Result<Self, KnobTextureError> {
+        let expected = width as usize * height as usize * 4;
+
+        if pixels.len() != expected {
+            return Err(KnobTextureError::PixelCount {
+                expected,
+                actual: pixels.len(),
+            });
+        }
+
+        let steps = steps.max(2);
+
+        // The texture points straight up when it is not rotated, which is
+        // half a turn away from an angle of `0.0`.
+        let start = angle_range.min() - std::f32::consts::PI;
+        let span = angle_range.max() - angle_range.min();
+
+        let frames: Vec<image::Handle> = (0..steps)
+            .map(|step| {
+                let angle = start + (span * step as f32 / (steps - 1) as f32);
+
+                image::Handle::from_pixels(
+                    width,
+                    height,
+                    rotate_pixels(
+                        width as usize,
+                        height as usize,
+                        &pixels,
+                        angle,
+                    ),
+                )
+            })
+            .collect();
+
+        Ok(Self {
+            frames: frames.into(),
+            start,
+            span,
+        })
+    }
+
+    /// Creates a new [`KnobTexture`] from an encoded image in memory, such
+    /// as the contents of a PNG file.
+    ///
+    /// The rest of the arguments are the same as in [`from_pixels`].
+    ///
+    /// This returns an error if the image could not be decoded.
+    ///
+    /// [`KnobTexture`]: struct.KnobTexture.html
+    /// [`from_pixels`]: #method.from_pixels
+    #[cfg(feature = "image")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+    pub fn from_memory(
+        bytes: &[u8],
+        angle_range: KnobAngleRange,
+        steps: usize,
+    ) -> Result<Self, KnobTextureError> {
+        let image = image_rs::load_from_memory(bytes)?.to_bgra8();
+        let (width, height) = image.dimensions();
+
+        Self::from_pixels(width, height, image.into_raw(), angle_range, steps)
+    }
+
+    /// Creates a new [`KnobTexture`] from an image file.
+    ///
+    /// The rest of the arguments are the same as in [`from_pixels`].
+    ///
+    /// This returns an error if the file could not be read or decoded.
+    ///
+    /// [`KnobTexture`]: struct.KnobTexture.html
+    /// [`from_pixels`]: #method.from_pixels
+    #[cfg(feature = "image")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+    pub fn from_path<P: AsRef<std::path::Path>>(
+        path: P,
+        angle_range: KnobAngleRange,
+        steps: usize,
+    ) -> Result<Self, KnobTextureError> {
+        let image = image_rs::open(path)?.to_bgra8();
+        let (width, height) = image.dimensions();
+
+        Self::from_pixels(width, height, image.into_raw(), angle_range, steps)
+    }
+
+    /// Returns the number of rotation steps in the [`KnobTexture`]
+    ///
+    /// [`KnobTexture`]: struct.KnobTexture.html
+    pub fn steps(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Returns the texture rotated clockwise by the step closest to
+    /// `angle` (in radians). Angles outside of the span the texture was
+    /// created with are clamped to the closest end of it.
+    pub fn rotated(&self, angle: f32) -> &image::Handle {
+        let steps = self.frames.len();
+
+        let offset = (angle - self.start).rem_euclid(crate::core::math::TWO_PI);
+
+        // Past the end of the span, snap to whichever end is closer
+        // around the circle.
+        let offset = if offset <= self.span {
+            offset
+        } else if offset - self.span < crate::core::math::TWO_PI - offset {
+            self.span
+        } else {
+            0.0
+        };
+
+        let step = if self.span > 0.0 {
+            (offset / self.span * (steps - 1) as f32).round() as usize
+        } else {
+            0
+        };
+
+        &self.frames[step.min(steps - 1)]
+    }
+}
+
+/// An error that occurred while creating a [`KnobTexture`]
+///
+/// [`KnobTexture`]: struct.KnobTexture.html
+#[derive(Debug)]
+pub enum KnobTextureError {
+    /// The number of pixel bytes was not `width * height * 4`
+    PixelCount {
+        /// The number of bytes expected from the width and height
+        expected: usize,
+        /// The number of bytes given
+        actual: usize,
+    },
+    /// The image could not be read or decoded
+    #[cfg(feature = "image")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+    Image(image_rs::ImageError),
+}
+
+impl std::fmt::Display for KnobTextureError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KnobTextureError::PixelCount { expected, actual } => write!(
+                f,
+                "expected {} bytes of BGRA pixels, got {}",
+                expected, actual
+            ),
+            #[cfg(feature = "image")]
+            KnobTextureError::Image(error) => {
+                write!(f, "failed to load image: {}", error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for KnobTextureError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            KnobTextureError::PixelCount { .. } => None,
+            #[cfg(feature = "image")]
+            KnobTextureError::Image(error) => Some(error),
+        }
+    }
+}
+
+#[cfg(feature = "image")]
+impl From<image_rs::ImageError> for KnobTextureError {
+    fn from(error: image_rs::ImageError) -> Self {
+        KnobTextureError::Image(error)
+    }
+}
+
+/// Rotates BGRA pixels clockwise around the center of the image with
+/// bilinear filtering. Pixels rotated in from outside of the image are
+/// transparent.
+fn rotate_pixels(
+    width: usize,
+    height: usize,
+    pixels: &[u8],
+    angle: f32,
+) -> Vec<u8> {
+    let (sin, cos) = angle.sin_cos();
+
+    let center_x = width as f32 / 2.0;
+    let center_y = height as f32 / 2.0;
+
+    let sample = |x: isize, y: isize| -> [f32; 4] {
+        if x < 0 || y < 0 || x >= width as isize || y >= height as isize {
+            return [0.0; 4];
+        }
+
+        let i = (y as usize * width + x as usize) * 4;
+        let alpha = f32::from(pixels[i + 3]);
+
+        // Premultiply so transparent pixels don't bleed their color into
+        // the edges.
+        [
+            f32::from(pixels[i]) * alpha,
+            f32::from(pixels[i + 1]) * alpha,
+            f32::from(pixels[i + 2]) * alpha,
+            alpha,
+        ]
+    };
+
+    let mut rotated = vec![0; pixels.len()];
+
+    for y in 0..height {
+        for x in 0..width {
+            let dx = x as f32 + 0.5 - center_x;
+            let dy = y as f32 + 0.5 - center_y;
+
+            let src_x = (dx * cos) + (dy * sin) + center_x - 0.5;
+            let src_y = (dy * cos) - (dx * sin) + center_y - 0.5;
+
+            let x0 = src_x.floor();
+            let y0 = src_y.floor();
+            let fx = src_x - x0;
+            let fy = src_y - y0;
+            let (x0, y0) = (x0 as isize, y0 as isize);
+
+            let top_left = sample(x0, y0);
+            let top_right = sample(x0 + 1, y0);
+            let bottom_left = sample(x0, y0 + 1);
+            let bottom_right = sample(x0 + 1, y0 + 1);
+
+            let mut color = [0.0; 4];
+            for c in 0..4 {
+                let top = top_left[c] + ((top_right[c] - top_left[c]) * fx);
+                let bottom =
+                    bottom_left[c] + ((bottom_right[c] - bottom_left[c]) * fx);
+                color[c] = top + ((bottom - top) * fy);
+            }
+
+            let alpha = color[3];
+
+            if alpha > 0.0 {
+                let i = (y * width + x) * 4;
+
+                rotated[i] = (color[0] / alpha).round().min(255.0) as u8;
+                rotated[i + 1] = (color[1] / alpha).round().min(255.0) as u8;
+                rotated[i + 2] = (color[2] / alpha).round().min(255.0) as u8;
+                rotated[i + 3] = alpha.round().min(255.0) as u8;
+            }
+        }
+    }
+
+    rotated
 }
-*/
 
 /// A length in a [`Knob`] stylesheet
 ///
@@ -212,6 +569,24 @@ pub struct ModRangeArcStyle {
     pub cap: LineCap,
 }
 
+/// A style for a modulated value dot around a [`Knob`]
+///
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+#[derive(Debug, Copy, Clone)]
+pub struct ModValueStyle {
+    /// The diameter of the dot
+    pub diameter: f32,
+    /// The offset from the edge of the `Knob` in pixels. Set this to a
+    /// negative value to place the dot inside the `Knob`.
+    pub offset: f32,
+    /// The color of the dot
+    pub color: Color,
+    /// The width of the border of the dot
+    pub border_width: f32,
+    /// The color of the border of the dot
+    pub border_color: Color,
+}
+
 /// Style of tick marks for a [`Knob`].
 ///
 /// [`Knob`]: ../../native/knob/struct.Knob.html
@@ -274,6 +649,15 @@ pub trait StyleSheet {
     /// [`Knob`]: ../../native/knob/struct.Knob.html
     fn dragging(&self) -> Style;
 
+    /// Produces the style of a [`Knob`] that has keyboard focus.
+    ///
+    /// The default is the hovered style.
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    fn focused(&self) -> Style {
+        self.hovered()
+    }
+
     /// a [`KnobAngleRange`] that defines the minimum and maximum angle that the
     /// knob rotates
     ///
@@ -321,6 +705,30 @@ pub trait StyleSheet {
         None
     }
 
+    /// The style of a modulated value dot around a [`Knob`]
+    ///
+    /// For no modulated value dot, don't override this or set this to return `None`.
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    fn mod_value_style(&self) -> Option<ModValueStyle> {
+        None
+    }
+
+    /// The style of the text field used to type in an exact value for
+    /// a [`Knob`]
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    fn value_entry_style(&self) -> value_entry::Style {
+        value_entry::Style::default()
+    }
+
+    /// The style of the tooltip that displays the value of a [`Knob`]
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    fn tooltip_style(&self) -> tooltip::Style {
+        tooltip::Style::default()
+    }
+
     /// The style of text marks around a [`Knob`]
     ///
     /// For no text marks, don't override this or set this to return `None`.
@@ -364,6 +772,13 @@ impl StyleSheet for Default {
         self.hovered()
     }
 
+    fn focused(&self) -> Style {
+        Style::Circle(CircleStyle {
+            border_color: default_colors::FOCUS_BORDER,
+            ..Self::ACTIVE_CIRCLE_STYLE
+        })
+    }
+
     fn tick_marks_style(&self) -> Option<TickMarksStyle> {
         Some(TickMarksStyle {
             style: tick_marks::Style {
@@ -384,6 +799,16 @@ impl StyleSheet for Default {
         })
     }
 
+    fn mod_value_style(&self) -> Option<ModValueStyle> {
+        Some(ModValueStyle {
+            diameter: 5.0,
+            offset: 2.0,
+            color: default_colors::MOD_VALUE,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        })
+    }
+
     fn text_marks_style(&self) -> Option<TextMarksStyle> {
         Some(TextMarksStyle {
             style: text_marks::Style::default(),
@@ -2,15 +2,31 @@
 
 mod default_colors;
 
+pub mod adsr_envelope;
+pub mod bar_meter;
+pub mod envelope_editor;
+pub mod goniometer;
+pub mod h_range_slider;
 pub mod h_slider;
+pub mod keys;
 pub mod knob;
 pub mod mod_range_input;
+pub mod mod_wheel;
+pub mod oscilloscope;
+pub mod par_eq_editor;
 pub mod ramp;
+pub mod spectrum_analyzer;
+pub mod v_range_slider;
 pub mod v_slider;
+pub mod waveform_view;
+pub mod wavetable_view;
 pub mod xy_pad;
 
+pub mod filmstrip;
 pub mod text_marks;
 pub mod tick_marks;
+pub mod tooltip;
+pub mod value_entry;
 
 //pub mod db_meter;
 //pub mod phase_meter;
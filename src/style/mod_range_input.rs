@@ -4,7 +4,7 @@
 
 use iced_native::Color;
 
-use crate::style::default_colors;
+use crate::style::{default_colors, tooltip};
 
 /// The appearance of an [`ModRangeInput`]
 ///
@@ -71,6 +71,22 @@ pub trait StyleSheet {
     ///
     /// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
     fn dragging(&self) -> Style;
+
+    /// Produces the style of a [`ModRangeInput`] that has keyboard focus.
+    ///
+    /// The default is the hovered style.
+    ///
+    /// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
+    fn focused(&self) -> Style {
+        self.hovered()
+    }
+
+    /// The style of the tooltip that displays the value of a [`ModRangeInput`]
+    ///
+    /// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
+    fn tooltip_style(&self) -> tooltip::Style {
+        tooltip::Style::default()
+    }
 }
 
 struct Default;
@@ -96,6 +112,13 @@ impl StyleSheet for Default {
     fn dragging(&self) -> Style {
         self.hovered()
     }
+
+    fn focused(&self) -> Style {
+        Style::Circle(CircleStyle {
+            border_color: default_colors::FOCUS_BORDER,
+            ..Self::ACTIVE_STYLE
+        })
+    }
 }
 
 /// An invisible [`StyleSheet`] for an [`ModRangeInput`]
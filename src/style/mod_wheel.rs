@@ -0,0 +1,148 @@
+//! Various styles for the [`ModWheel`] widget
+//!
+//! [`ModWheel`]: ../native/mod_wheel/struct.ModWheel.html
+
+use iced_native::Color;
+
+use crate::style::{default_colors, tooltip};
+
+/// The appearance of a [`ModWheel`].
+///
+/// [`ModWheel`]: ../../native/mod_wheel/struct.ModWheel.html
+#[derive(Debug, Clone)]
+pub struct Style {
+    /// The color of the slot the wheel sits in
+    pub back_color: Color,
+    /// The width of the border of the slot
+    pub back_border_width: f32,
+    /// The color of the border of the slot
+    pub back_border_color: Color,
+    /// The radius of the corners of the slot
+    pub back_border_radius: f32,
+    /// The gap between the edge of the slot and the wheel
+    pub wheel_padding: f32,
+    /// The color of the wheel
+    pub wheel_color: Color,
+    /// The width of the border of the wheel
+    pub wheel_border_width: f32,
+    /// The color of the border of the wheel
+    pub wheel_border_color: Color,
+    /// The color of the ribs on the wheel
+    pub rib_color: Color,
+    /// The thickness of a rib when it faces the viewer
+    pub rib_width: f32,
+    /// The number of ribs around the whole wheel
+    pub rib_count: u16,
+    /// How far the wheel rotates from its lowest to its highest value, in
+    /// degrees
+    pub rotation_range: f32,
+    /// The color of the marker that shows the current value
+    pub marker_color: Color,
+    /// The thickness of the marker
+    pub marker_width: f32,
+    /// The color of the optional highlight layer on the upper half of the
+    /// wheel
+    pub highlight_color: Option<Color>,
+    /// The color of the optional shadow layer on the lower half of the
+    /// wheel
+    pub shadow_color: Option<Color>,
+}
+
+/// A set of rules that dictate the style of a [`ModWheel`].
+///
+/// [`ModWheel`]: ../../native/mod_wheel/struct.ModWheel.html
+pub trait StyleSheet {
+    /// Produces the style of an active [`ModWheel`].
+    ///
+    /// [`ModWheel`]: ../../native/mod_wheel/struct.ModWheel.html
+    fn active(&self) -> Style;
+
+    /// Produces the style of a hovered [`ModWheel`].
+    ///
+    /// [`ModWheel`]: ../../native/mod_wheel/struct.ModWheel.html
+    fn hovered(&self) -> Style;
+
+    /// Produces the style of a [`ModWheel`] that is being dragged.
+    ///
+    /// [`ModWheel`]: ../../native/mod_wheel/struct.ModWheel.html
+    fn dragging(&self) -> Style;
+
+    /// Produces the style of a [`ModWheel`] that has keyboard focus.
+    ///
+    /// The default is the hovered style.
+    ///
+    /// [`ModWheel`]: ../../native/mod_wheel/struct.ModWheel.html
+    fn focused(&self) -> Style {
+        self.hovered()
+    }
+
+    /// The style of the tooltip that displays the value of a [`ModWheel`]
+    ///
+    /// [`ModWheel`]: ../../native/mod_wheel/struct.ModWheel.html
+    fn tooltip_style(&self) -> tooltip::Style {
+        tooltip::Style::default()
+    }
+}
+
+struct Default;
+impl Default {
+    const ACTIVE_STYLE: Style = Style {
+        back_color: default_colors::MOD_WHEEL_SLOT,
+        back_border_width: 1.0,
+        back_border_color: default_colors::BORDER,
+        back_border_radius: 3.0,
+        wheel_padding: 2.0,
+        wheel_color: default_colors::LIGHT_BACK,
+        wheel_border_width: 1.0,
+        wheel_border_color: default_colors::BORDER,
+        rib_color: default_colors::MOD_WHEEL_RIB,
+        rib_width: 2.0,
+        rib_count: 40,
+        rotation_range: 120.0,
+        marker_color: default_colors::MOD_WHEEL_MARKER,
+        marker_width: 3.0,
+        highlight_color: Some(default_colors::MOD_WHEEL_HIGHLIGHT),
+        shadow_color: Some(default_colors::MOD_WHEEL_SHADOW),
+    };
+}
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Self::ACTIVE_STYLE
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            wheel_color: default_colors::LIGHT_BACK_HOVER,
+            ..Self::ACTIVE_STYLE
+        }
+    }
+
+    fn dragging(&self) -> Style {
+        Style {
+            wheel_color: default_colors::LIGHT_BACK_DRAG,
+            ..Self::ACTIVE_STYLE
+        }
+    }
+
+    fn focused(&self) -> Style {
+        Style {
+            back_border_color: default_colors::FOCUS_BORDER,
+            ..Self::ACTIVE_STYLE
+        }
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -0,0 +1,81 @@
+//! Various styles for the [`Oscilloscope`] widget
+//!
+//! [`Oscilloscope`]: ../native/oscilloscope/struct.Oscilloscope.html
+
+use iced_native::Color;
+
+use crate::style::default_colors;
+
+/// The appearance of an [`Oscilloscope`].
+///
+/// [`Oscilloscope`]: ../../native/oscilloscope/struct.Oscilloscope.html
+#[derive(Debug, Clone)]
+pub struct Style {
+    /// The color of the background rectangle
+    pub back_color: Color,
+    /// The width of the border of the background rectangle
+    pub back_border_width: f32,
+    /// The color of the border of the background rectangle
+    pub back_border_color: Color,
+    /// The width of the waveform line
+    pub line_width: f32,
+    /// The color of the waveform line
+    pub line_color: Color,
+    /// The color of the horizontal center (zero) line. Set this to `None`
+    /// for no center line.
+    pub center_line_color: Option<Color>,
+    /// The width of the horizontal center (zero) line
+    pub center_line_width: f32,
+}
+
+/// A set of rules that dictate the style of an [`Oscilloscope`].
+///
+/// [`Oscilloscope`]: ../../native/oscilloscope/struct.Oscilloscope.html
+pub trait StyleSheet {
+    /// Produces the style of an active [`Oscilloscope`].
+    ///
+    /// [`Oscilloscope`]: ../../native/oscilloscope/struct.Oscilloscope.html
+    fn active(&self) -> Style;
+
+    /// Produces the style of a hovered [`Oscilloscope`].
+    ///
+    /// [`Oscilloscope`]: ../../native/oscilloscope/struct.Oscilloscope.html
+    fn hovered(&self) -> Style;
+}
+
+struct Default;
+impl Default {
+    const ACTIVE_STYLE: Style = Style {
+        back_color: default_colors::LIGHT_BACK,
+        back_border_width: 1.0,
+        back_border_color: default_colors::BORDER,
+        line_width: 1.5,
+        line_color: default_colors::BORDER,
+        center_line_color: Some(default_colors::GRID_LINE),
+        center_line_width: 1.0,
+    };
+}
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Self::ACTIVE_STYLE
+    }
+
+    fn hovered(&self) -> Style {
+        Self::ACTIVE_STYLE
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
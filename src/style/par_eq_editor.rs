@@ -0,0 +1,135 @@
+//! Various styles for the [`ParEqEditor`] widget
+//!
+//! [`ParEqEditor`]: ../native/par_eq_editor/struct.ParEqEditor.html
+
+use iced_native::Color;
+
+use crate::style::default_colors;
+
+/// The appearance of a [`ParEqEditor`].
+///
+/// [`ParEqEditor`]: ../../native/par_eq_editor/struct.ParEqEditor.html
+#[derive(Debug, Clone)]
+pub struct Style {
+    /// The color of the background rectangle
+    pub back_color: Color,
+    /// The width of the border of the background rectangle
+    pub back_border_width: f32,
+    /// The color of the border of the background rectangle
+    pub back_border_color: Color,
+    /// The width of the `0 dB` line
+    pub zero_line_width: f32,
+    /// The color of the `0 dB` line
+    pub zero_line_color: Color,
+    /// The width of the response curve
+    pub line_width: f32,
+    /// The color of the response curve
+    pub line_color: Color,
+    /// The color of the area between the response curve and the `0 dB`
+    /// line. Set this to `None` for no fill.
+    pub fill_color: Option<Color>,
+    /// The style of the band handles
+    pub handle: HandleStyle,
+    /// The style of a band handle that is hovered or being dragged
+    pub active_handle: HandleStyle,
+    /// The style of the handle of a bypassed band
+    pub bypassed_handle: HandleStyle,
+}
+
+/// The style of a band handle in a [`ParEqEditor`]
+///
+/// [`ParEqEditor`]: ../../native/par_eq_editor/struct.ParEqEditor.html
+#[derive(Debug, Copy, Clone)]
+pub struct HandleStyle {
+    /// The diameter of the handle
+    pub diameter: f32,
+    /// The color of the handle
+    pub color: Color,
+    /// The width of the border of the handle
+    pub border_width: f32,
+    /// The color of the border of the handle
+    pub border_color: Color,
+}
+
+/// A set of rules that dictate the style of a [`ParEqEditor`].
+///
+/// [`ParEqEditor`]: ../../native/par_eq_editor/struct.ParEqEditor.html
+pub trait StyleSheet {
+    /// Produces the style of an active [`ParEqEditor`].
+    ///
+    /// [`ParEqEditor`]: ../../native/par_eq_editor/struct.ParEqEditor.html
+    fn active(&self) -> Style;
+
+    /// Produces the style of a hovered [`ParEqEditor`].
+    ///
+    /// [`ParEqEditor`]: ../../native/par_eq_editor/struct.ParEqEditor.html
+    fn hovered(&self) -> Style;
+
+    /// Produces the style of a [`ParEqEditor`] that is being dragged.
+    ///
+    /// [`ParEqEditor`]: ../../native/par_eq_editor/struct.ParEqEditor.html
+    fn dragging(&self) -> Style;
+}
+
+struct Default;
+impl Default {
+    const ACTIVE_STYLE: Style = Style {
+        back_color: default_colors::LIGHT_BACK,
+        back_border_width: 1.0,
+        back_border_color: default_colors::BORDER,
+        zero_line_width: 1.0,
+        zero_line_color: default_colors::GRID_LINE,
+        line_width: 2.0,
+        line_color: default_colors::BORDER,
+        fill_color: Some(default_colors::ENVELOPE_FILL),
+        handle: HandleStyle {
+            diameter: 9.0,
+            color: default_colors::LIGHT_BACK,
+            border_width: 1.5,
+            border_color: default_colors::BORDER,
+        },
+        active_handle: HandleStyle {
+            diameter: 11.0,
+            color: default_colors::ENVELOPE_ACTIVE,
+            border_width: 1.5,
+            border_color: default_colors::BORDER,
+        },
+        bypassed_handle: HandleStyle {
+            diameter: 9.0,
+            color: default_colors::LIGHT_BACK_DRAG,
+            border_width: 1.5,
+            border_color: default_colors::TICK_TIER_2,
+        },
+    };
+}
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Self::ACTIVE_STYLE
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            back_color: default_colors::LIGHT_BACK_HOVER,
+            ..Self::ACTIVE_STYLE
+        }
+    }
+
+    fn dragging(&self) -> Style {
+        self.hovered()
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
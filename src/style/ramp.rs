@@ -4,7 +4,7 @@
 
 use iced_native::Color;
 
-use crate::style::default_colors;
+use crate::style::{default_colors, tooltip};
 
 /// The appearance of a [`Ramp`],
 ///
@@ -45,6 +45,22 @@ pub trait StyleSheet {
     ///
     /// [`Ramp`]: ../../native/ramp/struct.Ramp.html
     fn dragging(&self) -> Style;
+
+    /// Produces the style of a [`Ramp`] that has keyboard focus.
+    ///
+    /// The default is the hovered style.
+    ///
+    /// [`Ramp`]: ../../native/ramp/struct.Ramp.html
+    fn focused(&self) -> Style {
+        self.hovered()
+    }
+
+    /// The style of the tooltip that displays the value of a [`Ramp`]
+    ///
+    /// [`Ramp`]: ../../native/ramp/struct.Ramp.html
+    fn tooltip_style(&self) -> tooltip::Style {
+        tooltip::Style::default()
+    }
 }
 
 struct Default;
@@ -74,6 +90,13 @@ impl StyleSheet for Default {
     fn dragging(&self) -> Style {
         self.hovered()
     }
+
+    fn focused(&self) -> Style {
+        Style {
+            back_border_color: default_colors::FOCUS_BORDER,
+            ..Self::ACTIVE_STYLE
+        }
+    }
 }
 
 impl std::default::Default for Box<dyn StyleSheet> {
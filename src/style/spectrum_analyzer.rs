@@ -0,0 +1,179 @@
+//! Various styles for the [`SpectrumAnalyzer`] widget
+//!
+//! [`SpectrumAnalyzer`]: ../native/spectrum_analyzer/struct.SpectrumAnalyzer.html
+
+use iced_native::Color;
+
+use crate::core::Offset;
+use crate::style::{default_colors, text_marks, tick_marks};
+
+/// The appearance of a [`SpectrumAnalyzer`].
+///
+/// [`SpectrumAnalyzer`]: ../../native/spectrum_analyzer/struct.SpectrumAnalyzer.html
+#[derive(Debug, Clone)]
+pub struct Style {
+    /// The color of the background rectangle
+    pub back_color: Color,
+    /// The width of the border of the background rectangle
+    pub back_border_width: f32,
+    /// The color of the border of the background rectangle
+    pub back_border_color: Color,
+    /// The width of the spectrum line
+    pub line_width: f32,
+    /// The color of the spectrum line
+    pub line_color: Color,
+    /// The color of the area below the spectrum line in
+    /// `DisplayMode::Filled`
+    pub fill_color: Color,
+    /// The color of the bars in `DisplayMode::Bars`
+    pub bar_color: Color,
+    /// The gap between the bars in `DisplayMode::Bars`
+    pub bar_gap: f32,
+    /// The style of the peak-hold curve. Set this to `None` to hide the
+    /// peak-hold curve.
+    pub peak_line: Option<PeakLineStyle>,
+}
+
+/// The style of the peak-hold curve in a [`SpectrumAnalyzer`]
+///
+/// [`SpectrumAnalyzer`]: ../../native/spectrum_analyzer/struct.SpectrumAnalyzer.html
+#[derive(Debug, Copy, Clone)]
+pub struct PeakLineStyle {
+    /// The color of the line
+    pub color: Color,
+    /// The width (thickness) of the line
+    pub width: f32,
+}
+
+/// Style of tick marks for a [`SpectrumAnalyzer`].
+///
+/// [`SpectrumAnalyzer`]: ../../native/spectrum_analyzer/struct.SpectrumAnalyzer.html
+#[derive(Debug, Clone)]
+pub struct TickMarksStyle {
+    /// The style of the tick marks
+    pub style: tick_marks::Style,
+    /// The placement of the tick marks
+    pub placement: tick_marks::Placement,
+}
+
+/// Style of text marks for a [`SpectrumAnalyzer`].
+///
+/// [`SpectrumAnalyzer`]: ../../native/spectrum_analyzer/struct.SpectrumAnalyzer.html
+#[derive(Debug, Clone)]
+pub struct TextMarksStyle {
+    /// The style of the text marks
+    pub style: text_marks::Style,
+    /// The placement of the text marks
+    pub placement: text_marks::Placement,
+}
+
+/// A set of rules that dictate the style of a [`SpectrumAnalyzer`].
+///
+/// [`SpectrumAnalyzer`]: ../../native/spectrum_analyzer/struct.SpectrumAnalyzer.html
+pub trait StyleSheet {
+    /// Produces the style of an active [`SpectrumAnalyzer`].
+    ///
+    /// [`SpectrumAnalyzer`]: ../../native/spectrum_analyzer/struct.SpectrumAnalyzer.html
+    fn active(&self) -> Style;
+
+    /// Produces the style of a hovered [`SpectrumAnalyzer`].
+    ///
+    /// [`SpectrumAnalyzer`]: ../../native/spectrum_analyzer/struct.SpectrumAnalyzer.html
+    fn hovered(&self) -> Style {
+        self.active()
+    }
+
+    /// The style of the frequency grid (tick marks) for a
+    /// [`SpectrumAnalyzer`]
+    ///
+    /// For no tick marks, don't override this or set this to return `None`.
+    ///
+    /// [`SpectrumAnalyzer`]: ../../native/spectrum_analyzer/struct.SpectrumAnalyzer.html
+    fn tick_marks_style(&self) -> Option<TickMarksStyle> {
+        None
+    }
+
+    /// The style of the frequency labels (text marks) for a
+    /// [`SpectrumAnalyzer`]
+    ///
+    /// For no text marks, don't override this or set this to return `None`.
+    ///
+    /// [`SpectrumAnalyzer`]: ../../native/spectrum_analyzer/struct.SpectrumAnalyzer.html
+    fn text_marks_style(&self) -> Option<TextMarksStyle> {
+        None
+    }
+}
+
+struct Default;
+impl Default {
+    const ACTIVE_STYLE: Style = Style {
+        back_color: default_colors::LIGHT_BACK,
+        back_border_width: 1.0,
+        back_border_color: default_colors::BORDER,
+        line_width: 1.5,
+        line_color: default_colors::SPECTRUM_LINE,
+        fill_color: default_colors::SPECTRUM_FILL,
+        bar_color: default_colors::SPECTRUM_LINE,
+        bar_gap: 1.0,
+        peak_line: Some(PeakLineStyle {
+            color: default_colors::SPECTRUM_PEAK,
+            width: 1.0,
+        }),
+    };
+}
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Self::ACTIVE_STYLE
+    }
+
+    fn tick_marks_style(&self) -> Option<TickMarksStyle> {
+        Some(TickMarksStyle {
+            style: tick_marks::Style {
+                tier_1: tick_marks::Shape::Line {
+                    length: 0.0,
+                    width: 1.0,
+                    color: default_colors::TICK_TIER_2,
+                },
+                tier_2: tick_marks::Shape::Line {
+                    length: 0.0,
+                    width: 1.0,
+                    color: default_colors::GRID_LINE,
+                },
+                tier_3: tick_marks::Shape::Line {
+                    length: 0.0,
+                    width: 1.0,
+                    color: default_colors::GRID_LINE,
+                },
+            },
+            placement: tick_marks::Placement::Center {
+                offset: Offset::ZERO,
+                fill_length: true,
+            },
+        })
+    }
+
+    fn text_marks_style(&self) -> Option<TextMarksStyle> {
+        Some(TextMarksStyle {
+            style: text_marks::Style::default(),
+            placement: text_marks::Placement::RightOrBottom {
+                inside: false,
+                offset: Offset { x: 0.0, y: 7.0 },
+            },
+        })
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -0,0 +1,69 @@
+//! Styles for the tooltip that displays the value of a parameter widget
+//!
+//! Tooltips are supported by the [`Knob`], [`HSlider`], [`VSlider`],
+//! [`HRangeSlider`], [`VRangeSlider`], [`XYPad`], [`Ramp`], [`ModWheel`],
+//! and [`ModRangeInput`] widgets.
+//!
+//! [`Knob`]: ../native/knob/struct.Knob.html
+//! [`HSlider`]: ../native/h_slider/struct.HSlider.html
+//! [`VSlider`]: ../native/v_slider/struct.VSlider.html
+//! [`HRangeSlider`]: ../native/h_range_slider/struct.HRangeSlider.html
+//! [`VRangeSlider`]: ../native/v_range_slider/struct.VRangeSlider.html
+//! [`XYPad`]: ../native/xy_pad/struct.XYPad.html
+//! [`Ramp`]: ../native/ramp/struct.Ramp.html
+//! [`ModWheel`]: ../native/mod_wheel/struct.ModWheel.html
+//! [`ModRangeInput`]: ../native/mod_range_input/struct.ModRangeInput.html
+
+use iced_graphics::{Color, Font};
+
+use crate::style::default_colors;
+
+/// The appearance of a tooltip
+///
+/// The tooltip is centered above the widget, or below it if there is not
+/// enough room above. It is moved horizontally to stay inside the window,
+/// and grows to fit its text.
+#[derive(Debug, Copy, Clone)]
+pub struct Style {
+    /// The minimum width of the tooltip
+    pub min_width: u16,
+    /// The minimum height of the tooltip
+    pub height: u16,
+    /// The space between the text and the left and right edges of the
+    /// tooltip
+    pub padding: f32,
+    /// The gap between the tooltip and the widget
+    pub offset: f32,
+    /// The color of the background
+    pub back_color: Color,
+    /// The width of the border
+    pub border_width: f32,
+    /// The radius of the border
+    pub border_radius: f32,
+    /// The color of the border
+    pub border_color: Color,
+    /// The color of the text
+    pub text_color: Color,
+    /// The size of the text
+    pub text_size: u16,
+    /// The font of the text
+    pub font: Font,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            min_width: 32,
+            height: 20,
+            padding: 6.0,
+            offset: 4.0,
+            back_color: default_colors::TOOLTIP_BACK,
+            border_width: 0.0,
+            border_radius: 3.0,
+            border_color: Color::TRANSPARENT,
+            text_color: default_colors::LIGHT_BACK,
+            text_size: 13,
+            font: Default::default(),
+        }
+    }
+}
@@ -0,0 +1,168 @@
+//! Various styles for the [`VRangeSlider`] widget
+//!
+//! [`VRangeSlider`]: ../native/v_range_slider/struct.VRangeSlider.html
+
+use iced_native::Color;
+
+use crate::style::v_slider::{ClassicRail, ModRangePlacement, ModRangeStyle};
+use crate::style::{default_colors, tooltip};
+
+/// The appearance of a [`VRangeSlider`].
+///
+/// [`VRangeSlider`]: ../../native/v_range_slider/struct.VRangeSlider.html
+#[derive(Debug, Clone)]
+pub struct Style {
+    /// The rail style
+    pub rail: ClassicRail,
+    /// The style of the filled span between the first and the last
+    /// handle. The span uses `filled_inverse_color` when the first
+    /// handle is above the last handle.
+    ///
+    /// Set to `None` for no span.
+    pub span: Option<ModRangeStyle>,
+    /// The style of the handles
+    pub handle: HandleStyle,
+}
+
+/// The appearance of the handles of a [`VRangeSlider`]
+///
+/// The height of the handles is set on the widget itself.
+///
+/// [`VRangeSlider`]: ../../native/v_range_slider/struct.VRangeSlider.html
+#[derive(Debug, Clone)]
+pub struct HandleStyle {
+    /// background color
+    pub color: Color,
+    /// the width (thickness) of the middle notch
+    pub notch_width: f32,
+    /// color of the middle notch
+    pub notch_color: Color,
+    /// radius of the background rectangle
+    pub border_radius: f32,
+    /// width of the background rectangle
+    pub border_width: f32,
+    /// color of the background rectangle border
+    pub border_color: Color,
+}
+
+/// A set of rules that dictate the style of a [`VRangeSlider`].
+///
+/// [`VRangeSlider`]: ../../native/v_range_slider/struct.VRangeSlider.html
+pub trait StyleSheet {
+    /// Produces the style of an active [`VRangeSlider`].
+    ///
+    /// [`VRangeSlider`]: ../../native/v_range_slider/struct.VRangeSlider.html
+    fn active(&self) -> Style;
+
+    /// Produces the style of a hovered [`VRangeSlider`].
+    ///
+    /// [`VRangeSlider`]: ../../native/v_range_slider/struct.VRangeSlider.html
+    fn hovered(&self) -> Style;
+
+    /// Produces the style of a [`VRangeSlider`] that is being dragged.
+    ///
+    /// Only the handles that are being dragged use this style's
+    /// `handle`.
+    ///
+    /// [`VRangeSlider`]: ../../native/v_range_slider/struct.VRangeSlider.html
+    fn dragging(&self) -> Style;
+
+    /// Produces the style of a [`VRangeSlider`] that has keyboard focus.
+    ///
+    /// Only the handle that has keyboard focus uses this style's `handle`.
+    /// The default is the hovered style.
+    ///
+    /// [`VRangeSlider`]: ../../native/v_range_slider/struct.VRangeSlider.html
+    fn focused(&self) -> Style {
+        self.hovered()
+    }
+
+    /// The style of the tooltips that display the values of the handles of
+    /// a [`VRangeSlider`]
+    ///
+    /// [`VRangeSlider`]: ../../native/v_range_slider/struct.VRangeSlider.html
+    fn tooltip_style(&self) -> tooltip::Style {
+        tooltip::Style::default()
+    }
+}
+
+struct Default;
+impl Default {
+    const ACTIVE_STYLE: Style = Style {
+        rail: ClassicRail {
+            rail_colors: default_colors::SLIDER_RAIL,
+            rail_widths: (1.0, 1.0),
+            rail_padding: 8.0,
+        },
+        span: Some(ModRangeStyle {
+            placement: ModRangePlacement::Center {
+                width: 4.0,
+                offset: 0.0,
+            },
+            back_border_width: 0.0,
+            back_border_radius: 2.0,
+            back_border_color: Color::TRANSPARENT,
+            back_color: None,
+            filled_color: default_colors::RANGE_SLIDER_SPAN,
+            filled_inverse_color: default_colors::RANGE_SLIDER_SPAN_INVERSE,
+        }),
+        handle: HandleStyle {
+            color: default_colors::LIGHT_BACK,
+            notch_width: 2.0,
+            notch_color: default_colors::BORDER,
+            border_radius: 2.0,
+            border_color: default_colors::BORDER,
+            border_width: 1.0,
+        },
+    };
+}
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Self::ACTIVE_STYLE
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            handle: HandleStyle {
+                color: default_colors::LIGHT_BACK_HOVER,
+                ..Self::ACTIVE_STYLE.handle
+            },
+            ..Self::ACTIVE_STYLE
+        }
+    }
+
+    fn dragging(&self) -> Style {
+        Style {
+            handle: HandleStyle {
+                color: default_colors::LIGHT_BACK_DRAG,
+                ..Self::ACTIVE_STYLE.handle
+            },
+            ..Self::ACTIVE_STYLE
+        }
+    }
+
+    fn focused(&self) -> Style {
+        Style {
+            handle: HandleStyle {
+                border_color: default_colors::FOCUS_BORDER,
+                ..Self::ACTIVE_STYLE.handle
+            },
+            ..Self::ACTIVE_STYLE
+        }
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
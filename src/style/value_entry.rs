@@ -0,0 +1,53 @@
+//! Styles for the text field used to type in an exact value
+//!
+//! Value entries are supported by the [`Knob`], [`HSlider`], and
+//! [`VSlider`] widgets.
+//!
+//! [`Knob`]: ../native/knob/struct.Knob.html
+//! [`HSlider`]: ../native/h_slider/struct.HSlider.html
+//! [`VSlider`]: ../native/v_slider/struct.VSlider.html
+
+use iced_graphics::{Color, Font};
+
+use crate::style::default_colors;
+
+/// The appearance of a value entry text field
+///
+/// The text field is centered over the widget.
+#[derive(Debug, Copy, Clone)]
+pub struct Style {
+    /// The width of the text field
+    pub width: u16,
+    /// The height of the text field
+    pub height: u16,
+    /// The color of the background
+    pub back_color: Color,
+    /// The width of the border
+    pub border_width: f32,
+    /// The radius of the border
+    pub border_radius: f32,
+    /// The color of the border
+    pub border_color: Color,
+    /// The color of the text
+    pub text_color: Color,
+    /// The size of the text
+    pub text_size: u16,
+    /// The font of the text
+    pub font: Font,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            width: 64,
+            height: 20,
+            back_color: default_colors::LIGHT_BACK,
+            border_width: 1.0,
+            border_radius: 2.0,
+            border_color: default_colors::FOCUS_BORDER,
+            text_color: default_colors::BORDER,
+            text_size: 14,
+            font: Default::default(),
+        }
+    }
+}
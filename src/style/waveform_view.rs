@@ -0,0 +1,104 @@
+//! Various styles for the [`WaveformView`] widget
+//!
+//! [`WaveformView`]: ../native/waveform_view/struct.WaveformView.html
+
+use iced_native::Color;
+
+use crate::style::default_colors;
+
+/// The appearance of a [`WaveformView`].
+///
+/// [`WaveformView`]: ../../native/waveform_view/struct.WaveformView.html
+#[derive(Debug, Clone)]
+pub struct Style {
+    /// The color of the background rectangle
+    pub back_color: Color,
+    /// The width of the border of the background rectangle
+    pub back_border_width: f32,
+    /// The color of the border of the background rectangle
+    pub back_border_color: Color,
+    /// The color of the waveform
+    pub wave_color: Color,
+    /// The width of the center line. Set this to `0.0` to hide it.
+    pub center_line_width: f32,
+    /// The color of the center line
+    pub center_line_color: Color,
+    /// The color of the selected region
+    pub selection_color: Color,
+    /// The style of the markers
+    pub markers: MarkerStyle,
+}
+
+/// The style of the markers of a [`WaveformView`]
+///
+/// [`WaveformView`]: ../../native/waveform_view/struct.WaveformView.html
+#[derive(Debug, Copy, Clone)]
+pub struct MarkerStyle {
+    /// The width of a marker line
+    pub width: f32,
+    /// The width of a marker line that is hovered or being dragged
+    pub active_width: f32,
+    /// The color of the start marker
+    pub start_color: Color,
+    /// The color of the loop marker
+    pub loop_color: Color,
+    /// The color of the end marker
+    pub end_color: Color,
+}
+
+/// A set of rules that dictate the style of a [`WaveformView`].
+///
+/// [`WaveformView`]: ../../native/waveform_view/struct.WaveformView.html
+pub trait StyleSheet {
+    /// Produces the style of an active [`WaveformView`].
+    ///
+    /// [`WaveformView`]: ../../native/waveform_view/struct.WaveformView.html
+    fn active(&self) -> Style;
+
+    /// Produces the style of a hovered [`WaveformView`].
+    ///
+    /// [`WaveformView`]: ../../native/waveform_view/struct.WaveformView.html
+    fn hovered(&self) -> Style {
+        self.active()
+    }
+}
+
+struct Default;
+impl Default {
+    const ACTIVE_STYLE: Style = Style {
+        back_color: default_colors::LIGHT_BACK,
+        back_border_width: 1.0,
+        back_border_color: default_colors::BORDER,
+        wave_color: default_colors::WAVEFORM,
+        center_line_width: 1.0,
+        center_line_color: default_colors::GRID_LINE,
+        selection_color: default_colors::WAVEFORM_SELECTION,
+        markers: MarkerStyle {
+            width: 1.0,
+            active_width: 2.0,
+            start_color: default_colors::WAVEFORM_START_MARKER,
+            loop_color: default_colors::WAVEFORM_LOOP_MARKER,
+            end_color: default_colors::WAVEFORM_END_MARKER,
+        },
+    };
+}
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Self::ACTIVE_STYLE
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -0,0 +1,123 @@
+//! Various styles for the [`WavetableView`] widget
+//!
+//! [`WavetableView`]: ../native/wavetable_view/struct.WavetableView.html
+
+use iced_native::Color;
+
+use crate::style::default_colors;
+
+/// The appearance of a [`WavetableView`].
+///
+/// [`WavetableView`]: ../../native/wavetable_view/struct.WavetableView.html
+#[derive(Debug, Clone)]
+pub struct Style {
+    /// The color of the background rectangle
+    pub back_color: Color,
+    /// The width of the border of the background rectangle
+    pub back_border_width: f32,
+    /// The color of the border of the background rectangle
+    pub back_border_color: Color,
+    /// The width of the line of the current frame
+    pub line_width: f32,
+    /// The color of the line of the current frame
+    pub line_color: Color,
+    /// The color of the horizontal center (zero) line of the current frame.
+    /// Set this to `None` for no center line.
+    pub center_line_color: Option<Color>,
+    /// The width of the horizontal center (zero) line
+    pub center_line_width: f32,
+    /// The style of the stack of frames
+    pub stack: StackStyle,
+}
+
+/// The style of the stack of frames in a [`WavetableView`]
+///
+/// [`WavetableView`]: ../../native/wavetable_view/struct.WavetableView.html
+#[derive(Debug, Copy, Clone)]
+pub struct StackStyle {
+    /// The horizontal distance between the first and the last frame. The
+    /// last frame is drawn to the right of the first frame.
+    pub offset_x: f32,
+    /// The vertical distance between the first and the last frame. The last
+    /// frame is drawn above the first frame.
+    pub offset_y: f32,
+    /// The maximum number of frames to draw. Frames are skipped evenly if
+    /// the wavetable has more frames than this. The current frame is always
+    /// drawn.
+    pub max_frames: u16,
+    /// The width of the lines of the other frames
+    pub line_width: f32,
+    /// The color of the lines of the other frames
+    pub line_color: Color,
+}
+
+/// A set of rules that dictate the style of a [`WavetableView`].
+///
+/// [`WavetableView`]: ../../native/wavetable_view/struct.WavetableView.html
+pub trait StyleSheet {
+    /// Produces the style of an active [`WavetableView`].
+    ///
+    /// [`WavetableView`]: ../../native/wavetable_view/struct.WavetableView.html
+    fn active(&self) -> Style;
+
+    /// Produces the style of a hovered [`WavetableView`].
+    ///
+    /// [`WavetableView`]: ../../native/wavetable_view/struct.WavetableView.html
+    fn hovered(&self) -> Style;
+
+    /// Produces the style of a [`WavetableView`] that is being dragged.
+    ///
+    /// [`WavetableView`]: ../../native/wavetable_view/struct.WavetableView.html
+    fn dragging(&self) -> Style;
+}
+
+struct Default;
+impl Default {
+    const ACTIVE_STYLE: Style = Style {
+        back_color: default_colors::LIGHT_BACK,
+        back_border_width: 1.0,
+        back_border_color: default_colors::BORDER,
+        line_width: 2.0,
+        line_color: default_colors::WAVETABLE_ACTIVE,
+        center_line_color: Some(default_colors::GRID_LINE),
+        center_line_width: 1.0,
+        stack: StackStyle {
+            offset_x: 40.0,
+            offset_y: 30.0,
+            max_frames: 32,
+            line_width: 1.0,
+            line_color: default_colors::WAVETABLE_FRAME,
+        },
+    };
+}
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Self::ACTIVE_STYLE
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            back_color: default_colors::LIGHT_BACK_HOVER,
+            ..Self::ACTIVE_STYLE
+        }
+    }
+
+    fn dragging(&self) -> Style {
+        self.hovered()
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -4,7 +4,7 @@
 
 use iced_native::Color;
 
-use crate::style::default_colors;
+use crate::style::{default_colors, tooltip};
 
 /// The appearance of an [`XYPad`].
 ///
@@ -98,6 +98,22 @@ pub trait StyleSheet {
     ///
     /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
     fn dragging(&self) -> Style;
+
+    /// Produces the style of an [`XYPad`] that has keyboard focus.
+    ///
+    /// The default is the hovered style.
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    fn focused(&self) -> Style {
+        self.hovered()
+    }
+
+    /// The style of the tooltip that displays the value of an [`XYPad`]
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    fn tooltip_style(&self) -> tooltip::Style {
+        tooltip::Style::default()
+    }
 }
 
 struct Default;
@@ -145,6 +161,13 @@ impl StyleSheet for Default {
             ..Self::ACTIVE_STYLE
         }
     }
+
+    fn focused(&self) -> Style {
+        Style {
+            border_color: default_colors::FOCUS_BORDER,
+            ..Self::ACTIVE_STYLE
+        }
+    }
 }
 
 impl std::default::Default for Box<dyn StyleSheet> {